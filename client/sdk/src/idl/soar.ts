@@ -48,7 +48,9 @@ export type Soar = {
         {
           name: "gameAuth";
           type: {
-            vec: "publicKey";
+            vec: {
+              defined: "GameAuthority";
+            };
           };
         }
       ];
@@ -91,12 +93,120 @@ export type Soar = {
           name: "newAuth";
           type: {
             option: {
-              vec: "publicKey";
+              vec: {
+                defined: "GameAuthority";
+              };
             };
           };
         }
       ];
     },
+    {
+      name: "createProposal";
+      docs: [
+        "Propose a sensitive change to a [Game], registering the proposing admin's approval.",
+        "",
+        "Only needed for games whose `threshold` is greater than `1`."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "action";
+          type: {
+            defined: "ProposalAction";
+          };
+        }
+      ];
+    },
+    {
+      name: "approveProposal";
+      docs: ["Register an admin's approval for a [GameProposal]."];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "executeProposal";
+      docs: [
+        "Apply an approved [GameProposal] that changes the [Game]'s authorities or threshold.",
+        "",
+        "Proposals for other actions are consumed by the instructions they authorize."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
     {
       name: "addAchievement";
       docs: [
@@ -141,12 +251,26 @@ export type Soar = {
         {
           name: "nftMeta";
           type: "publicKey";
+        },
+        {
+          name: "unlockRule";
+          type: {
+            option: {
+              defined: "AchievementRule";
+            };
+          };
+        },
+        {
+          name: "progressTarget";
+          type: {
+            option: "u64";
+          };
         }
       ];
     },
     {
       name: "updateAchievement";
-      docs: ["Update an [Achievement]'s meta information."];
+      docs: ["Update an [Achievement]'s meta information or unlock rule."];
       accounts: [
         {
           name: "authority";
@@ -182,6 +306,16 @@ export type Soar = {
           type: {
             option: "publicKey";
           };
+        },
+        {
+          name: "newUnlockRule";
+          type: {
+            option: {
+              option: {
+                defined: "AchievementRule";
+              };
+            };
+          };
         }
       ];
     },
@@ -219,6 +353,12 @@ export type Soar = {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
         }
       ];
       args: [
@@ -233,8 +373,9 @@ export type Soar = {
     {
       name: "updateLeaderboard";
       docs: [
-        "Update's a leaderboard's description, nft metadata information, min/max score, or whether",
-        "or not multiple scores are allowed for a single player."
+        "Update's a leaderboard's description, nft metadata information, min/max score, order,",
+        "whether or not multiple scores are allowed for a single player, its submission window,",
+        "retention policy, scoring mode or tie-break rule."
       ];
       accounts: [
         {
@@ -261,59 +402,46 @@ export type Soar = {
       ];
       args: [
         {
-          name: "newDescription";
-          type: {
-            option: "string";
-          };
-        },
-        {
-          name: "newNftMeta";
-          type: {
-            option: "publicKey";
-          };
-        },
-        {
-          name: "newMinScore";
-          type: {
-            option: "u64";
-          };
-        },
-        {
-          name: "newMaxScore";
-          type: {
-            option: "u64";
-          };
-        },
-        {
-          name: "newIsAscending";
-          type: {
-            option: "bool";
-          };
-        },
-        {
-          name: "newAllowMultipleScores";
+          name: "input";
           type: {
-            option: "bool";
+            defined: "UpdateLeaderBoardInput";
           };
         }
       ];
     },
     {
-      name: "initializePlayer";
-      docs: ["Create a [Player] account for a particular user."];
+      name: "resizeTopEntries";
+      docs: [
+        "Change the number of scores a leaderboard's [LeaderTopEntriesV2] retains, keeping",
+        "current rankings. Shrinking refunds rent to `payer`, and can only drop the",
+        "lowest-ranked entries if `drop_entries` is true.",
+        "",
+        "The account can grow by at most 10KiB per call, so large increases take several calls.",
+        "Not callable while the leaderboard's season is closed."
+      ];
       accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
         {
           name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "user";
+          name: "game";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "playerAccount";
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
           isMut: true;
           isSigner: false;
         },
@@ -325,50 +453,50 @@ export type Soar = {
       ];
       args: [
         {
-          name: "username";
-          type: "string";
+          name: "newLen";
+          type: "u16";
         },
         {
-          name: "nftMeta";
-          type: "publicKey";
+          name: "dropEntries";
+          type: "bool";
         }
       ];
     },
     {
-      name: "updatePlayer";
-      docs: ["Update the username or nft_meta for a [Player] account."];
+      name: "migrateGame";
+      docs: [
+        "Convert a [Game] from its legacy layout to the current one in place, granting each of",
+        "its authorities the admin role.",
+        "",
+        "Can be called by anyone. `payer` covers any extra rent."
+      ];
       accounts: [
         {
-          name: "user";
-          isMut: false;
+          name: "payer";
+          isMut: true;
           isSigner: true;
         },
         {
-          name: "playerAccount";
+          name: "game";
           isMut: true;
           isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "username";
-          type: {
-            option: "string";
-          };
         },
         {
-          name: "nftMeta";
-          type: {
-            option: "publicKey";
-          };
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "registerPlayer";
+      name: "migrateTopEntries";
       docs: [
-        "Register a [Player] for a particular [Leaderboard], resulting in a newly-",
-        "created [PlayerEntryList] account."
+        "Convert a leaderboard's top entries account from the legacy borsh [LeaderTopEntries]",
+        "layout to the zero-copy [LeaderTopEntriesV2] layout in place, keeping its rankings.",
+        "",
+        "Can be called by anyone. `payer` covers any extra rent. The account can grow by at most",
+        "10KiB per call, so large accounts take several calls and are converted by the last one."
       ];
       accounts: [
         {
@@ -376,28 +504,13 @@ export type Soar = {
           isMut: true;
           isSigner: true;
         },
-        {
-          name: "user";
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "playerAccount";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "game";
-          isMut: false;
-          isSigner: false;
-        },
         {
           name: "leaderboard";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "newList";
+          name: "topEntries";
           isMut: true;
           isSigner: false;
         },
@@ -410,12 +523,14 @@ export type Soar = {
       args: [];
     },
     {
-      name: "submitScore";
+      name: "migratePlayerScores";
       docs: [
-        "Submit a score for a player and have it timestamped and added to the [PlayerEntryList].",
-        "Optionally increase the player's rank if needed.",
+        "Convert a [PlayerScoresList] from its legacy layout to the current one in place,",
+        "rebuilding its aggregates from the scores it holds.",
         "",
-        "This instruction automatically resizes the [PlayerScoresList] account if needed."
+        "Can be called by anyone. `payer` covers any extra rent. The account can grow by at most",
+        "10KiB per call, so large lists take several calls and are converted by the last one.",
+        "Leaderboards with top entries must have them migrated first."
       ];
       accounts: [
         {
@@ -423,58 +538,42 @@ export type Soar = {
           isMut: true;
           isSigner: true;
         },
-        {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
-        },
         {
           name: "playerAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "game";
+          name: "leaderboard";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "leaderboard";
+          name: "topEntries";
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
           name: "playerScores";
           isMut: true;
           isSigner: false;
         },
-        {
-          name: "topEntries";
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-        },
         {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
         }
       ];
-      args: [
-        {
-          name: "score";
-          type: "u64";
-        }
-      ];
+      args: [];
     },
     {
-      name: "initiateMerge";
+      name: "migratePlayerAchievement";
       docs: [
-        "Initialize a new merge account and await approval from the verified users of all the",
-        "specified [Player] accounts.",
+        "Convert a [PlayerAchievement] from its legacy layout to the current one in place, with",
+        "no progress recorded.",
         "",
-        "A merge is complete when all the users of the [Player] account keys referenced in it",
-        "have signed to set their approval to `true`."
+        "Can be called by anyone. `payer` covers any extra rent."
       ];
       accounts: [
         {
@@ -483,19 +582,9 @@ export type Soar = {
           isSigner: true;
         },
         {
-          name: "user";
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "playerAccount";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "mergeAccount";
+          name: "playerAchievement";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
           name: "systemProgram";
@@ -503,50 +592,46 @@ export type Soar = {
           isSigner: false;
         }
       ];
-      args: [
-        {
-          name: "keys";
-          type: {
-            vec: "publicKey";
-          };
-        }
-      ];
+      args: [];
     },
     {
-      name: "approveMerge";
+      name: "migrateMerge";
       docs: [
-        "Register merge confirmation for a particular [Player] account included in a [Merged]."
+        "Convert a [Merged] account from its legacy layout to the current one in place. Its rent",
+        "is refunded to the initiator when it's closed, and a pending merge expires a week after",
+        "being migrated.",
+        "",
+        "Can be called by anyone. `payer` covers any extra rent."
       ];
       accounts: [
         {
-          name: "user";
-          isMut: false;
+          name: "payer";
+          isMut: true;
           isSigner: true;
         },
         {
-          name: "playerAccount";
-          isMut: false;
+          name: "mergeAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "mergeAccount";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "unlockPlayerAchievement";
+      name: "closeSeason";
       docs: [
-        "Unlock a [PlayerAchievement] account without minting a reward.",
+        "Close the current season of a [LeaderBoard], freezing it and archiving its",
+        "[LeaderTopEntriesV2] into a [SeasonArchive] account.",
         "",
-        "Used `ONLY` for custom rewards mechanism to setup a [PlayerAchievement] account that",
-        "can serve as a gated verification-method for claims.",
+        "Leaderboards with top entries must pass them, and can't rank more than",
+        "[SeasonArchive::MAX_SCORES] entries; shrink them with [resize_top_entries] first.",
         "",
-        "Since claim instructions like [claim_ft_reward] and [claim_nft_reward] for reward types",
-        "defined by this program try to initialize this account and will fail if it already exists,",
-        "calling this means opting out of using these functions."
+        "No scores can be submitted to a frozen leaderboard until [start_season] is called."
       ];
       accounts: [
         {
@@ -560,22 +645,23 @@ export type Soar = {
           isSigner: true;
         },
         {
-          name: "playerAccount";
+          name: "game";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "game";
-          isMut: false;
+          name: "leaderboard";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "achievement";
+          name: "topEntries";
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: "playerAchievement";
+          name: "archive";
           isMut: true;
           isSigner: false;
         },
@@ -588,11 +674,9 @@ export type Soar = {
       args: [];
     },
     {
-      name: "addFtReward";
+      name: "startSeason";
       docs: [
-        "Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.",
-        "",
-        "Overwrites the current reward if one exists."
+        "Start a new season for a frozen [LeaderBoard], resetting its [LeaderTopEntriesV2]."
       ];
       accounts: [
         {
@@ -600,316 +684,474 @@ export type Soar = {
           isMut: false;
           isSigner: true;
         },
-        {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
         {
           name: "game";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "achievement";
+          name: "leaderboard";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "newReward";
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        }
+      ];
+      args: [
+        {
+          name: "seasonEnd";
+          type: {
+            option: "i64";
+          };
+        }
+      ];
+    },
+    {
+      name: "initializePlayer";
+      docs: ["Create a [Player] account for a particular user."];
+      accounts: [
+        {
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "rewardTokenMint";
+          name: "user";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "delegateFromTokenAccount";
+          name: "playerAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenAccountOwner";
+          name: "systemProgram";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "username";
+          type: "string";
         },
         {
-          name: "tokenProgram";
+          name: "nftMeta";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "updatePlayer";
+      docs: ["Update the username or nft_meta for a [Player] account."];
+      accounts: [
+        {
+          name: "user";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "systemProgram";
-          isMut: false;
+          name: "playerAccount";
+          isMut: true;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "input";
+          name: "username";
           type: {
-            defined: "AddNewRewardInput";
+            option: "string";
+          };
+        },
+        {
+          name: "nftMeta";
+          type: {
+            option: "publicKey";
           };
         }
       ];
     },
     {
-      name: "addNftReward";
+      name: "registerPlayer";
       docs: [
-        "Add a nft [Reward] to an [Achievement] to mint to users on unlock.",
-        "",
-        "Overwrites the current reward if one exists."
+        "Register a [Player] for a particular [Leaderboard], resulting in a newly-",
+        "created [PlayerEntryList] account."
       ];
       accounts: [
-        {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
-        },
         {
           name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "game";
+          name: "user";
           isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "achievement";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "newReward";
-          isMut: true;
           isSigner: true;
         },
         {
-          name: "systemProgram";
+          name: "playerAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "rewardCollectionMint";
+          name: "game";
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: "collectionUpdateAuth";
+          name: "leaderboard";
           isMut: false;
-          isSigner: true;
-          isOptional: true;
+          isSigner: false;
         },
         {
-          name: "collectionMetadata";
+          name: "newList";
           isMut: true;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: "tokenMetadataProgram";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
-          isOptional: true;
-        }
-      ];
-      args: [
-        {
-          name: "input";
-          type: {
-            defined: "AddNewRewardInput";
-          };
         }
       ];
+      args: [];
     },
     {
-      name: "claimFtReward";
+      name: "submitScore";
       docs: [
-        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
+        "Submit a score for a player and have it timestamped and added to the [PlayerEntryList].",
+        "Optionally increase the player's rank if needed.",
         "",
-        "This will attempt to create a [PlayerAchievement] account and fail if it already exists.",
+        "On leaderboards with a [ScoreType::Signed] score type, `score` is the `i64` value cast",
+        "to `u64`.",
         "",
-        "Relevant `ONLY` if an FT reward is specified for that achievement."
+        "`secondary_score` and `context` are stored with the entry. The secondary score settles",
+        "ties on leaderboards using [TieBreak::SecondaryScore], where entries without one rank last.",
+        "",
+        "Submissions that break the [LeaderBoard]'s [RateLimit] for the player are rejected.",
+        "",
+        "This instruction automatically resizes the [PlayerScoresList] account if needed, within the",
+        "limit set by the [LeaderBoard]'s [RetentionPolicy].",
+        "",
+        "Optionally takes `(achievement, player_achievement)` pairs as remaining accounts, creating",
+        "the [PlayerAchievement] account for each [Achievement] whose unlock rule is satisfied."
       ];
       accounts: [
-        {
-          name: "user";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
-        },
         {
           name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "game";
+          name: "authority";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "achievement";
+          name: "playerAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "reward";
-          isMut: true;
+          name: "game";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "playerAccount";
+          name: "leaderboard";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "playerAchievement";
+          name: "playerScores";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "sourceTokenAccount";
+          name: "topEntries";
           isMut: true;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: "userTokenAccount";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "score";
+          type: "u64";
         },
         {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
+          name: "secondaryScore";
+          type: {
+            option: "u64";
+          };
         },
         {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
+          name: "context";
+          type: {
+            option: {
+              array: ["u8", 32];
+            };
+          };
         }
       ];
-      args: [];
     },
     {
-      name: "claimNftReward";
+      name: "submitAttestedScore";
       docs: [
-        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
+        "Submit a score signed off-chain by a [Game] authority with the attester role, with the",
+        "player's user signing and paying for the transaction instead of the game.",
         "",
-        "This will attempt to create a [PlayerAchievement] account and fail if it already exists.",
+        "The preceding instruction must be an ed25519 signature verification of the borsh-serialized",
+        "[ScoreAttestation] for these arguments. `nonce` must exceed the last one used for this",
+        "[PlayerScoresList], and the attestation is rejected after `expiry`.",
         "",
-        "Relevant `ONLY` if an NFT reward is specified for that achievement."
+        "Takes the same optional remaining accounts as [submit_score]."
       ];
       accounts: [
         {
-          name: "user";
-          isMut: false;
-          isSigner: false;
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "authority";
+          name: "user";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "game";
+          name: "playerAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
+          name: "game";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "achievement";
+          name: "leaderboard";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "reward";
+          name: "playerScores";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "playerAccount";
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "instructions";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "playerAchievement";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "score";
+          type: "u64";
         },
         {
-          name: "claim";
+          name: "secondaryScore";
+          type: {
+            option: "u64";
+          };
+        },
+        {
+          name: "context";
+          type: {
+            option: {
+              array: ["u8", 32];
+            };
+          };
+        },
+        {
+          name: "nonce";
+          type: "u64";
+        },
+        {
+          name: "expiry";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "initiateMerge";
+      docs: [
+        "Initialize a new merge account and await approval from the verified users of all the",
+        "specified [Player] accounts.",
+        "",
+        "A merge is complete when all the users of the [Player] account keys referenced in it",
+        "have signed to set their approval to `true`."
+      ];
+      accounts: [
+        {
+          name: "payer";
           isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "newMint";
+          name: "mergeAccount";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "newMetadata";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "keys";
+          type: {
+            vec: "publicKey";
+          };
+        }
+      ];
+    },
+    {
+      name: "approveMerge";
+      docs: [
+        "Register merge confirmation for a particular [Player] account included in a [Merged]."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "newMasterEdition";
-          isMut: true;
+          name: "playerAccount";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "mintTo";
+          name: "mergeAccount";
           isMut: true;
           isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "rejectMerge";
+      docs: [
+        "Reject a pending merge that includes the signer's [Player] account, closing the",
+        "[Merged] account and refunding its rent to the original payer."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "tokenMetadataProgram";
+          name: "playerAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedTokenProgram";
-          isMut: false;
+          name: "mergeAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "payer";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "cancelMerge";
+      docs: [
+        "Cancel a pending merge as its initiator, closing the [Merged] account and refunding",
+        "its rent to the original payer."
+      ];
+      accounts: [
+        {
+          name: "initiator";
           isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "mergeAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "payer";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeMerge";
+      docs: [
+        "Close a [Merged] account and refund its rent to the original payer.",
+        "",
+        "Pending merges can be closed by anyone once expired. Completed merges can only be",
+        "closed by their initiator."
+      ];
+      accounts: [
+        {
+          name: "authority";
           isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "mergeAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "rent";
-          isMut: false;
+          name: "payer";
+          isMut: true;
           isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "verifyNftReward";
+      name: "mergePlayerScores";
       docs: [
-        "Verify NFT reward as belonging to a particular collection.",
+        "Move all scores from a merged [Player]'s [PlayerScoresList] into the merge initiator's",
+        "list for the same [LeaderBoard], keeping only what the leaderboard's retention policy allows.",
         "",
-        "Optional: Only relevant if an NFT reward is specified and the reward's",
-        "`collection_mint` is Some(...)"
+        "Only callable once the [Merged] account is complete."
       ];
       accounts: [
         {
@@ -918,2543 +1160,9195 @@ export type Soar = {
           isSigner: true;
         },
         {
-          name: "game";
+          name: "mergeAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "achievement";
+          name: "playerAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "reward";
+          name: "mergedPlayerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "playerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "user";
+          name: "topEntries";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
+          isOptional: true;
         },
         {
-          name: "playerAccount";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "mergePlayerAchievement";
+      docs: [
+        "Fold a merged [Player]'s [PlayerAchievement] into the merge initiator's, creating it if",
+        "needed.",
+        "",
+        "Only callable once the [Merged] account is complete. Each merged [PlayerAchievement]",
+        "can only be folded in once."
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "claim";
+          name: "mergeAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "playerAchievement";
+          name: "playerAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "mint";
+          name: "achievement";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "metadataToVerify";
+          name: "mergedPlayerAchievement";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "collectionMint";
-          isMut: false;
+          name: "playerAchievement";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "collectionMetadata";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "mergeTopEntries";
+      docs: [
+        "Reassign every [LeaderTopEntriesV2] slot held by a merged [Player] to the merge initiator's",
+        "[Player], keeping only its best entry if the [LeaderBoard] doesn't allow multiple scores.",
+        "",
+        "Only callable once the [Merged] account is complete, and not while the leaderboard's",
+        "season is closed."
+      ];
+      accounts: [
+        {
+          name: "mergeAccount";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "collectionEdition";
+          name: "playerAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
+          name: "leaderboard";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
         }
       ];
       args: [];
-    }
-  ];
-  accounts: [
+    },
     {
-      name: "achievement";
+      name: "unlockPlayerAchievement";
       docs: [
-        "Represents an achievement(with optional rewards) for this game",
-        "that can be attained by players.",
+        "Unlock a [PlayerAchievement] account without minting a reward.",
         "",
-        'PDA with seeds = `[b"achievement", game.key().as_ref(), &id.to_le_bytes()]`',
+        "Used `ONLY` for custom rewards mechanism to setup a [PlayerAchievement] account that",
+        "can serve as a gated verification-method for claims.",
         "",
-        "`id` is an incrementing index stored in the game account."
+        "Claim instructions like [claim_ft_reward] and [claim_nft_reward] still accept an",
+        "account unlocked this way, as long as its reward hasn't been claimed."
       ];
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "game";
-            docs: [
-              "Public key of the game account this achievement is derived from."
-            ];
-            type: "publicKey";
-          },
-          {
-            name: "id";
-            docs: [
-              "The achievement_count of the game account when this account was",
-              "created, also used as a seed for its PDA."
-            ];
-            type: "u64";
-          },
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "incrementAchievementProgress";
+      docs: [
+        "Increase a player's progress towards an [Achievement] with a `progress_target`,",
+        "creating their [PlayerAchievement] account if needed and unlocking it once the",
+        "target is reached."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "addFtReward";
+      docs: [
+        "Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.",
+        "",
+        "Overwrites the current reward if one exists."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newReward";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "delegateFromTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenAccountOwner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        }
+      ];
+      args: [
+        {
+          name: "input";
+          type: {
+            defined: "AddNewRewardInput";
+          };
+        }
+      ];
+    },
+    {
+      name: "addNftReward";
+      docs: [
+        "Add a nft [Reward] to an [Achievement] to mint to users on unlock.",
+        "",
+        "Overwrites the current reward if one exists."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newReward";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardCollectionMint";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "collectionUpdateAuth";
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: "collectionMetadata";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        }
+      ];
+      args: [
+        {
+          name: "input";
+          type: {
+            defined: "AddNewRewardInput";
+          };
+        }
+      ];
+    },
+    {
+      name: "addRankReward";
+      docs: [
+        "Add a fungible token [RankReward] paying out to the top ranks of a [LeaderBoard]'s",
+        "current season.",
+        "",
+        "Tiers can't reward ranks beyond the capacity of the leaderboard's [LeaderTopEntriesV2]",
+        "or [SeasonArchive::MAX_SCORES]."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rankReward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardTokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "delegateFromTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenAccountOwner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        }
+      ];
+      args: [
+        {
+          name: "input";
+          type: {
+            defined: "AddRankRewardInput";
+          };
+        }
+      ];
+    },
+    {
+      name: "claimRankReward";
+      docs: [
+        "Claim the [RankReward] for a player holding `rank` in the [SeasonArchive] of the",
+        "reward's season.",
+        "",
+        "Each rank can be claimed only once."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rankReward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "archive";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sourceTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "rank";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "claimFtReward";
+      docs: [
+        "Transfer an FT reward for unlocking a [PlayerAchievement] account.",
+        "",
+        "This will create the [PlayerAchievement] account if it doesn't exist yet. If it does,",
+        "it must already be unlocked and not yet claimed.",
+        "",
+        "Relevant `ONLY` if an FT reward is specified for that achievement."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sourceTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "claimNftReward";
+      docs: [
+        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
+        "",
+        "This will create the [PlayerAchievement] account if it doesn't exist yet. If it does,",
+        "it must already be unlocked and not yet claimed.",
+        "",
+        "Relevant `ONLY` if an NFT reward is specified for that achievement."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newMint";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "newMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newMasterEdition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintTo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeAchievement";
+      docs: [
+        "Close an [Achievement] that has no [Reward] attached, refunding its rent to `receiver`."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeReward";
+      docs: [
+        "Close a [Reward] with no available spots left, detaching it from its [Achievement]",
+        "and refunding its rent to `receiver`."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeLeaderboard";
+      docs: [
+        "Close a [LeaderBoard] whose current season is closed, along with its",
+        "[LeaderTopEntriesV2] if it has one, refunding their rent to `receiver`."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "topEntries";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closePlayerScores";
+      docs: [
+        "Close a user's own [PlayerScoresList], refunding its rent to `receiver`.",
+        "",
+        "Only allowed once the [LeaderBoard]'s season is closed, or the leaderboard itself is,",
+        "since the list holds the player's attestation nonce and rate limit state."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerScores";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closePlayerAchievement";
+      docs: [
+        "Close a [PlayerAchievement], refunding its rent to `receiver`.",
+        "",
+        "Closing it allows the achievement to be unlocked again. Accounts that claimed, or were",
+        "merged into another player's, can't be closed while the achievement has a reward."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeNftClaim";
+      docs: [
+        "Close an [NftClaim], refunding its rent to `receiver`.",
+        "",
+        "The claimed NFT can no longer be verified as part of the reward's collection afterwards."
+      ];
+      accounts: [
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closePlayer";
+      docs: [
+        "Close a user's own [Player] account, refunding its rent to `receiver`."
+      ];
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "receiver";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "verifyNftReward";
+      docs: [
+        "Verify NFT reward as belonging to a particular collection.",
+        "",
+        "Optional: Only relevant if an NFT reward is specified and the reward's",
+        "`collection_mint` is Some(...)"
+      ];
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "game";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "achievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reward";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "playerAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claim";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "playerAchievement";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "metadataToVerify";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "collectionMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionEdition";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "achievement";
+      docs: [
+        "Represents an achievement(with optional rewards) for this game",
+        "that can be attained by players.",
+        "",
+        'PDA with seeds = `[b"achievement", game.key().as_ref(), &id.to_le_bytes()]`',
+        "",
+        "`id` is an incrementing index stored in the game account."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "game";
+            docs: [
+              "Public key of the game account this achievement is derived from."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "id";
+            docs: [
+              "The achievement_count of the game account when this account was",
+              "created, also used as a seed for its PDA."
+            ];
+            type: "u64";
+          },
+          {
+            name: "title";
+            docs: ["Achievement title."];
+            type: "string";
+          },
+          {
+            name: "description";
+            docs: ["Achievement description."];
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            docs: [
+              "Public key of a nft metadata account describing this achievement."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "reward";
+            docs: [
+              "Optional: Specify a reward to players for unlocking this achievement."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "unlockRule";
+            docs: [
+              "Optional: A rule that automatically unlocks this achievement for a",
+              "player when satisfied during a score submission."
+            ];
+            type: {
+              option: {
+                defined: "AchievementRule";
+              };
+            };
+          },
+          {
+            name: "progressTarget";
+            docs: [
+              "Optional: A progress value players must reach through",
+              "[increment_achievement_progress][crate::soar::increment_achievement_progress]",
+              "for this achievement to be unlocked."
+            ];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "game";
+      docs: ["An account representing a single game."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "meta";
+            docs: ["Game meta-information."];
+            type: {
+              defined: "GameAttributes";
+            };
+          },
+          {
+            name: "leaderboardCount";
+            docs: [
+              "Number of leaderboards this game has created. Used both",
+              "in determining the most recent leaderboard address, and",
+              "as a seed for the next leaderboard."
+            ];
+            type: "u64";
+          },
+          {
+            name: "achievementCount";
+            docs: [
+              "Number of achievements that exist for this game. Also",
+              "used to determine the u64 seed for the next achievement."
+            ];
+            type: "u64";
+          },
+          {
+            name: "auth";
+            docs: [
+              "A collection of valid authorities for this game and the",
+              "roles each of them has been granted."
+            ];
+            type: {
+              vec: {
+                defined: "GameAuthority";
+              };
+            };
+          },
+          {
+            name: "threshold";
+            docs: [
+              "Number of admin approvals a [GameProposal][super::GameProposal] needs before",
+              "sensitive changes can be made. Values of `0` or `1` disable proposals."
+            ];
+            type: "u8";
+          },
+          {
+            name: "proposalCount";
+            docs: [
+              "Number of proposals created for this game. Also used to",
+              "determine the u64 seed for the next proposal."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "leaderBoard";
+      docs: [
+        "Represents a [Game][super::Game]'s leaderboard.",
+        "",
+        'Seeds: `[b"leaderboard", game.key().as_ref(), &id.to_le_bytes()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "id";
+            docs: [
+              "The leaderboard's id, used in deriving its address from the game."
+            ];
+            type: "u64";
+          },
+          {
+            name: "game";
+            docs: ["The game this leaderboard belongs to and is derived from."];
+            type: "publicKey";
+          },
+          {
+            name: "description";
+            docs: ["Leaderboard description."];
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            docs: [
+              "Pubkey of an nft metadata account that describes this leaderboard."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "decimals";
+            docs: [
+              "Used to contextualize scores for this leaderboard, as the number of fixed-point",
+              "decimal places."
+            ];
+            type: "u8";
+          },
+          {
+            name: "minScore";
+            docs: [
+              "Minimum possible score for this leaderboard, interpreted according to `score_type`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "maxScore";
+            docs: [
+              "Maximum possible score for this leaderboard, interpreted according to `score_type`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "topEntries";
+            docs: ["Top [entries](ScoreEntry) for a leaderboard."];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "allowMultipleScores";
+            docs: [
+              "Whether or not multiple scores are allowed for a single player."
+            ];
+            type: "bool";
+          },
+          {
+            name: "season";
+            docs: [
+              "The current season number, starting at `1`. Also used as a seed",
+              "for the [SeasonArchive][super::SeasonArchive] created when it closes."
+            ];
+            type: "u64";
+          },
+          {
+            name: "seasonStart";
+            docs: ["Timestamp at which the current season started."];
+            type: "i64";
+          },
+          {
+            name: "seasonEnd";
+            docs: [
+              "Optional timestamp after which scores are no longer accepted for",
+              "the current season."
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "isFrozen";
+            docs: [
+              "Set to true when the current season is closed and the leaderboard",
+              "is frozen until a new season is started."
+            ];
+            type: "bool";
+          },
+          {
+            name: "opensAt";
+            docs: ["Optional timestamp before which scores are not accepted."];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "closesAt";
+            docs: ["Optional timestamp after which scores are not accepted."];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "retention";
+            docs: [
+              "Which scores each player's [PlayerScoresList][super::PlayerScoresList] keeps."
+            ];
+            type: {
+              defined: "RetentionPolicy";
+            };
+          },
+          {
+            name: "scoringMode";
+            docs: [
+              "What each player is ranked by in the leaderboard's top entries."
+            ];
+            type: {
+              defined: "ScoringMode";
+            };
+          },
+          {
+            name: "tieBreak";
+            docs: [
+              "How entries with equal scores are ordered in the leaderboard's top entries."
+            ];
+            type: {
+              defined: "TieBreak";
+            };
+          },
+          {
+            name: "scoreType";
+            docs: ["How scores submitted to this leaderboard are interpreted."];
+            type: {
+              defined: "ScoreType";
+            };
+          },
+          {
+            name: "rateLimit";
+            docs: [
+              "Limits on how often scores can be submitted for each player."
+            ];
+            type: {
+              defined: "RateLimit";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "merged";
+      docs: [
+        "An account that represents a single user's ownership of",
+        "multiple [Player][super::Player] accounts."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "initiator";
+            docs: ["The user that initialized this merge."];
+            type: "publicKey";
+          },
+          {
+            name: "approvals";
+            docs: [
+              "Details of all the player accounts to be merged with the main_user's."
+            ];
+            type: {
+              vec: {
+                defined: "MergeApproval";
+              };
+            };
+          },
+          {
+            name: "mergeComplete";
+            docs: [
+              "Set to true when every user in `others` has registered their approval."
+            ];
+            type: "bool";
+          },
+          {
+            name: "payer";
+            docs: [
+              "The account that paid rent for this merge and is refunded when it's closed."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            docs: [
+              "Timestamp after which a pending merge can no longer be approved and can be",
+              "closed by anyone."
+            ];
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "playerAchievement";
+      docs: [
+        "Represents a player's status for a particular [Achievement](super::Achievement).",
+        "",
+        'Seeds = `[b"player-achievement", player.key().as_ref(), achievement.key().as_ref()]`.'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: ["The user's [player][super::Player] account."];
+            type: "publicKey";
+          },
+          {
+            name: "achievement";
+            docs: ["The key of the achievement unlocked for this player."];
+            type: "publicKey";
+          },
+          {
+            name: "timestamp";
+            docs: ["Timestamp showing when this achievement was unlocked."];
+            type: "i64";
+          },
+          {
+            name: "unlocked";
+            docs: ["A player's unlock status for this achievement."];
+            type: "bool";
+          },
+          {
+            name: "claimed";
+            docs: ["Whether or not this player has claimed their reward."];
+            type: "bool";
+          },
+          {
+            name: "progress";
+            docs: [
+              "Progress towards the achievement's `progress_target`, if it has one."
+            ];
+            type: "u64";
+          },
+          {
+            name: "absorbed";
+            docs: [
+              "Whether this account's state has been merged into another player's account."
+            ];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "playerScoresList";
+      docs: [
+        "Holds a list of a [player][super::Player]'s [scores][ScoreEntry])",
+        "for a particular [LeaderBoard].",
+        "",
+        'Seeds: `[b"player-scores-list", player_account.key().as_ref(), leaderboard.key().as_ref()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: [
+              "The player[super::Player] account this entry is derived from"
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "leaderboard";
+            docs: ["The id of the specific leaderboard."];
+            type: "publicKey";
+          },
+          {
+            name: "allocCount";
+            docs: [
+              "Max number of [scores][ScoreEntry] the current space allocation supports."
+            ];
+            type: "u16";
+          },
+          {
+            name: "lastNonce";
+            docs: [
+              "Nonce of the last [attestation][super::ScoreAttestation] submitted for this player.",
+              "New attestations must use a greater nonce."
+            ];
+            type: "u64";
+          },
+          {
+            name: "bestScore";
+            docs: [
+              "Best score ever submitted, ranked in the leaderboard's top-entries order or",
+              "highest-first if it has none. Zero if nothing was submitted."
+            ];
+            type: "u64";
+          },
+          {
+            name: "totalScore";
+            docs: [
+              "Sum of the values of all scores ever submitted, interpreted according to",
+              "the leaderboard's [ScoreType]."
+            ];
+            type: "i128";
+          },
+          {
+            name: "submissionCount";
+            docs: [
+              "Number of scores ever submitted, including ones no longer retained."
+            ];
+            type: "u64";
+          },
+          {
+            name: "firstSubmission";
+            docs: [
+              "Timestamp of the first submission. Zero if nothing was submitted."
+            ];
+            type: "i64";
+          },
+          {
+            name: "lastSubmission";
+            docs: [
+              "Timestamp of the latest submission. Zero if nothing was submitted."
+            ];
+            type: "i64";
+          },
+          {
+            name: "windowStart";
+            docs: [
+              "Start of the [attempt window][RateLimit::attempt_window] `window_attempts` counts."
+            ];
+            type: "i64";
+          },
+          {
+            name: "windowAttempts";
+            docs: [
+              "Number of submissions within the attempt window starting at `window_start`."
+            ];
+            type: "u32";
+          },
+          {
+            name: "season";
+            docs: [
+              "The leaderboard season `season_total_score` and `season_submission_count` cover."
+            ];
+            type: "u64";
+          },
+          {
+            name: "seasonTotalScore";
+            docs: [
+              "Sum of the values of the scores submitted during `season`, interpreted according to",
+              "the leaderboard's [ScoreType]."
+            ];
+            type: "i128";
+          },
+          {
+            name: "seasonSubmissionCount";
+            docs: ["Number of scores submitted during `season`."];
+            type: "u64";
+          },
+          {
+            name: "scores";
+            docs: ["Collection of [scores][ScoreEntry]."];
+            type: {
+              vec: {
+                defined: "ScoreEntry";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "player";
+      docs: [
+        "An account representing a player.",
+        "",
+        'Seeds: `[b"player", user.key().as_ref()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "user";
+            docs: ["The wallet that owns this player-info account"];
+            type: "publicKey";
+          },
+          {
+            name: "username";
+            docs: ["The player's username."];
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            docs: ["Metadata to represent this player."];
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "gameProposal";
+      docs: [
+        "A sensitive change to a [Game] awaiting approval from `game.threshold` of its admins.",
+        "",
+        'Seeds = `[b"proposal", game.key().as_ref(), &id.to_le_bytes()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "game";
+            docs: ["The game this proposal applies to."];
+            type: "publicKey";
+          },
+          {
+            name: "id";
+            docs: [
+              "The proposal_count of the game when this account was created,",
+              "also used as a seed for its PDA."
+            ];
+            type: "u64";
+          },
+          {
+            name: "action";
+            docs: ["The proposed change."];
+            type: {
+              defined: "ProposalAction";
+            };
+          },
+          {
+            name: "approvals";
+            docs: ["Admin authorities that have approved this proposal."];
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "executed";
+            docs: ["Set to true once the proposed change has been made."];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "rankReward";
+      docs: [
+        "A fungible token reward paid out to the holders of specific ranks in a",
+        "[LeaderBoard][super::LeaderBoard]'s [top entries][super::LeaderTopEntries]",
+        "once its season is closed.",
+        "",
+        'Seeds = `[b"rank-reward", leaderboard.key().as_ref(), &season.to_le_bytes()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "leaderboard";
+            docs: ["The leaderboard this reward is given out for."];
+            type: "publicKey";
+          },
+          {
+            name: "season";
+            docs: ["The leaderboard season this reward is given out for."];
+            type: "u64";
+          },
+          {
+            name: "mint";
+            docs: ["The mint of the token to be given out."];
+            type: "publicKey";
+          },
+          {
+            name: "account";
+            docs: ["The token account to withdraw from."];
+            type: "publicKey";
+          },
+          {
+            name: "tiers";
+            docs: ["Payout table, mapping ranges of ranks to reward amounts."];
+            type: {
+              vec: {
+                defined: "RankRewardTier";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "rankRewardClaim";
+      docs: [
+        "Existence serves as proof that the reward for a rank has been claimed.",
+        "",
+        'Seeds = `[b"rank-reward-claim", rank_reward.key().as_ref(), &rank.to_le_bytes()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: ["The player account that claimed this rank."];
+            type: "publicKey";
+          },
+          {
+            name: "rank";
+            docs: ["The claimed rank."];
+            type: "u32";
+          }
+        ];
+      };
+    },
+    {
+      name: "nftClaim";
+      type: {
+        kind: "struct";
+        fields: [];
+      };
+    },
+    {
+      name: "reward";
+      docs: ["An account representing a reward for a given achievement."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "achievement";
+            docs: ["The achievement this reward is given for."];
+            type: "publicKey";
+          },
+          {
+            name: "availableSpots";
+            docs: ["Number of available reward spots."];
+            type: "u64";
+          },
+          {
+            name: "reward";
+            docs: [
+              "The reward kind. Current supports Nft and Ft rewards only."
+            ];
+            type: {
+              defined: "RewardKind";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "seasonArchive";
+      docs: [
+        "An immutable snapshot of a [LeaderBoard][super::LeaderBoard]'s top entries,",
+        "taken when one of its seasons is closed.",
+        "",
+        'Seeds = `[b"season-archive", leaderboard.key().as_ref(), &season.to_le_bytes()]`'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "leaderboard";
+            docs: ["The leaderboard this archive belongs to."];
+            type: "publicKey";
+          },
+          {
+            name: "season";
+            docs: ["The season number this archive was taken for."];
+            type: "u64";
+          },
+          {
+            name: "start";
+            docs: ["Timestamp at which the season started."];
+            type: "i64";
+          },
+          {
+            name: "end";
+            docs: ["Timestamp at which the season was closed."];
+            type: "i64";
+          },
+          {
+            name: "isAscending";
+            docs: ["Arrangement order of `top_scores`."];
+            type: "bool";
+          },
+          {
+            name: "topScores";
+            docs: [
+              "Top scores at the time the season was closed. Seasons ranking more than",
+              "[Self::MAX_SCORES] entries can't be closed."
+            ];
+            type: {
+              vec: {
+                defined: "LeaderBoardScore";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "leaderTopEntries";
+      docs: [
+        "Legacy borsh layout of a leaderboard's top scores.",
+        "",
+        "Only kept so existing accounts can be read and converted to a [LeaderTopEntriesV2]",
+        "with `migrate_top_entries`.",
+        "",
+        'Seeds = [b"top-scores", leaderboard.key().as_ref()]'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "isAscending";
+            docs: ["Arrangement order."];
+            type: "bool";
+          },
+          {
+            name: "topScores";
+            docs: ["Top scores."];
+            type: {
+              vec: {
+                defined: "LegacyLeaderBoardScore";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "leaderTopEntriesV2";
+      docs: [
+        "Keeps track of a sorted list of top scores for a leaderboard.",
+        "",
+        "This is the zero-copy header of the account. It's followed in the account's data by",
+        "`capacity` [LeaderBoardScore]s, sorted by rank, which are accessed with [Self::split]",
+        "and [Self::split_mut] instead of being deserialized.",
+        "",
+        'Seeds = [b"top-scores", leaderboard.key().as_ref()]'
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "leaderboard";
+            docs: ["The leaderboard these entries belong to."];
+            type: "publicKey";
+          },
+          {
+            name: "capacity";
+            docs: ["Number of score slots following the header."];
+            type: "u32";
+          },
+          {
+            name: "isAscending";
+            docs: ["Arrangement order. `1` for ascending, `0` for descending."];
+            type: "u8";
+          },
+          {
+            name: "padding";
+            docs: ["Unused."];
+            type: {
+              array: ["u8", 3];
+            };
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "LegacyGame";
+      docs: [
+        "Layout of a [Game] before authority roles and proposals were added.",
+        "",
+        "Legacy accounts share [Game]'s discriminator and are told apart by their size, which",
+        "depends on the length of `auth` in both layouts. They're only read to be converted in",
+        "place with `migrate_game`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "meta";
+            docs: ["Game meta-information."];
+            type: {
+              defined: "GameAttributes";
+            };
+          },
+          {
+            name: "leaderboardCount";
+            docs: ["Number of leaderboards this game has created."];
+            type: "u64";
+          },
+          {
+            name: "achievementCount";
+            docs: ["Number of achievements that exist for this game."];
+            type: "u64";
+          },
+          {
+            name: "auth";
+            docs: [
+              "A collection of pubkeys which each represent a valid",
+              "authority for this game."
+            ];
+            type: {
+              vec: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "GameAuthority";
+      docs: ["A [Game] authority and the roles it has been granted."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "key";
+            docs: ["The authority's public key."];
+            type: "publicKey";
+          },
+          {
+            name: "roles";
+            docs: ["A bitmask of the roles granted to this authority."];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "GameAttributes";
+      docs: ["A type that represents game-specific information."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "title";
+            docs: ["The title of the game, max length = 30 bytes."];
+            type: "string";
+          },
+          {
+            name: "description";
+            docs: ["The game description, max length = 200 bytes."];
+            type: "string";
+          },
+          {
+            name: "genre";
+            docs: ["The game's [genre](super::Genre), as a u8."];
+            type: "u8";
+          },
+          {
+            name: "gameType";
+            docs: ["The game's [type](super::GameType), as a u8."];
+            type: "u8";
+          },
+          {
+            name: "nftMeta";
+            docs: ["An nft metadata account describing the game."];
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "ScoreEntry";
+      docs: ["A single score entry for a player."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "score";
+            docs: ["The player's score."];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            docs: ["When this entry was made."];
+            type: "i64";
+          },
+          {
+            name: "secondaryScore";
+            docs: [
+              "Optional secondary metric, such as a move count. Zero if not provided."
+            ];
+            type: "u64";
+          },
+          {
+            name: "context";
+            docs: [
+              "Optional opaque data linked to this entry, such as the hash of a replay.",
+              "All zeroes if not provided."
+            ];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "hasSecondaryScore";
+            docs: ["`1` if `secondary_score` was provided, `0` otherwise."];
+            type: "u8";
+          },
+          {
+            name: "padding";
+            docs: ["Unused."];
+            type: {
+              array: ["u8", 7];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyScoreEntry";
+      docs: [
+        "Layout of a [ScoreEntry] before secondary scores and context were added.",
+        "",
+        "Only kept to read legacy [LeaderTopEntries][super::LeaderTopEntries] and",
+        "[PlayerScoresList] accounts."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "score";
+            docs: ["The player's score."];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            docs: ["When this entry was made."];
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "RateLimit";
+      docs: [
+        "Limits how often scores can be submitted for each player of a leaderboard.",
+        "",
+        "Zero values disable the corresponding limit."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "minInterval";
+            docs: [
+              "Minimum number of seconds between two submissions for the same player."
+            ];
+            type: "u32";
+          },
+          {
+            name: "maxAttempts";
+            docs: [
+              "Maximum number of submissions per player, counted within each attempt window",
+              "or over the player's lifetime on the leaderboard if `attempt_window` is zero."
+            ];
+            type: "u32";
+          },
+          {
+            name: "attemptWindow";
+            docs: [
+              "Length in seconds of the fixed windows attempts are counted in, aligned to the",
+              "unix epoch. For example, `86400` counts attempts per UTC day."
+            ];
+            type: "u32";
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyMerged";
+      docs: [
+        "Layout of a [Merged] account before rent refunds and expiry were added.",
+        "",
+        "Legacy accounts share [Merged]'s discriminator and are told apart by their size. They're",
+        "only read to be converted in place with `migrate_merge`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "initiator";
+            docs: ["The user that initialized this merge."];
+            type: "publicKey";
+          },
+          {
+            name: "approvals";
+            docs: [
+              "Details of all the player accounts to be merged with the main_user's."
+            ];
+            type: {
+              vec: {
+                defined: "MergeApproval";
+              };
+            };
+          },
+          {
+            name: "mergeComplete";
+            docs: [
+              "Set to true when every user in `others` has registered their approval."
+            ];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "MergeApproval";
+      docs: [
+        "Represents a [Player][super::Player] account involved in a merge",
+        "and if that account's user/authority has granted approval."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "key";
+            docs: ["The player_account pubkey."];
+            type: "publicKey";
+          },
+          {
+            name: "approved";
+            docs: ["User's approval status."];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyPlayerAchievement";
+      docs: [
+        "Layout of a [PlayerAchievement] before progress tracking and merges were added.",
+        "",
+        "Legacy accounts share [PlayerAchievement]'s discriminator and are told apart by their",
+        "size. They're only read to be converted in place with `migrate_player_achievement`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: ["The user's [player][super::Player] account."];
+            type: "publicKey";
+          },
+          {
+            name: "achievement";
+            docs: ["The key of the achievement unlocked for this player."];
+            type: "publicKey";
+          },
+          {
+            name: "timestamp";
+            docs: ["Timestamp showing when this achievement was unlocked."];
+            type: "i64";
+          },
+          {
+            name: "unlocked";
+            docs: ["A player's unlock status for this achievement."];
+            type: "bool";
+          },
+          {
+            name: "claimed";
+            docs: ["Whether or not this player has claimed their reward."];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyPlayerScoresList";
+      docs: [
+        "Layout of a [PlayerScoresList] before aggregates, attestation nonces and rate limits",
+        "were added.",
+        "",
+        "Legacy accounts share [PlayerScoresList]'s discriminator and are told apart by their size:",
+        "`migrate_player_scores` grows them in steps before converting them, so any size from",
+        "[Self::size] up to, but excluding, [PlayerScoresList::size] for their `alloc_count` is legacy."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: [
+              "The player[super::Player] account this entry is derived from"
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "leaderboard";
+            docs: ["The id of the specific leaderboard."];
+            type: "publicKey";
+          },
+          {
+            name: "allocCount";
+            docs: [
+              "Max number of [scores][LegacyScoreEntry] the current space allocation supports."
+            ];
+            type: "u16";
+          },
+          {
+            name: "scores";
+            docs: ["Collection of [scores][LegacyScoreEntry]."];
+            type: {
+              vec: {
+                defined: "LegacyScoreEntry";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "RankRewardTier";
+      docs: [
+        "An inclusive range of 1-indexed ranks and the amount each of them receives."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "startRank";
+            docs: ["First rank in this tier."];
+            type: "u32";
+          },
+          {
+            name: "endRank";
+            docs: ["Last rank in this tier."];
+            type: "u32";
+          },
+          {
+            name: "amount";
+            docs: ["Reward amount given to each rank in this tier."];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "LeaderBoardScore";
+      docs: ["An single entry to a [LeaderTopEntriesV2]."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "player";
+            docs: ["The player"];
+            type: "publicKey";
+          },
+          {
+            name: "entry";
+            docs: ["The user's [score][super::ScoreEntry]."];
+            type: {
+              defined: "ScoreEntry";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "LegacyLeaderBoardScore";
+      docs: ["An entry to a legacy [LeaderTopEntries]."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "player";
+            docs: ["The player"];
+            type: "publicKey";
+          },
+          {
+            name: "entry";
+            docs: ["The user's score."];
+            type: {
+              defined: "LegacyScoreEntry";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "RegisterLeaderBoardInput";
+      docs: ["Parameters needed when registering a leaderboard."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "description";
+            docs: ["Leaderboard description."];
+            type: "string";
+          },
+          {
+            name: "nftMeta";
+            docs: ["Nft metadata representing the leaderboard."];
+            type: "publicKey";
+          },
+          {
+            name: "decimals";
+            docs: [
+              "Specify the decimals score values are represented in. Defaults to `0` if [None]."
+            ];
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "minScore";
+            docs: [
+              "Specifies minimum allowed score. Defaults to the lowest score representable by",
+              "`score_type` if [None]."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxScore";
+            docs: [
+              "Specifies maximum allowed score. Defaults to the highest score representable by",
+              "`score_type` if [None]."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "scoresToRetain";
+            docs: [
+              "Number of top scores to store on-chain. Can be changed later with `resize_top_entries`."
+            ];
+            type: "u16";
+          },
+          {
+            name: "isAscending";
+            docs: [
+              "Order by which scores are stored. `true` for ascending, `false` for descending."
+            ];
+            type: "bool";
+          },
+          {
+            name: "allowMultipleScores";
+            docs: [
+              "Whether or not multiple scores are kept in the leaderboard for a single player."
+            ];
+            type: "bool";
+          },
+          {
+            name: "seasonEnd";
+            docs: [
+              "Optional end timestamp for the leaderboard's first season."
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "opensAt";
+            docs: ["Optional timestamp before which scores are rejected."];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "closesAt";
+            docs: ["Optional timestamp after which scores are rejected."];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "retention";
+            docs: [
+              "Which scores are kept in each player's [PlayerScoresList]."
+            ];
+            type: {
+              defined: "RetentionPolicy";
+            };
+          },
+          {
+            name: "scoringMode";
+            docs: [
+              "What players are ranked by in the top entries. Modes other than",
+              "[ScoringMode::Best] require `allow_multiple_scores` to be false."
+            ];
+            type: {
+              defined: "ScoringMode";
+            };
+          },
+          {
+            name: "tieBreak";
+            docs: [
+              "How entries with equal scores are ordered in the top entries."
+            ];
+            type: {
+              defined: "TieBreak";
+            };
+          },
+          {
+            name: "scoreType";
+            docs: [
+              "How submitted scores are interpreted. Can't be changed later."
+            ];
+            type: {
+              defined: "ScoreType";
+            };
+          },
+          {
+            name: "rateLimit";
+            docs: [
+              "Limits on how often scores can be submitted for each player."
+            ];
+            type: {
+              defined: "RateLimit";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "UpdateLeaderBoardInput";
+      docs: [
+        "Parameters for updating a leaderboard. Fields set to [None] are left unchanged."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "newDescription";
+            docs: ["New leaderboard description."];
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "newNftMeta";
+            docs: ["New nft metadata representing the leaderboard."];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "newMinScore";
+            docs: ["New minimum allowed score."];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "newMaxScore";
+            docs: ["New maximum allowed score."];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "newIsAscending";
+            docs: [
+              "New arrangement order for the leaderboard's top entries. Can't be changed while the",
+              "season is closed."
+            ];
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "newAllowMultipleScores";
+            docs: [
+              "Whether or not multiple scores are kept in the leaderboard for a single player."
+            ];
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "newOpensAt";
+            docs: [
+              "New opening timestamp. `Some(None)` removes the lower bound."
+            ];
+            type: {
+              option: {
+                option: "i64";
+              };
+            };
+          },
+          {
+            name: "newClosesAt";
+            docs: [
+              "New closing timestamp. `Some(None)` removes the upper bound."
+            ];
+            type: {
+              option: {
+                option: "i64";
+              };
+            };
+          },
+          {
+            name: "newRetention";
+            docs: [
+              "New retention policy. Existing [PlayerScoresList]s are trimmed on their next submission."
+            ];
+            type: {
+              option: {
+                defined: "RetentionPolicy";
+              };
+            };
+          },
+          {
+            name: "newScoringMode";
+            docs: ["New scoring mode. Existing top entries are kept as-is."];
+            type: {
+              option: {
+                defined: "ScoringMode";
+              };
+            };
+          },
+          {
+            name: "newTieBreak";
+            docs: [
+              "New tie-break rule. The top entries are re-sorted under it, so it can't be changed",
+              "while the season is closed."
+            ];
+            type: {
+              option: {
+                defined: "TieBreak";
+              };
+            };
+          },
+          {
+            name: "newRateLimit";
+            docs: [
+              "New submission rate limits, applied from the next submission."
+            ];
+            type: {
+              option: {
+                defined: "RateLimit";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "ScoreAttestation";
+      docs: [
+        "A score signed off-chain by a [Game] authority with the",
+        "[attester][GameAuthority::ATTESTER] role, allowing players to submit it themselves.",
+        "",
+        "The signed message is the borsh serialization of this struct."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "playerAccount";
+            docs: ["The player account the score is for."];
+            type: "publicKey";
+          },
+          {
+            name: "leaderboard";
+            docs: ["The leaderboard the score is for."];
+            type: "publicKey";
+          },
+          {
+            name: "score";
+            docs: ["The attested score."];
+            type: "u64";
+          },
+          {
+            name: "secondaryScore";
+            docs: ["The attested secondary score, if any."];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "context";
+            docs: ["The attested context. All zeroes if not provided."];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "nonce";
+            docs: [
+              "Must be greater than the player's `last_nonce` for this leaderboard."
+            ];
+            type: "u64";
+          },
+          {
+            name: "expiry";
+            docs: [
+              "Timestamp after which the attestation can no longer be submitted."
+            ];
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "AddNewRewardInput";
+      docs: ["Input to add a new reward for an achievement."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "availableSpots";
+            docs: ["Number of rewards to be given out."];
+            type: "u64";
+          },
+          {
+            name: "kind";
+            docs: ["Specific reward kind."];
+            type: {
+              defined: "RewardKindInput";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "AddRankRewardInput";
+      docs: ["Input to add a new rank-based reward for a leaderboard season."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "deposit";
+            docs: [
+              "Amount to be delegated to this program's PDA so it can spend for reward claims."
+            ];
+            type: "u64";
+          },
+          {
+            name: "tiers";
+            docs: ["Payout table, mapping ranges of ranks to reward amounts."];
+            type: {
+              vec: {
+                defined: "RankRewardTier";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "AchievementRule";
+      docs: [
+        "A condition on a player's score submissions that unlocks an [Achievement]."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "ScoreAtLeast";
+            fields: [
+              {
+                name: "leaderboard";
+                type: "publicKey";
+              },
+              {
+                name: "score";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "ScoreAtMost";
+            fields: [
+              {
+                name: "leaderboard";
+                type: "publicKey";
+              },
+              {
+                name: "score";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "SubmissionCount";
+            fields: [
+              {
+                name: "leaderboard";
+                type: "publicKey";
+              },
+              {
+                name: "count";
+                type: "u64";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "GameType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Mobile";
+          },
+          {
+            name: "Desktop";
+          },
+          {
+            name: "Web";
+          },
+          {
+            name: "Unspecified";
+          }
+        ];
+      };
+    },
+    {
+      name: "Genre";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Rpg";
+          },
+          {
+            name: "Mmo";
+          },
+          {
+            name: "Action";
+          },
+          {
+            name: "Adventure";
+          },
+          {
+            name: "Puzzle";
+          },
+          {
+            name: "Casual";
+          },
+          {
+            name: "Unspecified";
+          }
+        ];
+      };
+    },
+    {
+      name: "RetentionPolicy";
+      docs: [
+        "Determines which [scores][ScoreEntry] a [PlayerScoresList][super::PlayerScoresList]",
+        "keeps for a leaderboard."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Unbounded";
+          },
+          {
+            name: "KeepLast";
+            fields: [
+              {
+                name: "count";
+                type: "u16";
+              }
+            ];
+          },
+          {
+            name: "KeepBest";
+            fields: [
+              {
+                name: "count";
+                type: "u16";
+              }
+            ];
+          },
+          {
+            name: "AggregateOnly";
+          }
+        ];
+      };
+    },
+    {
+      name: "ScoringMode";
+      docs: [
+        "Determines the value a player is ranked by in a leaderboard's",
+        "[top entries][super::LeaderTopEntries]."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Best";
+          },
+          {
+            name: "Latest";
+          },
+          {
+            name: "Cumulative";
+          },
+          {
+            name: "Count";
+          }
+        ];
+      };
+    },
+    {
+      name: "TieBreak";
+      docs: ["Decides which of two entries with equal scores ranks higher."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "EarlierWins";
+          },
+          {
+            name: "LaterWins";
+          },
+          {
+            name: "SecondaryScore";
+            fields: [
+              {
+                name: "isAscending";
+                type: "bool";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "ScoreType";
+      docs: [
+        "How the `u64` scores of a leaderboard are interpreted.",
+        "",
+        "Scores are always stored and submitted as `u64`. On a [ScoreType::Signed] leaderboard",
+        "they hold the two's complement bit pattern of an `i64`, i.e. `value as u64`. Together",
+        "with the leaderboard's `decimals`, this also represents fixed-point values."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Unsigned";
+          },
+          {
+            name: "Signed";
+          }
+        ];
+      };
+    },
+    {
+      name: "ProposalAction";
+      docs: [
+        "A sensitive change that needs to be approved through a [GameProposal]."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "UpdateAuth";
+            fields: [
+              {
+                name: "newAuth";
+                type: {
+                  vec: {
+                    defined: "GameAuthority";
+                  };
+                };
+              }
+            ];
+          },
+          {
+            name: "SetThreshold";
+            fields: [
+              {
+                name: "threshold";
+                type: "u8";
+              }
+            ];
+          },
+          {
+            name: "AddLeaderBoard";
+          },
+          {
+            name: "AddReward";
+            fields: [
+              {
+                name: "achievement";
+                type: "publicKey";
+              }
+            ];
+          },
+          {
+            name: "AddRankReward";
+            fields: [
+              {
+                name: "leaderboard";
+                type: "publicKey";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "RewardKind";
+      docs: ["The kind of reward to be given out."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "FungibleToken";
+            fields: [
+              {
+                name: "mint";
+                docs: ["The mint of the token to be given out."];
+                type: "publicKey";
+              },
+              {
+                name: "account";
+                docs: ["The token account to withdraw from."];
+                type: "publicKey";
+              },
+              {
+                name: "amount";
+                docs: ["Reward amount per user."];
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "NonFungibleToken";
+            fields: [
+              {
+                name: "uri";
+                docs: ["URI of the NFT to be minted."];
+                type: "string";
+              },
+              {
+                name: "name";
+                docs: ["Name of the NFT to be minted."];
+                type: "string";
+              },
+              {
+                name: "symbol";
+                docs: ["Symbol of the NFT to be minted."];
+                type: "string";
+              },
+              {
+                name: "minted";
+                docs: ["Total NFTs minted so far."];
+                type: "u64";
+              },
+              {
+                name: "collection";
+                docs: [
+                  "Optional field for a collection mint used for",
+                  "verifying minted rewards."
+                ];
+                type: {
+                  option: "publicKey";
+                };
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "RewardKindInput";
+      docs: ["Specific variant of [AddNewRewardInput]."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Ft";
+            fields: [
+              {
+                name: "deposit";
+                docs: [
+                  "Amount to be delegated to this program's PDA",
+                  "so it can spend for reward claims."
+                ];
+                type: "u64";
+              },
+              {
+                name: "amount";
+                docs: ["Amount given to a single user."];
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "Nft";
+            fields: [
+              {
+                name: "uri";
+                docs: ["Uri of the minted nft."];
+                type: "string";
+              },
+              {
+                name: "name";
+                docs: ["Name of the minted nft."];
+                type: "string";
+              },
+              {
+                name: "symbol";
+                docs: ["Symbol of the minted nft."];
+                type: "string";
+              }
+            ];
+          }
+        ];
+      };
+    }
+  ];
+  events: [
+    {
+      name: "GameCreated";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GameUpdated";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "newAttributes";
+          type: {
+            option: {
+              defined: "GameAttributes";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "newAuth";
+          type: {
+            option: {
+              vec: {
+                defined: "GameAuthority";
+              };
+            };
+          };
+          index: false;
+        },
+        {
+          name: "newThreshold";
+          type: {
+            option: "u8";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ProposalCreated";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "id";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "action";
+          type: {
+            defined: "ProposalAction";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ProposalApproved";
+      fields: [
+        {
+          name: "proposal";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "approvals";
+          type: "u32";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LeaderBoardCreated";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "id";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "topEntries";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LeaderBoardUpdated";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "changes";
+          type: {
+            defined: "UpdateLeaderBoardInput";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "SeasonClosed";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "season";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "end";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "SeasonStarted";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "season";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "start";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "end";
+          type: {
+            option: "i64";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TopEntriesResized";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "topEntries";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousLen";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "newLen";
+          type: "u32";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GameMigrated";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "auth";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerAchievementMigrated";
+      fields: [
+        {
+          name: "playerAchievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MergeMigrated";
+      fields: [
+        {
+          name: "mergeAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "expiresAt";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerScoresMigrated";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerScores";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "submissionCount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TopEntriesMigrated";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "topEntries";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "capacity";
+          type: "u32";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TopEntriesMerged";
+      fields: [
+        {
+          name: "mergeAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LeaderBoardClosed";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AchievementCreated";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "id";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AchievementUpdated";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "newTitle";
+          type: {
+            option: "string";
+          };
+          index: false;
+        },
+        {
+          name: "newDescription";
+          type: {
+            option: "string";
+          };
+          index: false;
+        },
+        {
+          name: "newMeta";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "newUnlockRule";
+          type: {
+            option: {
+              option: {
+                defined: "AchievementRule";
+              };
+            };
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AchievementClosed";
+      fields: [
+        {
+          name: "game";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerCreated";
+      fields: [
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "user";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "username";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "nftMeta";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerUpdated";
+      fields: [
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "newUsername";
+          type: {
+            option: "string";
+          };
+          index: false;
+        },
+        {
+          name: "newNftMeta";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerRegistered";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerScores";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerClosed";
+      fields: [
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerScoresClosed";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerScores";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerAchievementClosed";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAchievement";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ScoreSubmitted";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "season";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "score";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "secondaryScore";
+          type: {
+            option: "u64";
+          };
+          index: false;
+        },
+        {
+          name: "context";
+          type: {
+            array: ["u8", 32];
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "rank";
+          type: {
+            option: "u32";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AchievementUnlocked";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardAdded";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "availableSpots";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "kind";
+          type: {
+            defined: "RewardKind";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardClosed";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "NftClaimClosed";
+      fields: [
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RewardClaimed";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RankRewardAdded";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rankReward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "season";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "deposit";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RankRewardClaimed";
+      fields: [
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rankReward";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "rank";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "NftRewardVerified";
+      fields: [
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MergeInitiated";
+      fields: [
+        {
+          name: "mergeAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "others";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "mergeComplete";
+          type: "bool";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MergeApproved";
+      fields: [
+        {
+          name: "mergeAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mergeComplete";
+          type: "bool";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MergeRejected";
+      fields: [
+        {
+          name: "mergeAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAccount";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MergeCancelled";
+      fields: [
+        {
+          name: "mergeAccount";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MergeClosed";
+      fields: [
+        {
+          name: "mergeAccount";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerScoresMerged";
+      fields: [
+        {
+          name: "mergeAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "leaderboard";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerScores";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mergedPlayerScores";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlayerAchievementMerged";
+      fields: [
+        {
+          name: "mergeAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "achievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "playerAchievement";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "mergedPlayerAchievement";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidFieldLength";
+      msg: "Exceeded max length for field.";
+    },
+    {
+      code: 6001;
+      name: "InvalidAuthority";
+      msg: "Invalid authority for instruction";
+    },
+    {
+      code: 6002;
+      name: "MissingSignature";
+      msg: "An expected signature isn't present";
+    },
+    {
+      code: 6003;
+      name: "NoRewardForAchievement";
+      msg: "Reward not specified for this achievement";
+    },
+    {
+      code: 6004;
+      name: "AccountNotPartOfMerge";
+      msg: "The merge account does not include this player account";
+    },
+    {
+      code: 6005;
+      name: "ScoreNotWithinBounds";
+      msg: "Tried to input score that is below the minimum or above the maximum";
+    },
+    {
+      code: 6006;
+      name: "MissingExpectedAccount";
+      msg: "An optional but expected account is missing";
+    },
+    {
+      code: 6007;
+      name: "InvalidRewardKind";
+      msg: "Invalid reward kind for this instruction";
+    },
+    {
+      code: 6008;
+      name: "NoAvailableRewards";
+      msg: "No more rewards are being given out for this game";
+    },
+    {
+      code: 6009;
+      name: "SeasonEnded";
+      msg: "The leaderboard's current season has ended";
+    },
+    {
+      code: 6010;
+      name: "SeasonStillActive";
+      msg: "The leaderboard's current season is still active";
+    },
+    {
+      code: 6011;
+      name: "SubmissionWindowClosed";
+      msg: "Scores are not being accepted for this leaderboard at this time";
+    },
+    {
+      code: 6012;
+      name: "InvalidSubmissionWindow";
+      msg: "A leaderboard's opening time must be before its closing time";
+    },
+    {
+      code: 6013;
+      name: "InvalidRankRewardTiers";
+      msg: "Rank reward tiers must be non-empty, 1-indexed and non-overlapping";
+    },
+    {
+      code: 6014;
+      name: "SeasonNotClosed";
+      msg: "The leaderboard's current season must be closed for this instruction";
+    },
+    {
+      code: 6015;
+      name: "RankNotEligible";
+      msg: "The player does not hold a rewarded rank";
+    },
+    {
+      code: 6016;
+      name: "InvalidRemainingAccounts";
+      msg: "Remaining accounts don't match the layout expected by this instruction";
+    },
+    {
+      code: 6017;
+      name: "NoProgressTarget";
+      msg: "This achievement doesn't track progress";
+    },
+    {
+      code: 6018;
+      name: "AchievementNotUnlocked";
+      msg: "The player hasn't unlocked this achievement";
+    },
+    {
+      code: 6019;
+      name: "RewardAlreadyClaimed";
+      msg: "The reward for this achievement has already been claimed";
+    },
+    {
+      code: 6020;
+      name: "MergeNotComplete";
+      msg: "All players in the merge must approve it before its data can be aggregated";
+    },
+    {
+      code: 6021;
+      name: "MergeAlreadyComplete";
+      msg: "This merge has already been approved by every player";
+    },
+    {
+      code: 6022;
+      name: "MergeExpired";
+      msg: "This merge has expired";
+    },
+    {
+      code: 6023;
+      name: "MergeNotClosable";
+      msg: "This merge can't be closed yet";
+    },
+    {
+      code: 6024;
+      name: "NoAdminAuthority";
+      msg: "A game must have at least one admin authority";
+    },
+    {
+      code: 6025;
+      name: "ProposalRequired";
+      msg: "This change requires an approved proposal";
+    },
+    {
+      code: 6026;
+      name: "ProposalNotApproved";
+      msg: "The proposal doesn't have enough approvals or has already been executed";
+    },
+    {
+      code: 6027;
+      name: "ProposalActionMismatch";
+      msg: "The proposal doesn't match this instruction";
+    },
+    {
+      code: 6028;
+      name: "InvalidThreshold";
+      msg: "The approval threshold can't exceed the number of admin authorities";
+    },
+    {
+      code: 6029;
+      name: "RewardSpotsRemaining";
+      msg: "The reward still has unclaimed spots";
+    },
+    {
+      code: 6030;
+      name: "AchievementHasReward";
+      msg: "The achievement's reward must be closed first";
+    },
+    {
+      code: 6031;
+      name: "InvalidAttestation";
+      msg: "Missing or invalid ed25519 signature verification for the score attestation";
+    },
+    {
+      code: 6032;
+      name: "AttestationExpired";
+      msg: "The score attestation has expired";
+    },
+    {
+      code: 6033;
+      name: "AttestationReplayed";
+      msg: "The score attestation's nonce has already been used";
+    },
+    {
+      code: 6034;
+      name: "InvalidRetentionPolicy";
+      msg: "Retained score count must be between 1 and the maximum a player scores account can hold";
+    },
+    {
+      code: 6035;
+      name: "InvalidScoringMode";
+      msg: "Only the best scoring mode can allow multiple scores per player";
+    },
+    {
+      code: 6036;
+      name: "InvalidTopEntriesSize";
+      msg: "Top entries must keep at least one score and grow by at most 10KiB per instruction";
+    },
+    {
+      code: 6037;
+      name: "InvalidScoreBounds";
+      msg: "The minimum score can't be greater than the maximum score";
+    },
+    {
+      code: 6038;
+      name: "SubmissionTooSoon";
+      msg: "A score was submitted for this player too recently";
+    },
+    {
+      code: 6039;
+      name: "AttemptLimitReached";
+      msg: "The player has no attempts left for this leaderboard's current window";
+    },
+    {
+      code: 6040;
+      name: "InvalidSeasonEnd";
+      msg: "The season end must be in the future";
+    },
+    {
+      code: 6041;
+      name: "AchievementAbsorbed";
+      msg: "This achievement was merged into another player's account";
+    },
+    {
+      code: 6042;
+      name: "RankedEntriesDropped";
+      msg: "Resizing top entries would drop ranked entries";
+    },
+    {
+      code: 6043;
+      name: "LegacyAccountLayout";
+      msg: "The account is in a legacy layout and must be migrated first";
+    },
+    {
+      code: 6044;
+      name: "TooManyEntriesToArchive";
+      msg: "The top entries rank more scores than a season archive can hold";
+    }
+  ];
+};
+
+export const IDL: Soar = {
+  version: "0.1.1",
+  name: "soar",
+  constants: [
+    {
+      name: "MAX_TITLE_LEN",
+      type: {
+        defined: "usize",
+      },
+      value: "30",
+    },
+    {
+      name: "MAX_DESCRIPTION_LEN",
+      type: {
+        defined: "usize",
+      },
+      value: "200",
+    },
+  ],
+  instructions: [
+    {
+      name: "initializeGame",
+      docs: ["Initialize a new [Game] and register its [LeaderBoard]."],
+      accounts: [
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "gameMeta",
+          type: {
+            defined: "GameAttributes",
+          },
+        },
+        {
+          name: "gameAuth",
+          type: {
+            vec: {
+              defined: "GameAuthority",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "updateGame",
+      docs: ["Update a [Game]'s meta-information or authority list."],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "newMeta",
+          type: {
+            option: {
+              defined: "GameAttributes",
+            },
+          },
+        },
+        {
+          name: "newAuth",
+          type: {
+            option: {
+              vec: {
+                defined: "GameAuthority",
+              },
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "createProposal",
+      docs: [
+        "Propose a sensitive change to a [Game], registering the proposing admin's approval.",
+        "",
+        "Only needed for games whose `threshold` is greater than `1`.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "action",
+          type: {
+            defined: "ProposalAction",
+          },
+        },
+      ],
+    },
+    {
+      name: "approveProposal",
+      docs: ["Register an admin's approval for a [GameProposal]."],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "executeProposal",
+      docs: [
+        "Apply an approved [GameProposal] that changes the [Game]'s authorities or threshold.",
+        "",
+        "Proposals for other actions are consumed by the instructions they authorize.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "addAchievement",
+      docs: [
+        "Add a new [Achievement] that can be attained for a particular [Game].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "title",
+          type: "string",
+        },
+        {
+          name: "description",
+          type: "string",
+        },
+        {
+          name: "nftMeta",
+          type: "publicKey",
+        },
+        {
+          name: "unlockRule",
+          type: {
+            option: {
+              defined: "AchievementRule",
+            },
+          },
+        },
+        {
+          name: "progressTarget",
+          type: {
+            option: "u64",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateAchievement",
+      docs: ["Update an [Achievement]'s meta information or unlock rule."],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "newTitle",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "newDescription",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "nftMeta",
+          type: {
+            option: "publicKey",
+          },
+        },
+        {
+          name: "newUnlockRule",
+          type: {
+            option: {
+              option: {
+                defined: "AchievementRule",
+              },
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "addLeaderboard",
+      docs: ["Overwrite the active [LeaderBoard] and set a newly created one."],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "RegisterLeaderBoardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateLeaderboard",
+      docs: [
+        "Update's a leaderboard's description, nft metadata information, min/max score, order,",
+        "whether or not multiple scores are allowed for a single player, its submission window,",
+        "retention policy, scoring mode or tie-break rule.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "UpdateLeaderBoardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "resizeTopEntries",
+      docs: [
+        "Change the number of scores a leaderboard's [LeaderTopEntriesV2] retains, keeping",
+        "current rankings. Shrinking refunds rent to `payer`, and can only drop the",
+        "lowest-ranked entries if `drop_entries` is true.",
+        "",
+        "The account can grow by at most 10KiB per call, so large increases take several calls.",
+        "Not callable while the leaderboard's season is closed.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "newLen",
+          type: "u16",
+        },
+        {
+          name: "dropEntries",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "migrateGame",
+      docs: [
+        "Convert a [Game] from its legacy layout to the current one in place, granting each of",
+        "its authorities the admin role.",
+        "",
+        "Can be called by anyone. `payer` covers any extra rent.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateTopEntries",
+      docs: [
+        "Convert a leaderboard's top entries account from the legacy borsh [LeaderTopEntries]",
+        "layout to the zero-copy [LeaderTopEntriesV2] layout in place, keeping its rankings.",
+        "",
+        "Can be called by anyone. `payer` covers any extra rent. The account can grow by at most",
+        "10KiB per call, so large accounts take several calls and are converted by the last one.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migratePlayerScores",
+      docs: [
+        "Convert a [PlayerScoresList] from its legacy layout to the current one in place,",
+        "rebuilding its aggregates from the scores it holds.",
+        "",
+        "Can be called by anyone. `payer` covers any extra rent. The account can grow by at most",
+        "10KiB per call, so large lists take several calls and are converted by the last one.",
+        "Leaderboards with top entries must have them migrated first.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migratePlayerAchievement",
+      docs: [
+        "Convert a [PlayerAchievement] from its legacy layout to the current one in place, with",
+        "no progress recorded.",
+        "",
+        "Can be called by anyone. `payer` covers any extra rent.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateMerge",
+      docs: [
+        "Convert a [Merged] account from its legacy layout to the current one in place. Its rent",
+        "is refunded to the initiator when it's closed, and a pending merge expires a week after",
+        "being migrated.",
+        "",
+        "Can be called by anyone. `payer` covers any extra rent.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mergeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeSeason",
+      docs: [
+        "Close the current season of a [LeaderBoard], freezing it and archiving its",
+        "[LeaderTopEntriesV2] into a [SeasonArchive] account.",
+        "",
+        "Leaderboards with top entries must pass them, and can't rank more than",
+        "[SeasonArchive::MAX_SCORES] entries; shrink them with [resize_top_entries] first.",
+        "",
+        "No scores can be submitted to a frozen leaderboard until [start_season] is called.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "archive",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "startSeason",
+      docs: [
+        "Start a new season for a frozen [LeaderBoard], resetting its [LeaderTopEntriesV2].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "seasonEnd",
+          type: {
+            option: "i64",
+          },
+        },
+      ],
+    },
+    {
+      name: "initializePlayer",
+      docs: ["Create a [Player] account for a particular user."],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "username",
+          type: "string",
+        },
+        {
+          name: "nftMeta",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "updatePlayer",
+      docs: ["Update the username or nft_meta for a [Player] account."],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "username",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "nftMeta",
+          type: {
+            option: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "registerPlayer",
+      docs: [
+        "Register a [Player] for a particular [Leaderboard], resulting in a newly-",
+        "created [PlayerEntryList] account.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "newList",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "submitScore",
+      docs: [
+        "Submit a score for a player and have it timestamped and added to the [PlayerEntryList].",
+        "Optionally increase the player's rank if needed.",
+        "",
+        "On leaderboards with a [ScoreType::Signed] score type, `score` is the `i64` value cast",
+        "to `u64`.",
+        "",
+        "`secondary_score` and `context` are stored with the entry. The secondary score settles",
+        "ties on leaderboards using [TieBreak::SecondaryScore], where entries without one rank last.",
+        "",
+        "Submissions that break the [LeaderBoard]'s [RateLimit] for the player are rejected.",
+        "",
+        "This instruction automatically resizes the [PlayerScoresList] account if needed, within the",
+        "limit set by the [LeaderBoard]'s [RetentionPolicy].",
+        "",
+        "Optionally takes `(achievement, player_achievement)` pairs as remaining accounts, creating",
+        "the [PlayerAchievement] account for each [Achievement] whose unlock rule is satisfied.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "score",
+          type: "u64",
+        },
+        {
+          name: "secondaryScore",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "context",
+          type: {
+            option: {
+              array: ["u8", 32],
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "submitAttestedScore",
+      docs: [
+        "Submit a score signed off-chain by a [Game] authority with the attester role, with the",
+        "player's user signing and paying for the transaction instead of the game.",
+        "",
+        "The preceding instruction must be an ed25519 signature verification of the borsh-serialized",
+        "[ScoreAttestation] for these arguments. `nonce` must exceed the last one used for this",
+        "[PlayerScoresList], and the attestation is rejected after `expiry`.",
+        "",
+        "Takes the same optional remaining accounts as [submit_score].",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "score",
+          type: "u64",
+        },
+        {
+          name: "secondaryScore",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "context",
+          type: {
+            option: {
+              array: ["u8", 32],
+            },
+          },
+        },
+        {
+          name: "nonce",
+          type: "u64",
+        },
+        {
+          name: "expiry",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "initiateMerge",
+      docs: [
+        "Initialize a new merge account and await approval from the verified users of all the",
+        "specified [Player] accounts.",
+        "",
+        "A merge is complete when all the users of the [Player] account keys referenced in it",
+        "have signed to set their approval to `true`.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergeAccount",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "keys",
+          type: {
+            vec: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "approveMerge",
+      docs: [
+        "Register merge confirmation for a particular [Player] account included in a [Merged].",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "rejectMerge",
+      docs: [
+        "Reject a pending merge that includes the signer's [Player] account, closing the",
+        "[Merged] account and refunding its rent to the original payer.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "cancelMerge",
+      docs: [
+        "Cancel a pending merge as its initiator, closing the [Merged] account and refunding",
+        "its rent to the original payer.",
+      ],
+      accounts: [
+        {
+          name: "initiator",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "mergeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeMerge",
+      docs: [
+        "Close a [Merged] account and refund its rent to the original payer.",
+        "",
+        "Pending merges can be closed by anyone once expired. Completed merges can only be",
+        "closed by their initiator.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "mergeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "mergePlayerScores",
+      docs: [
+        "Move all scores from a merged [Player]'s [PlayerScoresList] into the merge initiator's",
+        "list for the same [LeaderBoard], keeping only what the leaderboard's retention policy allows.",
+        "",
+        "Only callable once the [Merged] account is complete.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mergeAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergedPlayerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "mergePlayerAchievement",
+      docs: [
+        "Fold a merged [Player]'s [PlayerAchievement] into the merge initiator's, creating it if",
+        "needed.",
+        "",
+        "Only callable once the [Merged] account is complete. Each merged [PlayerAchievement]",
+        "can only be folded in once.",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mergeAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mergedPlayerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "mergeTopEntries",
+      docs: [
+        "Reassign every [LeaderTopEntriesV2] slot held by a merged [Player] to the merge initiator's",
+        "[Player], keeping only its best entry if the [LeaderBoard] doesn't allow multiple scores.",
+        "",
+        "Only callable once the [Merged] account is complete, and not while the leaderboard's",
+        "season is closed.",
+      ],
+      accounts: [
+        {
+          name: "mergeAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "unlockPlayerAchievement",
+      docs: [
+        "Unlock a [PlayerAchievement] account without minting a reward.",
+        "",
+        "Used `ONLY` for custom rewards mechanism to setup a [PlayerAchievement] account that",
+        "can serve as a gated verification-method for claims.",
+        "",
+        "Claim instructions like [claim_ft_reward] and [claim_nft_reward] still accept an",
+        "account unlocked this way, as long as its reward hasn't been claimed.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "incrementAchievementProgress",
+      docs: [
+        "Increase a player's progress towards an [Achievement] with a `progress_target`,",
+        "creating their [PlayerAchievement] account if needed and unlocking it once the",
+        "target is reached.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "addFtReward",
+      docs: [
+        "Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.",
+        "",
+        "Overwrites the current reward if one exists.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newReward",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "delegateFromTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccountOwner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "AddNewRewardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "addNftReward",
+      docs: [
+        "Add a nft [Reward] to an [Achievement] to mint to users on unlock.",
+        "",
+        "Overwrites the current reward if one exists.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newReward",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardCollectionMint",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "collectionUpdateAuth",
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: "collectionMetadata",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "AddNewRewardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "addRankReward",
+      docs: [
+        "Add a fungible token [RankReward] paying out to the top ranks of a [LeaderBoard]'s",
+        "current season.",
+        "",
+        "Tiers can't reward ranks beyond the capacity of the leaderboard's [LeaderTopEntriesV2]",
+        "or [SeasonArchive::MAX_SCORES].",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rankReward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardTokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "delegateFromTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccountOwner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "input",
+          type: {
+            defined: "AddRankRewardInput",
+          },
+        },
+      ],
+    },
+    {
+      name: "claimRankReward",
+      docs: [
+        "Claim the [RankReward] for a player holding `rank` in the [SeasonArchive] of the",
+        "reward's season.",
+        "",
+        "Each rank can be claimed only once.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rankReward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "archive",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sourceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "rank",
+          type: "u32",
+        },
+      ],
+    },
+    {
+      name: "claimFtReward",
+      docs: [
+        "Transfer an FT reward for unlocking a [PlayerAchievement] account.",
+        "",
+        "This will create the [PlayerAchievement] account if it doesn't exist yet. If it does,",
+        "it must already be unlocked and not yet claimed.",
+        "",
+        "Relevant `ONLY` if an FT reward is specified for that achievement.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sourceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimNftReward",
+      docs: [
+        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
+        "",
+        "This will create the [PlayerAchievement] account if it doesn't exist yet. If it does,",
+        "it must already be unlocked and not yet claimed.",
+        "",
+        "Relevant `ONLY` if an NFT reward is specified for that achievement.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newMint",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "newMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newMasterEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintTo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeAchievement",
+      docs: [
+        "Close an [Achievement] that has no [Reward] attached, refunding its rent to `receiver`.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeReward",
+      docs: [
+        "Close a [Reward] with no available spots left, detaching it from its [Achievement]",
+        "and refunding its rent to `receiver`.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeLeaderboard",
+      docs: [
+        "Close a [LeaderBoard] whose current season is closed, along with its",
+        "[LeaderTopEntriesV2] if it has one, refunding their rent to `receiver`.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "topEntries",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closePlayerScores",
+      docs: [
+        "Close a user's own [PlayerScoresList], refunding its rent to `receiver`.",
+        "",
+        "Only allowed once the [LeaderBoard]'s season is closed, or the leaderboard itself is,",
+        "since the list holds the player's attestation nonce and rate limit state.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerScores",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closePlayerAchievement",
+      docs: [
+        "Close a [PlayerAchievement], refunding its rent to `receiver`.",
+        "",
+        "Closing it allows the achievement to be unlocked again. Accounts that claimed, or were",
+        "merged into another player's, can't be closed while the achievement has a reward.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeNftClaim",
+      docs: [
+        "Close an [NftClaim], refunding its rent to `receiver`.",
+        "",
+        "The claimed NFT can no longer be verified as part of the reward's collection afterwards.",
+      ],
+      accounts: [
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closePlayer",
+      docs: [
+        "Close a user's own [Player] account, refunding its rent to `receiver`.",
+      ],
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "receiver",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "verifyNftReward",
+      docs: [
+        "Verify NFT reward as belonging to a particular collection.",
+        "",
+        "Optional: Only relevant if an NFT reward is specified and the reward's",
+        "`collection_mint` is Some(...)",
+      ],
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "game",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "achievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reward",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "playerAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claim",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "playerAchievement",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadataToVerify",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "collectionMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionEdition",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "achievement",
+      docs: [
+        "Represents an achievement(with optional rewards) for this game",
+        "that can be attained by players.",
+        "",
+        'PDA with seeds = `[b"achievement", game.key().as_ref(), &id.to_le_bytes()]`',
+        "",
+        "`id` is an incrementing index stored in the game account.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "game",
+            docs: [
+              "Public key of the game account this achievement is derived from.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "id",
+            docs: [
+              "The achievement_count of the game account when this account was",
+              "created, also used as a seed for its PDA.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "title",
+            docs: ["Achievement title."],
+            type: "string",
+          },
+          {
+            name: "description",
+            docs: ["Achievement description."],
+            type: "string",
+          },
+          {
+            name: "nftMeta",
+            docs: [
+              "Public key of a nft metadata account describing this achievement.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "reward",
+            docs: [
+              "Optional: Specify a reward to players for unlocking this achievement.",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "unlockRule",
+            docs: [
+              "Optional: A rule that automatically unlocks this achievement for a",
+              "player when satisfied during a score submission.",
+            ],
+            type: {
+              option: {
+                defined: "AchievementRule",
+              },
+            },
+          },
+          {
+            name: "progressTarget",
+            docs: [
+              "Optional: A progress value players must reach through",
+              "[increment_achievement_progress][crate::soar::increment_achievement_progress]",
+              "for this achievement to be unlocked.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "game",
+      docs: ["An account representing a single game."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "meta",
+            docs: ["Game meta-information."],
+            type: {
+              defined: "GameAttributes",
+            },
+          },
+          {
+            name: "leaderboardCount",
+            docs: [
+              "Number of leaderboards this game has created. Used both",
+              "in determining the most recent leaderboard address, and",
+              "as a seed for the next leaderboard.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "achievementCount",
+            docs: [
+              "Number of achievements that exist for this game. Also",
+              "used to determine the u64 seed for the next achievement.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "auth",
+            docs: [
+              "A collection of valid authorities for this game and the",
+              "roles each of them has been granted.",
+            ],
+            type: {
+              vec: {
+                defined: "GameAuthority",
+              },
+            },
+          },
+          {
+            name: "threshold",
+            docs: [
+              "Number of admin approvals a [GameProposal][super::GameProposal] needs before",
+              "sensitive changes can be made. Values of `0` or `1` disable proposals.",
+            ],
+            type: "u8",
+          },
+          {
+            name: "proposalCount",
+            docs: [
+              "Number of proposals created for this game. Also used to",
+              "determine the u64 seed for the next proposal.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "leaderBoard",
+      docs: [
+        "Represents a [Game][super::Game]'s leaderboard.",
+        "",
+        'Seeds: `[b"leaderboard", game.key().as_ref(), &id.to_le_bytes()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "id",
+            docs: [
+              "The leaderboard's id, used in deriving its address from the game.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "game",
+            docs: ["The game this leaderboard belongs to and is derived from."],
+            type: "publicKey",
+          },
+          {
+            name: "description",
+            docs: ["Leaderboard description."],
+            type: "string",
+          },
+          {
+            name: "nftMeta",
+            docs: [
+              "Pubkey of an nft metadata account that describes this leaderboard.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "decimals",
+            docs: [
+              "Used to contextualize scores for this leaderboard, as the number of fixed-point",
+              "decimal places.",
+            ],
+            type: "u8",
+          },
+          {
+            name: "minScore",
+            docs: [
+              "Minimum possible score for this leaderboard, interpreted according to `score_type`.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "maxScore",
+            docs: [
+              "Maximum possible score for this leaderboard, interpreted according to `score_type`.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "topEntries",
+            docs: ["Top [entries](ScoreEntry) for a leaderboard."],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "allowMultipleScores",
+            docs: [
+              "Whether or not multiple scores are allowed for a single player.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "season",
+            docs: [
+              "The current season number, starting at `1`. Also used as a seed",
+              "for the [SeasonArchive][super::SeasonArchive] created when it closes.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "seasonStart",
+            docs: ["Timestamp at which the current season started."],
+            type: "i64",
+          },
+          {
+            name: "seasonEnd",
+            docs: [
+              "Optional timestamp after which scores are no longer accepted for",
+              "the current season.",
+            ],
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "isFrozen",
+            docs: [
+              "Set to true when the current season is closed and the leaderboard",
+              "is frozen until a new season is started.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "opensAt",
+            docs: ["Optional timestamp before which scores are not accepted."],
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "closesAt",
+            docs: ["Optional timestamp after which scores are not accepted."],
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "retention",
+            docs: [
+              "Which scores each player's [PlayerScoresList][super::PlayerScoresList] keeps.",
+            ],
+            type: {
+              defined: "RetentionPolicy",
+            },
+          },
+          {
+            name: "scoringMode",
+            docs: [
+              "What each player is ranked by in the leaderboard's top entries.",
+            ],
+            type: {
+              defined: "ScoringMode",
+            },
+          },
+          {
+            name: "tieBreak",
+            docs: [
+              "How entries with equal scores are ordered in the leaderboard's top entries.",
+            ],
+            type: {
+              defined: "TieBreak",
+            },
+          },
+          {
+            name: "scoreType",
+            docs: ["How scores submitted to this leaderboard are interpreted."],
+            type: {
+              defined: "ScoreType",
+            },
+          },
+          {
+            name: "rateLimit",
+            docs: [
+              "Limits on how often scores can be submitted for each player.",
+            ],
+            type: {
+              defined: "RateLimit",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "merged",
+      docs: [
+        "An account that represents a single user's ownership of",
+        "multiple [Player][super::Player] accounts.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "initiator",
+            docs: ["The user that initialized this merge."],
+            type: "publicKey",
+          },
+          {
+            name: "approvals",
+            docs: [
+              "Details of all the player accounts to be merged with the main_user's.",
+            ],
+            type: {
+              vec: {
+                defined: "MergeApproval",
+              },
+            },
+          },
+          {
+            name: "mergeComplete",
+            docs: [
+              "Set to true when every user in `others` has registered their approval.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "payer",
+            docs: [
+              "The account that paid rent for this merge and is refunded when it's closed.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "expiresAt",
+            docs: [
+              "Timestamp after which a pending merge can no longer be approved and can be",
+              "closed by anyone.",
+            ],
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "playerAchievement",
+      docs: [
+        "Represents a player's status for a particular [Achievement](super::Achievement).",
+        "",
+        'Seeds = `[b"player-achievement", player.key().as_ref(), achievement.key().as_ref()]`.',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "playerAccount",
+            docs: ["The user's [player][super::Player] account."],
+            type: "publicKey",
+          },
+          {
+            name: "achievement",
+            docs: ["The key of the achievement unlocked for this player."],
+            type: "publicKey",
+          },
+          {
+            name: "timestamp",
+            docs: ["Timestamp showing when this achievement was unlocked."],
+            type: "i64",
+          },
+          {
+            name: "unlocked",
+            docs: ["A player's unlock status for this achievement."],
+            type: "bool",
+          },
+          {
+            name: "claimed",
+            docs: ["Whether or not this player has claimed their reward."],
+            type: "bool",
+          },
+          {
+            name: "progress",
+            docs: [
+              "Progress towards the achievement's `progress_target`, if it has one.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "absorbed",
+            docs: [
+              "Whether this account's state has been merged into another player's account.",
+            ],
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "playerScoresList",
+      docs: [
+        "Holds a list of a [player][super::Player]'s [scores][ScoreEntry])",
+        "for a particular [LeaderBoard].",
+        "",
+        'Seeds: `[b"player-scores-list", player_account.key().as_ref(), leaderboard.key().as_ref()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "playerAccount",
+            docs: [
+              "The player[super::Player] account this entry is derived from",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "leaderboard",
+            docs: ["The id of the specific leaderboard."],
+            type: "publicKey",
+          },
+          {
+            name: "allocCount",
+            docs: [
+              "Max number of [scores][ScoreEntry] the current space allocation supports.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "lastNonce",
+            docs: [
+              "Nonce of the last [attestation][super::ScoreAttestation] submitted for this player.",
+              "New attestations must use a greater nonce.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "bestScore",
+            docs: [
+              "Best score ever submitted, ranked in the leaderboard's top-entries order or",
+              "highest-first if it has none. Zero if nothing was submitted.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "totalScore",
+            docs: [
+              "Sum of the values of all scores ever submitted, interpreted according to",
+              "the leaderboard's [ScoreType].",
+            ],
+            type: "i128",
+          },
+          {
+            name: "submissionCount",
+            docs: [
+              "Number of scores ever submitted, including ones no longer retained.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "firstSubmission",
+            docs: [
+              "Timestamp of the first submission. Zero if nothing was submitted.",
+            ],
+            type: "i64",
+          },
+          {
+            name: "lastSubmission",
+            docs: [
+              "Timestamp of the latest submission. Zero if nothing was submitted.",
+            ],
+            type: "i64",
+          },
+          {
+            name: "windowStart",
+            docs: [
+              "Start of the [attempt window][RateLimit::attempt_window] `window_attempts` counts.",
+            ],
+            type: "i64",
+          },
+          {
+            name: "windowAttempts",
+            docs: [
+              "Number of submissions within the attempt window starting at `window_start`.",
+            ],
+            type: "u32",
+          },
+          {
+            name: "season",
+            docs: [
+              "The leaderboard season `season_total_score` and `season_submission_count` cover.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "seasonTotalScore",
+            docs: [
+              "Sum of the values of the scores submitted during `season`, interpreted according to",
+              "the leaderboard's [ScoreType].",
+            ],
+            type: "i128",
+          },
+          {
+            name: "seasonSubmissionCount",
+            docs: ["Number of scores submitted during `season`."],
+            type: "u64",
+          },
+          {
+            name: "scores",
+            docs: ["Collection of [scores][ScoreEntry]."],
+            type: {
+              vec: {
+                defined: "ScoreEntry",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "player",
+      docs: [
+        "An account representing a player.",
+        "",
+        'Seeds: `[b"player", user.key().as_ref()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "user",
+            docs: ["The wallet that owns this player-info account"],
+            type: "publicKey",
+          },
+          {
+            name: "username",
+            docs: ["The player's username."],
+            type: "string",
+          },
+          {
+            name: "nftMeta",
+            docs: ["Metadata to represent this player."],
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "gameProposal",
+      docs: [
+        "A sensitive change to a [Game] awaiting approval from `game.threshold` of its admins.",
+        "",
+        'Seeds = `[b"proposal", game.key().as_ref(), &id.to_le_bytes()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "game",
+            docs: ["The game this proposal applies to."],
+            type: "publicKey",
+          },
+          {
+            name: "id",
+            docs: [
+              "The proposal_count of the game when this account was created,",
+              "also used as a seed for its PDA.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "action",
+            docs: ["The proposed change."],
+            type: {
+              defined: "ProposalAction",
+            },
+          },
+          {
+            name: "approvals",
+            docs: ["Admin authorities that have approved this proposal."],
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "executed",
+            docs: ["Set to true once the proposed change has been made."],
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "rankReward",
+      docs: [
+        "A fungible token reward paid out to the holders of specific ranks in a",
+        "[LeaderBoard][super::LeaderBoard]'s [top entries][super::LeaderTopEntries]",
+        "once its season is closed.",
+        "",
+        'Seeds = `[b"rank-reward", leaderboard.key().as_ref(), &season.to_le_bytes()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "leaderboard",
+            docs: ["The leaderboard this reward is given out for."],
+            type: "publicKey",
+          },
+          {
+            name: "season",
+            docs: ["The leaderboard season this reward is given out for."],
+            type: "u64",
+          },
+          {
+            name: "mint",
+            docs: ["The mint of the token to be given out."],
+            type: "publicKey",
+          },
+          {
+            name: "account",
+            docs: ["The token account to withdraw from."],
+            type: "publicKey",
+          },
+          {
+            name: "tiers",
+            docs: ["Payout table, mapping ranges of ranks to reward amounts."],
+            type: {
+              vec: {
+                defined: "RankRewardTier",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "rankRewardClaim",
+      docs: [
+        "Existence serves as proof that the reward for a rank has been claimed.",
+        "",
+        'Seeds = `[b"rank-reward-claim", rank_reward.key().as_ref(), &rank.to_le_bytes()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "title";
-            docs: ["Achievement title."];
-            type: "string";
+            name: "playerAccount",
+            docs: ["The player account that claimed this rank."],
+            type: "publicKey",
           },
           {
-            name: "description";
-            docs: ["Achievement description."];
-            type: "string";
+            name: "rank",
+            docs: ["The claimed rank."],
+            type: "u32",
+          },
+        ],
+      },
+    },
+    {
+      name: "nftClaim",
+      type: {
+        kind: "struct",
+        fields: [],
+      },
+    },
+    {
+      name: "reward",
+      docs: ["An account representing a reward for a given achievement."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "achievement",
+            docs: ["The achievement this reward is given for."],
+            type: "publicKey",
           },
           {
-            name: "nftMeta";
+            name: "availableSpots",
+            docs: ["Number of available reward spots."],
+            type: "u64",
+          },
+          {
+            name: "reward",
             docs: [
-              "Public key of a nft metadata account describing this achievement."
-            ];
-            type: "publicKey";
+              "The reward kind. Current supports Nft and Ft rewards only.",
+            ],
+            type: {
+              defined: "RewardKind",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "seasonArchive",
+      docs: [
+        "An immutable snapshot of a [LeaderBoard][super::LeaderBoard]'s top entries,",
+        "taken when one of its seasons is closed.",
+        "",
+        'Seeds = `[b"season-archive", leaderboard.key().as_ref(), &season.to_le_bytes()]`',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "leaderboard",
+            docs: ["The leaderboard this archive belongs to."],
+            type: "publicKey",
           },
           {
-            name: "reward";
+            name: "season",
+            docs: ["The season number this archive was taken for."],
+            type: "u64",
+          },
+          {
+            name: "start",
+            docs: ["Timestamp at which the season started."],
+            type: "i64",
+          },
+          {
+            name: "end",
+            docs: ["Timestamp at which the season was closed."],
+            type: "i64",
+          },
+          {
+            name: "isAscending",
+            docs: ["Arrangement order of `top_scores`."],
+            type: "bool",
+          },
+          {
+            name: "topScores",
             docs: [
-              "Optional: Specify a reward to players for unlocking this achievement."
-            ];
+              "Top scores at the time the season was closed. Seasons ranking more than",
+              "[Self::MAX_SCORES] entries can't be closed.",
+            ],
             type: {
-              option: "publicKey";
-            };
-          }
-        ];
-      };
+              vec: {
+                defined: "LeaderBoardScore",
+              },
+            },
+          },
+        ],
+      },
     },
     {
-      name: "game";
-      docs: ["An account representing a single game."];
+      name: "leaderTopEntries",
+      docs: [
+        "Legacy borsh layout of a leaderboard's top scores.",
+        "",
+        "Only kept so existing accounts can be read and converted to a [LeaderTopEntriesV2]",
+        "with `migrate_top_entries`.",
+        "",
+        'Seeds = [b"top-scores", leaderboard.key().as_ref()]',
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "meta";
-            docs: ["Game meta-information."];
+            name: "isAscending",
+            docs: ["Arrangement order."],
+            type: "bool",
+          },
+          {
+            name: "topScores",
+            docs: ["Top scores."],
             type: {
-              defined: "GameAttributes";
-            };
+              vec: {
+                defined: "LegacyLeaderBoardScore",
+              },
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "leaderTopEntriesV2",
+      docs: [
+        "Keeps track of a sorted list of top scores for a leaderboard.",
+        "",
+        "This is the zero-copy header of the account. It's followed in the account's data by",
+        "`capacity` [LeaderBoardScore]s, sorted by rank, which are accessed with [Self::split]",
+        "and [Self::split_mut] instead of being deserialized.",
+        "",
+        'Seeds = [b"top-scores", leaderboard.key().as_ref()]',
+      ],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "leaderboardCount";
-            docs: [
-              "Number of leaderboards this game has created. Used both",
-              "in determining the most recent leaderboard address, and",
-              "as a seed for the next leaderboard."
-            ];
-            type: "u64";
+            name: "leaderboard",
+            docs: ["The leaderboard these entries belong to."],
+            type: "publicKey",
           },
           {
-            name: "achievementCount";
-            docs: [
-              "Number of achievements that exist for this game. Also",
-              "used to determine the u64 seed for the next achievement."
-            ];
-            type: "u64";
+            name: "capacity",
+            docs: ["Number of score slots following the header."],
+            type: "u32",
           },
           {
-            name: "auth";
-            docs: [
-              "A collection of pubkeys which each represent a valid",
-              "authority for this game."
-            ];
+            name: "isAscending",
+            docs: ["Arrangement order. `1` for ascending, `0` for descending."],
+            type: "u8",
+          },
+          {
+            name: "padding",
+            docs: ["Unused."],
             type: {
-              vec: "publicKey";
-            };
-          }
-        ];
-      };
+              array: ["u8", 3],
+            },
+          },
+        ],
+      },
     },
+  ],
+  types: [
     {
-      name: "leaderBoard";
+      name: "LegacyGame",
       docs: [
-        "Represents a [Game][super::Game]'s leaderboard.",
+        "Layout of a [Game] before authority roles and proposals were added.",
         "",
-        'Seeds: `[b"leaderboard", game.key().as_ref(), &id.to_le_bytes()]`'
-      ];
+        "Legacy accounts share [Game]'s discriminator and are told apart by their size, which",
+        "depends on the length of `auth` in both layouts. They're only read to be converted in",
+        "place with `migrate_game`.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "id";
-            docs: [
-              "The leaderboard's id, used in deriving its address from the game."
-            ];
-            type: "u64";
+            name: "meta",
+            docs: ["Game meta-information."],
+            type: {
+              defined: "GameAttributes",
+            },
           },
           {
-            name: "game";
-            docs: ["The game this leaderboard belongs to and is derived from."];
-            type: "publicKey";
+            name: "leaderboardCount",
+            docs: ["Number of leaderboards this game has created."],
+            type: "u64",
           },
           {
-            name: "description";
-            docs: ["Leaderboard description."];
-            type: "string";
+            name: "achievementCount",
+            docs: ["Number of achievements that exist for this game."],
+            type: "u64",
           },
           {
-            name: "nftMeta";
+            name: "auth",
             docs: [
-              "Pubkey of an nft metadata account that describes this leaderboard."
-            ];
-            type: "publicKey";
+              "A collection of pubkeys which each represent a valid",
+              "authority for this game.",
+            ],
+            type: {
+              vec: "publicKey",
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "GameAuthority",
+      docs: ["A [Game] authority and the roles it has been granted."],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "decimals";
-            docs: ["Used to contextualize scores for this leaderboard."];
-            type: "u8";
+            name: "key",
+            docs: ["The authority's public key."],
+            type: "publicKey",
           },
           {
-            name: "minScore";
-            docs: ["Minimum possible score for this leaderboard."];
-            type: "u64";
+            name: "roles",
+            docs: ["A bitmask of the roles granted to this authority."],
+            type: "u8",
           },
+        ],
+      },
+    },
+    {
+      name: "GameAttributes",
+      docs: ["A type that represents game-specific information."],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "maxScore";
-            docs: ["Maximum possible score for this leaderboard."];
-            type: "u64";
+            name: "title",
+            docs: ["The title of the game, max length = 30 bytes."],
+            type: "string",
           },
           {
-            name: "topEntries";
-            docs: ["Top [entries](ScoreEntry) for a leaderboard."];
-            type: {
-              option: "publicKey";
-            };
+            name: "description",
+            docs: ["The game description, max length = 200 bytes."],
+            type: "string",
           },
           {
-            name: "allowMultipleScores";
-            docs: [
-              "Whether or not multiple scores are allowed for a single player."
-            ];
-            type: "bool";
-          }
-        ];
-      };
+            name: "genre",
+            docs: ["The game's [genre](super::Genre), as a u8."],
+            type: "u8",
+          },
+          {
+            name: "gameType",
+            docs: ["The game's [type](super::GameType), as a u8."],
+            type: "u8",
+          },
+          {
+            name: "nftMeta",
+            docs: ["An nft metadata account describing the game."],
+            type: "publicKey",
+          },
+        ],
+      },
     },
     {
-      name: "merged";
-      docs: [
-        "An account that represents a single user's ownership of",
-        "multiple [Player][super::Player] accounts."
-      ];
+      name: "ScoreEntry",
+      docs: ["A single score entry for a player."],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "initiator";
-            docs: ["The user that initialized this merge."];
-            type: "publicKey";
+            name: "score",
+            docs: ["The player's score."],
+            type: "u64",
           },
           {
-            name: "approvals";
+            name: "timestamp",
+            docs: ["When this entry was made."],
+            type: "i64",
+          },
+          {
+            name: "secondaryScore",
             docs: [
-              "Details of all the player accounts to be merged with the main_user's."
-            ];
-            type: {
-              vec: {
-                defined: "MergeApproval";
-              };
-            };
+              "Optional secondary metric, such as a move count. Zero if not provided.",
+            ],
+            type: "u64",
           },
           {
-            name: "mergeComplete";
+            name: "context",
             docs: [
-              "Set to true when every user in `others` has registered their approval."
-            ];
-            type: "bool";
-          }
-        ];
-      };
+              "Optional opaque data linked to this entry, such as the hash of a replay.",
+              "All zeroes if not provided.",
+            ],
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "hasSecondaryScore",
+            docs: ["`1` if `secondary_score` was provided, `0` otherwise."],
+            type: "u8",
+          },
+          {
+            name: "padding",
+            docs: ["Unused."],
+            type: {
+              array: ["u8", 7],
+            },
+          },
+        ],
+      },
     },
     {
-      name: "playerAchievement";
+      name: "LegacyScoreEntry",
       docs: [
-        "Represents a player's status for a particular [Achievement](super::Achievement).",
+        "Layout of a [ScoreEntry] before secondary scores and context were added.",
         "",
-        'Seeds = `[b"player-achievement", player.key().as_ref(), achievement.key().as_ref()]`.'
-      ];
+        "Only kept to read legacy [LeaderTopEntries][super::LeaderTopEntries] and",
+        "[PlayerScoresList] accounts.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "playerAccount";
-            docs: ["The user's [player][super::Player] account."];
-            type: "publicKey";
-          },
-          {
-            name: "achievement";
-            docs: ["The key of the achievement unlocked for this player."];
-            type: "publicKey";
-          },
-          {
-            name: "timestamp";
-            docs: ["Timestamp showing when this achievement was unlocked."];
-            type: "i64";
+            name: "score",
+            docs: ["The player's score."],
+            type: "u64",
           },
           {
-            name: "unlocked";
-            docs: ["A player's unlock status for this achievement."];
-            type: "bool";
+            name: "timestamp",
+            docs: ["When this entry was made."],
+            type: "i64",
           },
-          {
-            name: "claimed";
-            docs: ["Whether or not this player has claimed their reward."];
-            type: "bool";
-          }
-        ];
-      };
+        ],
+      },
     },
     {
-      name: "playerScoresList";
+      name: "RateLimit",
       docs: [
-        "Holds a list of a [player][super::Player]'s [scores][ScoreEntry])",
-        "for a particular [LeaderBoard].",
+        "Limits how often scores can be submitted for each player of a leaderboard.",
         "",
-        'Seeds: `[b"player-scores-list", player_account.key().as_ref(), leaderboard.key().as_ref()]`'
-      ];
+        "Zero values disable the corresponding limit.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "playerAccount";
+            name: "minInterval",
             docs: [
-              "The player[super::Player] account this entry is derived from"
-            ];
-            type: "publicKey";
+              "Minimum number of seconds between two submissions for the same player.",
+            ],
+            type: "u32",
           },
           {
-            name: "leaderboard";
-            docs: ["The id of the specific leaderboard."];
-            type: "publicKey";
+            name: "maxAttempts",
+            docs: [
+              "Maximum number of submissions per player, counted within each attempt window",
+              "or over the player's lifetime on the leaderboard if `attempt_window` is zero.",
+            ],
+            type: "u32",
           },
           {
-            name: "allocCount";
+            name: "attemptWindow",
             docs: [
-              "Max number of [scores][ScoreEntry] the current space allocation supports."
-            ];
-            type: "u16";
+              "Length in seconds of the fixed windows attempts are counted in, aligned to the",
+              "unix epoch. For example, `86400` counts attempts per UTC day.",
+            ],
+            type: "u32",
           },
-          {
-            name: "scores";
-            docs: ["Collection of [scores][ScoreEntry]."];
-            type: {
-              vec: {
-                defined: "ScoreEntry";
-              };
-            };
-          }
-        ];
-      };
+        ],
+      },
     },
     {
-      name: "player";
+      name: "LegacyMerged",
       docs: [
-        "An account representing a player.",
+        "Layout of a [Merged] account before rent refunds and expiry were added.",
         "",
-        'Seeds: `[b"player", user.key().as_ref()]`'
-      ];
+        "Legacy accounts share [Merged]'s discriminator and are told apart by their size. They're",
+        "only read to be converted in place with `migrate_merge`.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "user";
-            docs: ["The wallet that owns this player-info account"];
-            type: "publicKey";
+            name: "initiator",
+            docs: ["The user that initialized this merge."],
+            type: "publicKey",
           },
           {
-            name: "username";
-            docs: ["The player's username."];
-            type: "string";
+            name: "approvals",
+            docs: [
+              "Details of all the player accounts to be merged with the main_user's.",
+            ],
+            type: {
+              vec: {
+                defined: "MergeApproval",
+              },
+            },
           },
           {
-            name: "nftMeta";
-            docs: ["Metadata to represent this player."];
-            type: "publicKey";
-          }
-        ];
-      };
-    },
-    {
-      name: "nftClaim";
-      type: {
-        kind: "struct";
-        fields: [];
-      };
+            name: "mergeComplete",
+            docs: [
+              "Set to true when every user in `others` has registered their approval.",
+            ],
+            type: "bool",
+          },
+        ],
+      },
     },
     {
-      name: "reward";
-      docs: ["An account representing a reward for a given achievement."];
+      name: "MergeApproval",
+      docs: [
+        "Represents a [Player][super::Player] account involved in a merge",
+        "and if that account's user/authority has granted approval.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "achievement";
-            docs: ["The achievement this reward is given for."];
-            type: "publicKey";
+            name: "key",
+            docs: ["The player_account pubkey."],
+            type: "publicKey",
           },
           {
-            name: "availableSpots";
-            docs: ["Number of available reward spots."];
-            type: "u64";
+            name: "approved",
+            docs: ["User's approval status."],
+            type: "bool",
           },
-          {
-            name: "reward";
-            docs: [
-              "The reward kind. Current supports Nft and Ft rewards only."
-            ];
-            type: {
-              defined: "RewardKind";
-            };
-          }
-        ];
-      };
+        ],
+      },
     },
     {
-      name: "leaderTopEntries";
+      name: "LegacyPlayerAchievement",
       docs: [
-        "Keeps track of a sorted list of top scores for a leaderboard.",
+        "Layout of a [PlayerAchievement] before progress tracking and merges were added.",
         "",
-        'Seeds = [b"top-scores", leaderboard.key().as_ref()]'
-      ];
+        "Legacy accounts share [PlayerAchievement]'s discriminator and are told apart by their",
+        "size. They're only read to be converted in place with `migrate_player_achievement`.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "isAscending";
-            docs: ["Arrangement order."];
-            type: "bool";
+            name: "playerAccount",
+            docs: ["The user's [player][super::Player] account."],
+            type: "publicKey",
           },
           {
-            name: "topScores";
-            docs: ["Top scores."];
-            type: {
-              vec: {
-                defined: "LeaderBoardScore";
-              };
-            };
-          }
-        ];
-      };
-    }
-  ];
-  types: [
+            name: "achievement",
+            docs: ["The key of the achievement unlocked for this player."],
+            type: "publicKey",
+          },
+          {
+            name: "timestamp",
+            docs: ["Timestamp showing when this achievement was unlocked."],
+            type: "i64",
+          },
+          {
+            name: "unlocked",
+            docs: ["A player's unlock status for this achievement."],
+            type: "bool",
+          },
+          {
+            name: "claimed",
+            docs: ["Whether or not this player has claimed their reward."],
+            type: "bool",
+          },
+        ],
+      },
+    },
     {
-      name: "GameAttributes";
-      docs: ["A type that represents game-specific information."];
+      name: "LegacyPlayerScoresList",
+      docs: [
+        "Layout of a [PlayerScoresList] before aggregates, attestation nonces and rate limits",
+        "were added.",
+        "",
+        "Legacy accounts share [PlayerScoresList]'s discriminator and are told apart by their size:",
+        "`migrate_player_scores` grows them in steps before converting them, so any size from",
+        "[Self::size] up to, but excluding, [PlayerScoresList::size] for their `alloc_count` is legacy.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "title";
-            docs: ["The title of the game, max length = 30 bytes."];
-            type: "string";
+            name: "playerAccount",
+            docs: [
+              "The player[super::Player] account this entry is derived from",
+            ],
+            type: "publicKey",
           },
           {
-            name: "description";
-            docs: ["The game description, max length = 200 bytes."];
-            type: "string";
+            name: "leaderboard",
+            docs: ["The id of the specific leaderboard."],
+            type: "publicKey",
           },
           {
-            name: "genre";
-            docs: ["The game's [genre](super::Genre), as a u8."];
-            type: "u8";
+            name: "allocCount",
+            docs: [
+              "Max number of [scores][LegacyScoreEntry] the current space allocation supports.",
+            ],
+            type: "u16",
           },
           {
-            name: "gameType";
-            docs: ["The game's [type](super::GameType), as a u8."];
-            type: "u8";
+            name: "scores",
+            docs: ["Collection of [scores][LegacyScoreEntry]."],
+            type: {
+              vec: {
+                defined: "LegacyScoreEntry",
+              },
+            },
           },
-          {
-            name: "nftMeta";
-            docs: ["An nft metadata account describing the game."];
-            type: "publicKey";
-          }
-        ];
-      };
+        ],
+      },
     },
     {
-      name: "ScoreEntry";
-      docs: ["A single score entry for a player."];
+      name: "RankRewardTier",
+      docs: [
+        "An inclusive range of 1-indexed ranks and the amount each of them receives.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "score";
-            docs: ["The player's score."];
-            type: "u64";
+            name: "startRank",
+            docs: ["First rank in this tier."],
+            type: "u32",
           },
           {
-            name: "timestamp";
-            docs: ["When this entry was made."];
-            type: "i64";
-          }
-        ];
-      };
+            name: "endRank",
+            docs: ["Last rank in this tier."],
+            type: "u32",
+          },
+          {
+            name: "amount",
+            docs: ["Reward amount given to each rank in this tier."],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "MergeApproval";
-      docs: [
-        "Represents a [Player][super::Player] account involved in a merge",
-        "and if that account's user/authority has granted approval."
-      ];
+      name: "LeaderBoardScore",
+      docs: ["An single entry to a [LeaderTopEntriesV2]."],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "key";
-            docs: ["The player_account pubkey."];
-            type: "publicKey";
+            name: "player",
+            docs: ["The player"],
+            type: "publicKey",
           },
           {
-            name: "approved";
-            docs: ["User's approval status."];
-            type: "bool";
-          }
-        ];
-      };
+            name: "entry",
+            docs: ["The user's [score][super::ScoreEntry]."],
+            type: {
+              defined: "ScoreEntry",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "LeaderBoardScore";
-      docs: ["An single entry to a [LeaderTopEntries]."];
+      name: "LegacyLeaderBoardScore",
+      docs: ["An entry to a legacy [LeaderTopEntries]."],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "player";
-            docs: ["The player"];
-            type: "publicKey";
+            name: "player",
+            docs: ["The player"],
+            type: "publicKey",
           },
           {
-            name: "entry";
-            docs: ["The user's [score][super::ScoreEntry]."];
+            name: "entry",
+            docs: ["The user's score."],
             type: {
-              defined: "ScoreEntry";
-            };
-          }
-        ];
-      };
+              defined: "LegacyScoreEntry",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "RegisterLeaderBoardInput";
-      docs: ["Parameters needed when registering a leaderboard."];
+      name: "RegisterLeaderBoardInput",
+      docs: ["Parameters needed when registering a leaderboard."],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "description";
-            docs: ["Leaderboard description."];
-            type: "string";
+            name: "description",
+            docs: ["Leaderboard description."],
+            type: "string",
           },
           {
-            name: "nftMeta";
-            docs: ["Nft metadata representing the leaderboard."];
-            type: "publicKey";
+            name: "nftMeta",
+            docs: ["Nft metadata representing the leaderboard."],
+            type: "publicKey",
           },
           {
-            name: "decimals";
+            name: "decimals",
             docs: [
-              "Specify the decimals score values are represented in. Defaults to `0` if [None]."
-            ];
+              "Specify the decimals score values are represented in. Defaults to `0` if [None].",
+            ],
             type: {
-              option: "u8";
-            };
+              option: "u8",
+            },
           },
           {
-            name: "minScore";
+            name: "minScore",
             docs: [
-              "Specifies minimum allowed score. Defaults to `u64::MIN` if [None]."
-            ];
+              "Specifies minimum allowed score. Defaults to the lowest score representable by",
+              "`score_type` if [None].",
+            ],
             type: {
-              option: "u64";
-            };
+              option: "u64",
+            },
           },
           {
-            name: "maxScore";
+            name: "maxScore",
             docs: [
-              "Specifies maximum allowed score. Defaults to `u64::MAX` if [None]."
-            ];
+              "Specifies maximum allowed score. Defaults to the highest score representable by",
+              "`score_type` if [None].",
+            ],
             type: {
-              option: "u64";
-            };
+              option: "u64",
+            },
           },
           {
-            name: "scoresToRetain";
-            docs: ["Number of top scores to store on-chain."];
-            type: "u8";
+            name: "scoresToRetain",
+            docs: [
+              "Number of top scores to store on-chain. Can be changed later with `resize_top_entries`.",
+            ],
+            type: "u16",
           },
           {
-            name: "isAscending";
+            name: "isAscending",
             docs: [
-              "Order by which scores are stored. `true` for ascending, `false` for descending."
-            ];
-            type: "bool";
+              "Order by which scores are stored. `true` for ascending, `false` for descending.",
+            ],
+            type: "bool",
           },
           {
-            name: "allowMultipleScores";
+            name: "allowMultipleScores",
             docs: [
-              "Whether or not multiple scores are kept in the leaderboard for a single player."
-            ];
-            type: "bool";
-          }
-        ];
-      };
-    },
-    {
-      name: "AddNewRewardInput";
-      docs: ["Input to add a new reward for an achievement."];
-      type: {
-        kind: "struct";
-        fields: [
+              "Whether or not multiple scores are kept in the leaderboard for a single player.",
+            ],
+            type: "bool",
+          },
           {
-            name: "availableSpots";
-            docs: ["Number of rewards to be given out."];
-            type: "u64";
+            name: "seasonEnd",
+            docs: [
+              "Optional end timestamp for the leaderboard's first season.",
+            ],
+            type: {
+              option: "i64",
+            },
           },
           {
-            name: "kind";
-            docs: ["Specific reward kind."];
+            name: "opensAt",
+            docs: ["Optional timestamp before which scores are rejected."],
             type: {
-              defined: "RewardKindInput";
-            };
-          }
-        ];
-      };
-    },
-    {
-      name: "GameType";
-      type: {
-        kind: "enum";
-        variants: [
+              option: "i64",
+            },
+          },
           {
-            name: "Mobile";
+            name: "closesAt",
+            docs: ["Optional timestamp after which scores are rejected."],
+            type: {
+              option: "i64",
+            },
           },
           {
-            name: "Desktop";
+            name: "retention",
+            docs: [
+              "Which scores are kept in each player's [PlayerScoresList].",
+            ],
+            type: {
+              defined: "RetentionPolicy",
+            },
           },
           {
-            name: "Web";
+            name: "scoringMode",
+            docs: [
+              "What players are ranked by in the top entries. Modes other than",
+              "[ScoringMode::Best] require `allow_multiple_scores` to be false.",
+            ],
+            type: {
+              defined: "ScoringMode",
+            },
           },
           {
-            name: "Unspecified";
-          }
-        ];
-      };
+            name: "tieBreak",
+            docs: [
+              "How entries with equal scores are ordered in the top entries.",
+            ],
+            type: {
+              defined: "TieBreak",
+            },
+          },
+          {
+            name: "scoreType",
+            docs: [
+              "How submitted scores are interpreted. Can't be changed later.",
+            ],
+            type: {
+              defined: "ScoreType",
+            },
+          },
+          {
+            name: "rateLimit",
+            docs: [
+              "Limits on how often scores can be submitted for each player.",
+            ],
+            type: {
+              defined: "RateLimit",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "Genre";
-      type: {
-        kind: "enum";
-        variants: [
+      name: "UpdateLeaderBoardInput",
+      docs: [
+        "Parameters for updating a leaderboard. Fields set to [None] are left unchanged.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "Rpg";
+            name: "newDescription",
+            docs: ["New leaderboard description."],
+            type: {
+              option: "string",
+            },
           },
           {
-            name: "Mmo";
+            name: "newNftMeta",
+            docs: ["New nft metadata representing the leaderboard."],
+            type: {
+              option: "publicKey",
+            },
           },
           {
-            name: "Action";
+            name: "newMinScore",
+            docs: ["New minimum allowed score."],
+            type: {
+              option: "u64",
+            },
           },
           {
-            name: "Adventure";
+            name: "newMaxScore",
+            docs: ["New maximum allowed score."],
+            type: {
+              option: "u64",
+            },
           },
           {
-            name: "Puzzle";
+            name: "newIsAscending",
+            docs: [
+              "New arrangement order for the leaderboard's top entries. Can't be changed while the",
+              "season is closed.",
+            ],
+            type: {
+              option: "bool",
+            },
           },
           {
-            name: "Casual";
+            name: "newAllowMultipleScores",
+            docs: [
+              "Whether or not multiple scores are kept in the leaderboard for a single player.",
+            ],
+            type: {
+              option: "bool",
+            },
           },
           {
-            name: "Unspecified";
-          }
-        ];
-      };
-    },
-    {
-      name: "RewardKind";
-      docs: ["The kind of reward to be given out."];
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "FungibleToken";
-            fields: [
-              {
-                name: "mint";
-                docs: ["The mint of the token to be given out."];
-                type: "publicKey";
-              },
-              {
-                name: "account";
-                docs: ["The token account to withdraw from."];
-                type: "publicKey";
+            name: "newOpensAt",
+            docs: [
+              "New opening timestamp. `Some(None)` removes the lower bound.",
+            ],
+            type: {
+              option: {
+                option: "i64",
               },
-              {
-                name: "amount";
-                docs: ["Reward amount per user."];
-                type: "u64";
-              }
-            ];
+            },
           },
           {
-            name: "NonFungibleToken";
-            fields: [
-              {
-                name: "uri";
-                docs: ["URI of the NFT to be minted."];
-                type: "string";
-              },
-              {
-                name: "name";
-                docs: ["Name of the NFT to be minted."];
-                type: "string";
-              },
-              {
-                name: "symbol";
-                docs: ["Symbol of the NFT to be minted."];
-                type: "string";
-              },
-              {
-                name: "minted";
-                docs: ["Total NFTs minted so far."];
-                type: "u64";
+            name: "newClosesAt",
+            docs: [
+              "New closing timestamp. `Some(None)` removes the upper bound.",
+            ],
+            type: {
+              option: {
+                option: "i64",
               },
-              {
-                name: "collection";
-                docs: [
-                  "Optional field for a collection mint used for",
-                  "verifying minted rewards."
-                ];
-                type: {
-                  option: "publicKey";
-                };
-              }
-            ];
-          }
-        ];
-      };
-    },
-    {
-      name: "RewardKindInput";
-      docs: ["Specific variant of [AddNewRewardInput]."];
-      type: {
-        kind: "enum";
-        variants: [
+            },
+          },
           {
-            name: "Ft";
-            fields: [
-              {
-                name: "deposit";
-                docs: [
-                  "Amount to be delegated to this program's PDA",
-                  "so it can spend for reward claims."
-                ];
-                type: "u64";
+            name: "newRetention",
+            docs: [
+              "New retention policy. Existing [PlayerScoresList]s are trimmed on their next submission.",
+            ],
+            type: {
+              option: {
+                defined: "RetentionPolicy",
               },
-              {
-                name: "amount";
-                docs: ["Amount given to a single user."];
-                type: "u64";
-              }
-            ];
+            },
           },
           {
-            name: "Nft";
-            fields: [
-              {
-                name: "uri";
-                docs: ["Uri of the minted nft."];
-                type: "string";
-              },
-              {
-                name: "name";
-                docs: ["Name of the minted nft."];
-                type: "string";
+            name: "newScoringMode",
+            docs: ["New scoring mode. Existing top entries are kept as-is."],
+            type: {
+              option: {
+                defined: "ScoringMode",
               },
-              {
-                name: "symbol";
-                docs: ["Symbol of the minted nft."];
-                type: "string";
-              }
-            ];
-          }
-        ];
-      };
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "InvalidFieldLength";
-      msg: "Exceeded max length for field.";
-    },
-    {
-      code: 6001;
-      name: "InvalidAuthority";
-      msg: "Invalid authority for instruction";
-    },
-    {
-      code: 6002;
-      name: "MissingSignature";
-      msg: "An expected signature isn't present";
-    },
-    {
-      code: 6003;
-      name: "NoRewardForAchievement";
-      msg: "Reward not specified for this achievement";
-    },
-    {
-      code: 6004;
-      name: "AccountNotPartOfMerge";
-      msg: "The merge account does not include this player account";
-    },
-    {
-      code: 6005;
-      name: "ScoreNotWithinBounds";
-      msg: "Tried to input score that is below the minimum or above the maximum";
-    },
-    {
-      code: 6006;
-      name: "MissingExpectedAccount";
-      msg: "An optional but expected account is missing";
-    },
-    {
-      code: 6007;
-      name: "InvalidRewardKind";
-      msg: "Invalid reward kind for this instruction";
-    },
-    {
-      code: 6008;
-      name: "NoAvailableRewards";
-      msg: "No more rewards are being given out for this game";
-    }
-  ];
-};
-
-export const IDL: Soar = {
-  version: "0.1.1",
-  name: "soar",
-  constants: [
-    {
-      name: "MAX_TITLE_LEN",
-      type: {
-        defined: "usize",
-      },
-      value: "30",
-    },
-    {
-      name: "MAX_DESCRIPTION_LEN",
-      type: {
-        defined: "usize",
-      },
-      value: "200",
-    },
-  ],
-  instructions: [
-    {
-      name: "initializeGame",
-      docs: ["Initialize a new [Game] and register its [LeaderBoard]."],
-      accounts: [
-        {
-          name: "creator",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "gameMeta",
-          type: {
-            defined: "GameAttributes",
+            },
           },
-        },
-        {
-          name: "gameAuth",
-          type: {
-            vec: "publicKey",
+          {
+            name: "newTieBreak",
+            docs: [
+              "New tie-break rule. The top entries are re-sorted under it, so it can't be changed",
+              "while the season is closed.",
+            ],
+            type: {
+              option: {
+                defined: "TieBreak",
+              },
+            },
           },
-        },
-      ],
+          {
+            name: "newRateLimit",
+            docs: [
+              "New submission rate limits, applied from the next submission.",
+            ],
+            type: {
+              option: {
+                defined: "RateLimit",
+              },
+            },
+          },
+        ],
+      },
     },
     {
-      name: "updateGame",
-      docs: ["Update a [Game]'s meta-information or authority list."],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
+      name: "ScoreAttestation",
+      docs: [
+        "A score signed off-chain by a [Game] authority with the",
+        "[attester][GameAuthority::ATTESTER] role, allowing players to submit it themselves.",
+        "",
+        "The signed message is the borsh serialization of this struct.",
       ],
-      args: [
-        {
-          name: "newMeta",
-          type: {
-            option: {
-              defined: "GameAttributes",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "playerAccount",
+            docs: ["The player account the score is for."],
+            type: "publicKey",
+          },
+          {
+            name: "leaderboard",
+            docs: ["The leaderboard the score is for."],
+            type: "publicKey",
+          },
+          {
+            name: "score",
+            docs: ["The attested score."],
+            type: "u64",
+          },
+          {
+            name: "secondaryScore",
+            docs: ["The attested secondary score, if any."],
+            type: {
+              option: "u64",
             },
           },
-        },
-        {
-          name: "newAuth",
-          type: {
-            option: {
-              vec: "publicKey",
+          {
+            name: "context",
+            docs: ["The attested context. All zeroes if not provided."],
+            type: {
+              array: ["u8", 32],
             },
           },
-        },
-      ],
+          {
+            name: "nonce",
+            docs: [
+              "Must be greater than the player's `last_nonce` for this leaderboard.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "expiry",
+            docs: [
+              "Timestamp after which the attestation can no longer be submitted.",
+            ],
+            type: "i64",
+          },
+        ],
+      },
     },
     {
-      name: "addAchievement",
-      docs: [
-        "Add a new [Achievement] that can be attained for a particular [Game].",
-      ],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "newAchievement",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "title",
-          type: "string",
-        },
-        {
-          name: "description",
-          type: "string",
-        },
-        {
-          name: "nftMeta",
-          type: "publicKey",
-        },
-      ],
+      name: "AddNewRewardInput",
+      docs: ["Input to add a new reward for an achievement."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "availableSpots",
+            docs: ["Number of rewards to be given out."],
+            type: "u64",
+          },
+          {
+            name: "kind",
+            docs: ["Specific reward kind."],
+            type: {
+              defined: "RewardKindInput",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "updateAchievement",
-      docs: ["Update an [Achievement]'s meta information."],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "achievement",
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "newTitle",
-          type: {
-            option: "string",
-          },
-        },
-        {
-          name: "newDescription",
-          type: {
-            option: "string",
+      name: "AddRankRewardInput",
+      docs: ["Input to add a new rank-based reward for a leaderboard season."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "deposit",
+            docs: [
+              "Amount to be delegated to this program's PDA so it can spend for reward claims.",
+            ],
+            type: "u64",
           },
-        },
-        {
-          name: "nftMeta",
-          type: {
-            option: "publicKey",
+          {
+            name: "tiers",
+            docs: ["Payout table, mapping ranges of ranks to reward amounts."],
+            type: {
+              vec: {
+                defined: "RankRewardTier",
+              },
+            },
           },
-        },
-      ],
+        ],
+      },
     },
     {
-      name: "addLeaderboard",
-      docs: ["Overwrite the active [LeaderBoard] and set a newly created one."],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "leaderboard",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "topEntries",
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
+      name: "AchievementRule",
+      docs: [
+        "A condition on a player's score submissions that unlocks an [Achievement].",
       ],
-      args: [
-        {
-          name: "input",
-          type: {
-            defined: "RegisterLeaderBoardInput",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "ScoreAtLeast",
+            fields: [
+              {
+                name: "leaderboard",
+                type: "publicKey",
+              },
+              {
+                name: "score",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "ScoreAtMost",
+            fields: [
+              {
+                name: "leaderboard",
+                type: "publicKey",
+              },
+              {
+                name: "score",
+                type: "u64",
+              },
+            ],
           },
-        },
-      ],
+          {
+            name: "SubmissionCount",
+            fields: [
+              {
+                name: "leaderboard",
+                type: "publicKey",
+              },
+              {
+                name: "count",
+                type: "u64",
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      name: "updateLeaderboard",
-      docs: [
-        "Update's a leaderboard's description, nft metadata information, min/max score, or whether",
-        "or not multiple scores are allowed for a single player.",
-      ],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "leaderboard",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "topEntries",
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
-        },
-      ],
-      args: [
-        {
-          name: "newDescription",
-          type: {
-            option: "string",
+      name: "GameType",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Mobile",
           },
-        },
-        {
-          name: "newNftMeta",
-          type: {
-            option: "publicKey",
+          {
+            name: "Desktop",
           },
-        },
-        {
-          name: "newMinScore",
-          type: {
-            option: "u64",
+          {
+            name: "Web",
           },
-        },
-        {
-          name: "newMaxScore",
-          type: {
-            option: "u64",
+          {
+            name: "Unspecified",
           },
-        },
-        {
-          name: "newIsAscending",
-          type: {
-            option: "bool",
+        ],
+      },
+    },
+    {
+      name: "Genre",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Rpg",
           },
-        },
-        {
-          name: "newAllowMultipleScores",
-          type: {
-            option: "bool",
+          {
+            name: "Mmo",
           },
-        },
-      ],
+          {
+            name: "Action",
+          },
+          {
+            name: "Adventure",
+          },
+          {
+            name: "Puzzle",
+          },
+          {
+            name: "Casual",
+          },
+          {
+            name: "Unspecified",
+          },
+        ],
+      },
     },
     {
-      name: "initializePlayer",
-      docs: ["Create a [Player] account for a particular user."],
-      accounts: [
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "user",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "playerAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "username",
-          type: "string",
-        },
-        {
-          name: "nftMeta",
-          type: "publicKey",
-        },
+      name: "RetentionPolicy",
+      docs: [
+        "Determines which [scores][ScoreEntry] a [PlayerScoresList][super::PlayerScoresList]",
+        "keeps for a leaderboard.",
       ],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Unbounded",
+          },
+          {
+            name: "KeepLast",
+            fields: [
+              {
+                name: "count",
+                type: "u16",
+              },
+            ],
+          },
+          {
+            name: "KeepBest",
+            fields: [
+              {
+                name: "count",
+                type: "u16",
+              },
+            ],
+          },
+          {
+            name: "AggregateOnly",
+          },
+        ],
+      },
     },
     {
-      name: "updatePlayer",
-      docs: ["Update the username or nft_meta for a [Player] account."],
-      accounts: [
-        {
-          name: "user",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "playerAccount",
-          isMut: true,
-          isSigner: false,
-        },
+      name: "ScoringMode",
+      docs: [
+        "Determines the value a player is ranked by in a leaderboard's",
+        "[top entries][super::LeaderTopEntries].",
       ],
-      args: [
-        {
-          name: "username",
-          type: {
-            option: "string",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Best",
           },
-        },
-        {
-          name: "nftMeta",
-          type: {
-            option: "publicKey",
+          {
+            name: "Latest",
           },
-        },
-      ],
+          {
+            name: "Cumulative",
+          },
+          {
+            name: "Count",
+          },
+        ],
+      },
     },
     {
-      name: "registerPlayer",
-      docs: [
-        "Register a [Player] for a particular [Leaderboard], resulting in a newly-",
-        "created [PlayerEntryList] account.",
-      ],
-      accounts: [
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "user",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "leaderboard",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "newList",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "TieBreak",
+      docs: ["Decides which of two entries with equal scores ranks higher."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "EarlierWins",
+          },
+          {
+            name: "LaterWins",
+          },
+          {
+            name: "SecondaryScore",
+            fields: [
+              {
+                name: "isAscending",
+                type: "bool",
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      name: "submitScore",
+      name: "ScoreType",
       docs: [
-        "Submit a score for a player and have it timestamped and added to the [PlayerEntryList].",
-        "Optionally increase the player's rank if needed.",
+        "How the `u64` scores of a leaderboard are interpreted.",
         "",
-        "This instruction automatically resizes the [PlayerScoresList] account if needed.",
-      ],
-      accounts: [
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "leaderboard",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "playerScores",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "topEntries",
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "score",
-          type: "u64",
-        },
+        "Scores are always stored and submitted as `u64`. On a [ScoreType::Signed] leaderboard",
+        "they hold the two's complement bit pattern of an `i64`, i.e. `value as u64`. Together",
+        "with the leaderboard's `decimals`, this also represents fixed-point values.",
       ],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Unsigned",
+          },
+          {
+            name: "Signed",
+          },
+        ],
+      },
     },
     {
-      name: "initiateMerge",
+      name: "ProposalAction",
       docs: [
-        "Initialize a new merge account and await approval from the verified users of all the",
-        "specified [Player] accounts.",
-        "",
-        "A merge is complete when all the users of the [Player] account keys referenced in it",
-        "have signed to set their approval to `true`.",
-      ],
-      accounts: [
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "user",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "mergeAccount",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
+        "A sensitive change that needs to be approved through a [GameProposal].",
       ],
-      args: [
-        {
-          name: "keys",
-          type: {
-            vec: "publicKey",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "UpdateAuth",
+            fields: [
+              {
+                name: "newAuth",
+                type: {
+                  vec: {
+                    defined: "GameAuthority",
+                  },
+                },
+              },
+            ],
           },
-        },
-      ],
+          {
+            name: "SetThreshold",
+            fields: [
+              {
+                name: "threshold",
+                type: "u8",
+              },
+            ],
+          },
+          {
+            name: "AddLeaderBoard",
+          },
+          {
+            name: "AddReward",
+            fields: [
+              {
+                name: "achievement",
+                type: "publicKey",
+              },
+            ],
+          },
+          {
+            name: "AddRankReward",
+            fields: [
+              {
+                name: "leaderboard",
+                type: "publicKey",
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      name: "approveMerge",
-      docs: [
-        "Register merge confirmation for a particular [Player] account included in a [Merged].",
-      ],
-      accounts: [
-        {
-          name: "user",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "mergeAccount",
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "RewardKind",
+      docs: ["The kind of reward to be given out."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "FungibleToken",
+            fields: [
+              {
+                name: "mint",
+                docs: ["The mint of the token to be given out."],
+                type: "publicKey",
+              },
+              {
+                name: "account",
+                docs: ["The token account to withdraw from."],
+                type: "publicKey",
+              },
+              {
+                name: "amount",
+                docs: ["Reward amount per user."],
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "NonFungibleToken",
+            fields: [
+              {
+                name: "uri",
+                docs: ["URI of the NFT to be minted."],
+                type: "string",
+              },
+              {
+                name: "name",
+                docs: ["Name of the NFT to be minted."],
+                type: "string",
+              },
+              {
+                name: "symbol",
+                docs: ["Symbol of the NFT to be minted."],
+                type: "string",
+              },
+              {
+                name: "minted",
+                docs: ["Total NFTs minted so far."],
+                type: "u64",
+              },
+              {
+                name: "collection",
+                docs: [
+                  "Optional field for a collection mint used for",
+                  "verifying minted rewards.",
+                ],
+                type: {
+                  option: "publicKey",
+                },
+              },
+            ],
+          },
+        ],
+      },
+    },
+    {
+      name: "RewardKindInput",
+      docs: ["Specific variant of [AddNewRewardInput]."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Ft",
+            fields: [
+              {
+                name: "deposit",
+                docs: [
+                  "Amount to be delegated to this program's PDA",
+                  "so it can spend for reward claims.",
+                ],
+                type: "u64",
+              },
+              {
+                name: "amount",
+                docs: ["Amount given to a single user."],
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "Nft",
+            fields: [
+              {
+                name: "uri",
+                docs: ["Uri of the minted nft."],
+                type: "string",
+              },
+              {
+                name: "name",
+                docs: ["Name of the minted nft."],
+                type: "string",
+              },
+              {
+                name: "symbol",
+                docs: ["Symbol of the minted nft."],
+                type: "string",
+              },
+            ],
+          },
+        ],
+      },
     },
+  ],
+  events: [
     {
-      name: "unlockPlayerAchievement",
-      docs: [
-        "Unlock a [PlayerAchievement] account without minting a reward.",
-        "",
-        "Used `ONLY` for custom rewards mechanism to setup a [PlayerAchievement] account that",
-        "can serve as a gated verification-method for claims.",
-        "",
-        "Since claim instructions like [claim_ft_reward] and [claim_nft_reward] for reward types",
-        "defined by this program try to initialize this account and will fail if it already exists,",
-        "calling this means opting out of using these functions.",
-      ],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
-        },
+      name: "GameCreated",
+      fields: [
         {
           name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "achievement",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "playerAchievement",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "addFtReward",
-      docs: [
-        "Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.",
-        "",
-        "Overwrites the current reward if one exists.",
-      ],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
+      name: "GameUpdated",
+      fields: [
         {
           name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "achievement",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "newReward",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "rewardTokenMint",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "delegateFromTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenAccountOwner",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "input",
+          name: "newAttributes",
           type: {
-            defined: "AddNewRewardInput",
+            option: {
+              defined: "GameAttributes",
+            },
           },
-        },
-      ],
-    },
-    {
-      name: "addNftReward",
-      docs: [
-        "Add a nft [Reward] to an [Achievement] to mint to users on unlock.",
-        "",
-        "Overwrites the current reward if one exists.",
-      ],
-      accounts: [
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "achievement",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "newReward",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          index: false,
         },
         {
-          name: "rewardCollectionMint",
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: "collectionUpdateAuth",
-          isMut: false,
-          isSigner: true,
-          isOptional: true,
-        },
-        {
-          name: "collectionMetadata",
-          isMut: true,
-          isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: "newAuth",
+          type: {
+            option: {
+              vec: {
+                defined: "GameAuthority",
+              },
+            },
+          },
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "input",
+          name: "newThreshold",
           type: {
-            defined: "AddNewRewardInput",
+            option: "u8",
           },
+          index: false,
         },
       ],
     },
     {
-      name: "claimFtReward",
-      docs: [
-        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
-        "",
-        "This will attempt to create a [PlayerAchievement] account and fail if it already exists.",
-        "",
-        "Relevant `ONLY` if an FT reward is specified for that achievement.",
-      ],
-      accounts: [
-        {
-          name: "user",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
+      name: "ProposalCreated",
+      fields: [
         {
           name: "game",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "achievement",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "reward",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "playerAchievement",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "sourceTokenAccount",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "userTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "proposal",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "id",
+          type: "u64",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "action",
+          type: {
+            defined: "ProposalAction",
+          },
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "claimNftReward",
-      docs: [
-        "Mint an NFT reward for unlocking a [PlayerAchievement] account.",
-        "",
-        "This will attempt to create a [PlayerAchievement] account and fail if it already exists.",
-        "",
-        "Relevant `ONLY` if an NFT reward is specified for that achievement.",
-      ],
-      accounts: [
+      name: "ProposalApproved",
+      fields: [
         {
-          name: "user",
-          isMut: false,
-          isSigner: false,
+          name: "proposal",
+          type: "publicKey",
+          index: false,
         },
         {
           name: "authority",
-          isMut: false,
-          isSigner: true,
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "approvals",
+          type: "u32",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "LeaderBoardCreated",
+      fields: [
         {
           name: "game",
-          isMut: false,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "achievement",
-          isMut: false,
-          isSigner: false,
+          name: "id",
+          type: "u64",
+          index: false,
         },
         {
-          name: "reward",
-          isMut: true,
-          isSigner: false,
+          name: "topEntries",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "LeaderBoardUpdated",
+      fields: [
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "playerAchievement",
-          isMut: true,
-          isSigner: false,
+          name: "changes",
+          type: {
+            defined: "UpdateLeaderBoardInput",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "SeasonClosed",
+      fields: [
         {
-          name: "claim",
-          isMut: true,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "newMint",
-          isMut: true,
-          isSigner: true,
+          name: "season",
+          type: "u64",
+          index: false,
         },
         {
-          name: "newMetadata",
-          isMut: true,
-          isSigner: false,
+          name: "end",
+          type: "i64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "SeasonStarted",
+      fields: [
         {
-          name: "newMasterEdition",
-          isMut: true,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "mintTo",
-          isMut: true,
-          isSigner: false,
+          name: "season",
+          type: "u64",
+          index: false,
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "start",
+          type: "i64",
+          index: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "end",
+          type: {
+            option: "i64",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "TopEntriesResized",
+      fields: [
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "topEntries",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "previousLen",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "newLen",
+          type: "u32",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "verifyNftReward",
-      docs: [
-        "Verify NFT reward as belonging to a particular collection.",
-        "",
-        "Optional: Only relevant if an NFT reward is specified and the reward's",
-        "`collection_mint` is Some(...)",
-      ],
-      accounts: [
+      name: "GameMigrated",
+      fields: [
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "game",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "game",
-          isMut: false,
-          isSigner: false,
+          name: "auth",
+          type: {
+            vec: "publicKey",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "PlayerAchievementMigrated",
+      fields: [
         {
-          name: "achievement",
-          isMut: false,
-          isSigner: false,
+          name: "playerAchievement",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "reward",
-          isMut: false,
-          isSigner: false,
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "user",
-          isMut: false,
-          isSigner: true,
+          name: "achievement",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "MergeMigrated",
+      fields: [
         {
-          name: "playerAccount",
-          isMut: false,
-          isSigner: false,
+          name: "mergeAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "claim",
-          isMut: false,
-          isSigner: false,
+          name: "expiresAt",
+          type: "i64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "PlayerScoresMigrated",
+      fields: [
         {
-          name: "playerAchievement",
-          isMut: false,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "mint",
-          isMut: false,
-          isSigner: false,
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "metadataToVerify",
-          isMut: true,
-          isSigner: false,
+          name: "playerScores",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "collectionMint",
-          isMut: false,
-          isSigner: false,
+          name: "submissionCount",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "TopEntriesMigrated",
+      fields: [
         {
-          name: "collectionMetadata",
-          isMut: true,
-          isSigner: false,
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "collectionEdition",
-          isMut: false,
-          isSigner: false,
+          name: "topEntries",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "capacity",
+          type: "u32",
+          index: false,
         },
       ],
-      args: [],
     },
-  ],
-  accounts: [
     {
-      name: "achievement",
-      docs: [
-        "Represents an achievement(with optional rewards) for this game",
-        "that can be attained by players.",
-        "",
-        'PDA with seeds = `[b"achievement", game.key().as_ref(), &id.to_le_bytes()]`',
-        "",
-        "`id` is an incrementing index stored in the game account.",
-      ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "game",
-            docs: [
-              "Public key of the game account this achievement is derived from.",
-            ],
-            type: "publicKey",
-          },
-          {
-            name: "id",
-            docs: [
-              "The achievement_count of the game account when this account was",
-              "created, also used as a seed for its PDA.",
-            ],
-            type: "u64",
-          },
-          {
-            name: "title",
-            docs: ["Achievement title."],
-            type: "string",
-          },
-          {
-            name: "description",
-            docs: ["Achievement description."],
-            type: "string",
-          },
-          {
-            name: "nftMeta",
-            docs: [
-              "Public key of a nft metadata account describing this achievement.",
-            ],
-            type: "publicKey",
-          },
-          {
-            name: "reward",
-            docs: [
-              "Optional: Specify a reward to players for unlocking this achievement.",
-            ],
-            type: {
-              option: "publicKey",
-            },
-          },
-        ],
-      },
+      name: "TopEntriesMerged",
+      fields: [
+        {
+          name: "mergeAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "game",
-      docs: ["An account representing a single game."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "meta",
-            docs: ["Game meta-information."],
-            type: {
-              defined: "GameAttributes",
-            },
-          },
-          {
-            name: "leaderboardCount",
-            docs: [
-              "Number of leaderboards this game has created. Used both",
-              "in determining the most recent leaderboard address, and",
-              "as a seed for the next leaderboard.",
-            ],
-            type: "u64",
-          },
-          {
-            name: "achievementCount",
-            docs: [
-              "Number of achievements that exist for this game. Also",
-              "used to determine the u64 seed for the next achievement.",
-            ],
-            type: "u64",
-          },
-          {
-            name: "auth",
-            docs: [
-              "A collection of pubkeys which each represent a valid",
-              "authority for this game.",
-            ],
-            type: {
-              vec: "publicKey",
-            },
-          },
-        ],
-      },
+      name: "LeaderBoardClosed",
+      fields: [
+        {
+          name: "game",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "leaderBoard",
-      docs: [
-        "Represents a [Game][super::Game]'s leaderboard.",
-        "",
-        'Seeds: `[b"leaderboard", game.key().as_ref(), &id.to_le_bytes()]`',
+      name: "AchievementCreated",
+      fields: [
+        {
+          name: "game",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "id",
+          type: "u64",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "id",
-            docs: [
-              "The leaderboard's id, used in deriving its address from the game.",
-            ],
-            type: "u64",
-          },
-          {
-            name: "game",
-            docs: ["The game this leaderboard belongs to and is derived from."],
-            type: "publicKey",
-          },
-          {
-            name: "description",
-            docs: ["Leaderboard description."],
-            type: "string",
-          },
-          {
-            name: "nftMeta",
-            docs: [
-              "Pubkey of an nft metadata account that describes this leaderboard.",
-            ],
-            type: "publicKey",
-          },
-          {
-            name: "decimals",
-            docs: ["Used to contextualize scores for this leaderboard."],
-            type: "u8",
+    },
+    {
+      name: "AchievementUpdated",
+      fields: [
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "newTitle",
+          type: {
+            option: "string",
           },
-          {
-            name: "minScore",
-            docs: ["Minimum possible score for this leaderboard."],
-            type: "u64",
+          index: false,
+        },
+        {
+          name: "newDescription",
+          type: {
+            option: "string",
           },
-          {
-            name: "maxScore",
-            docs: ["Maximum possible score for this leaderboard."],
-            type: "u64",
+          index: false,
+        },
+        {
+          name: "newMeta",
+          type: {
+            option: "publicKey",
           },
-          {
-            name: "topEntries",
-            docs: ["Top [entries](ScoreEntry) for a leaderboard."],
-            type: {
-              option: "publicKey",
+          index: false,
+        },
+        {
+          name: "newUnlockRule",
+          type: {
+            option: {
+              option: {
+                defined: "AchievementRule",
+              },
             },
           },
-          {
-            name: "allowMultipleScores",
-            docs: [
-              "Whether or not multiple scores are allowed for a single player.",
-            ],
-            type: "bool",
-          },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: "merged",
-      docs: [
-        "An account that represents a single user's ownership of",
-        "multiple [Player][super::Player] accounts.",
+      name: "AchievementClosed",
+      fields: [
+        {
+          name: "game",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "initiator",
-            docs: ["The user that initialized this merge."],
-            type: "publicKey",
-          },
-          {
-            name: "approvals",
-            docs: [
-              "Details of all the player accounts to be merged with the main_user's.",
-            ],
-            type: {
-              vec: {
-                defined: "MergeApproval",
-              },
-            },
-          },
-          {
-            name: "mergeComplete",
-            docs: [
-              "Set to true when every user in `others` has registered their approval.",
-            ],
-            type: "bool",
-          },
-        ],
-      },
     },
     {
-      name: "playerAchievement",
-      docs: [
-        "Represents a player's status for a particular [Achievement](super::Achievement).",
-        "",
-        'Seeds = `[b"player-achievement", player.key().as_ref(), achievement.key().as_ref()]`.',
+      name: "PlayerCreated",
+      fields: [
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "user",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "username",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "nftMeta",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "playerAccount",
-            docs: ["The user's [player][super::Player] account."],
-            type: "publicKey",
-          },
-          {
-            name: "achievement",
-            docs: ["The key of the achievement unlocked for this player."],
-            type: "publicKey",
-          },
-          {
-            name: "timestamp",
-            docs: ["Timestamp showing when this achievement was unlocked."],
-            type: "i64",
-          },
-          {
-            name: "unlocked",
-            docs: ["A player's unlock status for this achievement."],
-            type: "bool",
-          },
-          {
-            name: "claimed",
-            docs: ["Whether or not this player has claimed their reward."],
-            type: "bool",
+    },
+    {
+      name: "PlayerUpdated",
+      fields: [
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "newUsername",
+          type: {
+            option: "string",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "newNftMeta",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
+        },
+      ],
     },
     {
-      name: "playerScoresList",
-      docs: [
-        "Holds a list of a [player][super::Player]'s [scores][ScoreEntry])",
-        "for a particular [LeaderBoard].",
-        "",
-        'Seeds: `[b"player-scores-list", player_account.key().as_ref(), leaderboard.key().as_ref()]`',
+      name: "PlayerRegistered",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerScores",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "playerAccount",
-            docs: [
-              "The player[super::Player] account this entry is derived from",
-            ],
-            type: "publicKey",
-          },
-          {
-            name: "leaderboard",
-            docs: ["The id of the specific leaderboard."],
-            type: "publicKey",
-          },
-          {
-            name: "allocCount",
-            docs: [
-              "Max number of [scores][ScoreEntry] the current space allocation supports.",
-            ],
-            type: "u16",
-          },
-          {
-            name: "scores",
-            docs: ["Collection of [scores][ScoreEntry]."],
-            type: {
-              vec: {
-                defined: "ScoreEntry",
-              },
-            },
-          },
-        ],
-      },
     },
     {
-      name: "player",
-      docs: [
-        "An account representing a player.",
-        "",
-        'Seeds: `[b"player", user.key().as_ref()]`',
+      name: "PlayerClosed",
+      fields: [
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "user",
-            docs: ["The wallet that owns this player-info account"],
-            type: "publicKey",
-          },
-          {
-            name: "username",
-            docs: ["The player's username."],
-            type: "string",
-          },
-          {
-            name: "nftMeta",
-            docs: ["Metadata to represent this player."],
-            type: "publicKey",
-          },
-        ],
-      },
     },
     {
-      name: "nftClaim",
-      type: {
-        kind: "struct",
-        fields: [],
-      },
+      name: "PlayerScoresClosed",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerScores",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "reward",
-      docs: ["An account representing a reward for a given achievement."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "achievement",
-            docs: ["The achievement this reward is given for."],
-            type: "publicKey",
+      name: "PlayerAchievementClosed",
+      fields: [
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAchievement",
+          type: "publicKey",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ScoreSubmitted",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "season",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "score",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "secondaryScore",
+          type: {
+            option: "u64",
           },
-          {
-            name: "availableSpots",
-            docs: ["Number of available reward spots."],
-            type: "u64",
+          index: false,
+        },
+        {
+          name: "context",
+          type: {
+            array: ["u8", 32],
           },
-          {
-            name: "reward",
-            docs: [
-              "The reward kind. Current supports Nft and Ft rewards only.",
-            ],
-            type: {
-              defined: "RewardKind",
-            },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "rank",
+          type: {
+            option: "u32",
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: "leaderTopEntries",
-      docs: [
-        "Keeps track of a sorted list of top scores for a leaderboard.",
-        "",
-        'Seeds = [b"top-scores", leaderboard.key().as_ref()]',
+      name: "AchievementUnlocked",
+      fields: [
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "isAscending",
-            docs: ["Arrangement order."],
-            type: "bool",
-          },
-          {
-            name: "topScores",
-            docs: ["Top scores."],
-            type: {
-              vec: {
-                defined: "LeaderBoardScore",
-              },
-            },
-          },
-        ],
-      },
     },
-  ],
-  types: [
     {
-      name: "GameAttributes",
-      docs: ["A type that represents game-specific information."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "title",
-            docs: ["The title of the game, max length = 30 bytes."],
-            type: "string",
-          },
-          {
-            name: "description",
-            docs: ["The game description, max length = 200 bytes."],
-            type: "string",
-          },
-          {
-            name: "genre",
-            docs: ["The game's [genre](super::Genre), as a u8."],
-            type: "u8",
-          },
-          {
-            name: "gameType",
-            docs: ["The game's [type](super::GameType), as a u8."],
-            type: "u8",
-          },
-          {
-            name: "nftMeta",
-            docs: ["An nft metadata account describing the game."],
-            type: "publicKey",
+      name: "RewardAdded",
+      fields: [
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "availableSpots",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "kind",
+          type: {
+            defined: "RewardKind",
           },
-        ],
-      },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RewardClosed",
+      fields: [
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "ScoreEntry",
-      docs: ["A single score entry for a player."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "score",
-            docs: ["The player's score."],
-            type: "u64",
-          },
-          {
-            name: "timestamp",
-            docs: ["When this entry was made."],
-            type: "i64",
-          },
-        ],
-      },
+      name: "NftClaimClosed",
+      fields: [
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "MergeApproval",
-      docs: [
-        "Represents a [Player][super::Player] account involved in a merge",
-        "and if that account's user/authority has granted approval.",
+      name: "RewardClaimed",
+      fields: [
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "reward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
       ],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "key",
-            docs: ["The player_account pubkey."],
-            type: "publicKey",
-          },
-          {
-            name: "approved",
-            docs: ["User's approval status."],
-            type: "bool",
-          },
-        ],
-      },
     },
     {
-      name: "LeaderBoardScore",
-      docs: ["An single entry to a [LeaderTopEntries]."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "player",
-            docs: ["The player"],
-            type: "publicKey",
-          },
-          {
-            name: "entry",
-            docs: ["The user's [score][super::ScoreEntry]."],
-            type: {
-              defined: "ScoreEntry",
-            },
-          },
-        ],
-      },
+      name: "RankRewardAdded",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rankReward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "season",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "deposit",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "RegisterLeaderBoardInput",
-      docs: ["Parameters needed when registering a leaderboard."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "description",
-            docs: ["Leaderboard description."],
-            type: "string",
-          },
-          {
-            name: "nftMeta",
-            docs: ["Nft metadata representing the leaderboard."],
-            type: "publicKey",
-          },
-          {
-            name: "decimals",
-            docs: [
-              "Specify the decimals score values are represented in. Defaults to `0` if [None].",
-            ],
-            type: {
-              option: "u8",
-            },
-          },
-          {
-            name: "minScore",
-            docs: [
-              "Specifies minimum allowed score. Defaults to `u64::MIN` if [None].",
-            ],
-            type: {
-              option: "u64",
-            },
-          },
-          {
-            name: "maxScore",
-            docs: [
-              "Specifies maximum allowed score. Defaults to `u64::MAX` if [None].",
-            ],
-            type: {
-              option: "u64",
-            },
-          },
-          {
-            name: "scoresToRetain",
-            docs: ["Number of top scores to store on-chain."],
-            type: "u8",
-          },
-          {
-            name: "isAscending",
-            docs: [
-              "Order by which scores are stored. `true` for ascending, `false` for descending.",
-            ],
-            type: "bool",
-          },
-          {
-            name: "allowMultipleScores",
-            docs: [
-              "Whether or not multiple scores are kept in the leaderboard for a single player.",
-            ],
-            type: "bool",
-          },
-        ],
-      },
+      name: "RankRewardClaimed",
+      fields: [
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rankReward",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "rank",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "AddNewRewardInput",
-      docs: ["Input to add a new reward for an achievement."],
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "availableSpots",
-            docs: ["Number of rewards to be given out."],
-            type: "u64",
-          },
-          {
-            name: "kind",
-            docs: ["Specific reward kind."],
-            type: {
-              defined: "RewardKindInput",
-            },
-          },
-        ],
-      },
+      name: "NftRewardVerified",
+      fields: [
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "GameType",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Mobile",
-          },
-          {
-            name: "Desktop",
-          },
-          {
-            name: "Web",
-          },
-          {
-            name: "Unspecified",
+      name: "MergeInitiated",
+      fields: [
+        {
+          name: "mergeAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "others",
+          type: {
+            vec: "publicKey",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "mergeComplete",
+          type: "bool",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "MergeApproved",
+      fields: [
+        {
+          name: "mergeAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mergeComplete",
+          type: "bool",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "MergeRejected",
+      fields: [
+        {
+          name: "mergeAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAccount",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "Genre",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Rpg",
-          },
-          {
-            name: "Mmo",
-          },
-          {
-            name: "Action",
-          },
-          {
-            name: "Adventure",
-          },
-          {
-            name: "Puzzle",
-          },
-          {
-            name: "Casual",
-          },
-          {
-            name: "Unspecified",
-          },
-        ],
-      },
+      name: "MergeCancelled",
+      fields: [
+        {
+          name: "mergeAccount",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "RewardKind",
-      docs: ["The kind of reward to be given out."],
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "FungibleToken",
-            fields: [
-              {
-                name: "mint",
-                docs: ["The mint of the token to be given out."],
-                type: "publicKey",
-              },
-              {
-                name: "account",
-                docs: ["The token account to withdraw from."],
-                type: "publicKey",
-              },
-              {
-                name: "amount",
-                docs: ["Reward amount per user."],
-                type: "u64",
-              },
-            ],
-          },
-          {
-            name: "NonFungibleToken",
-            fields: [
-              {
-                name: "uri",
-                docs: ["URI of the NFT to be minted."],
-                type: "string",
-              },
-              {
-                name: "name",
-                docs: ["Name of the NFT to be minted."],
-                type: "string",
-              },
-              {
-                name: "symbol",
-                docs: ["Symbol of the NFT to be minted."],
-                type: "string",
-              },
-              {
-                name: "minted",
-                docs: ["Total NFTs minted so far."],
-                type: "u64",
-              },
-              {
-                name: "collection",
-                docs: [
-                  "Optional field for a collection mint used for",
-                  "verifying minted rewards.",
-                ],
-                type: {
-                  option: "publicKey",
-                },
-              },
-            ],
-          },
-        ],
-      },
+      name: "MergeClosed",
+      fields: [
+        {
+          name: "mergeAccount",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
     {
-      name: "RewardKindInput",
-      docs: ["Specific variant of [AddNewRewardInput]."],
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Ft",
-            fields: [
-              {
-                name: "deposit",
-                docs: [
-                  "Amount to be delegated to this program's PDA",
-                  "so it can spend for reward claims.",
-                ],
-                type: "u64",
-              },
-              {
-                name: "amount",
-                docs: ["Amount given to a single user."],
-                type: "u64",
-              },
-            ],
-          },
-          {
-            name: "Nft",
-            fields: [
-              {
-                name: "uri",
-                docs: ["Uri of the minted nft."],
-                type: "string",
-              },
-              {
-                name: "name",
-                docs: ["Name of the minted nft."],
-                type: "string",
-              },
-              {
-                name: "symbol",
-                docs: ["Symbol of the minted nft."],
-                type: "string",
-              },
-            ],
-          },
-        ],
-      },
+      name: "PlayerScoresMerged",
+      fields: [
+        {
+          name: "mergeAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "leaderboard",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerScores",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mergedPlayerScores",
+          type: "publicKey",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PlayerAchievementMerged",
+      fields: [
+        {
+          name: "mergeAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "achievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "playerAchievement",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "mergedPlayerAchievement",
+          type: "publicKey",
+          index: false,
+        },
+      ],
     },
   ],
   errors: [
@@ -3503,5 +10397,185 @@ export const IDL: Soar = {
       name: "NoAvailableRewards",
       msg: "No more rewards are being given out for this game",
     },
+    {
+      code: 6009,
+      name: "SeasonEnded",
+      msg: "The leaderboard's current season has ended",
+    },
+    {
+      code: 6010,
+      name: "SeasonStillActive",
+      msg: "The leaderboard's current season is still active",
+    },
+    {
+      code: 6011,
+      name: "SubmissionWindowClosed",
+      msg: "Scores are not being accepted for this leaderboard at this time",
+    },
+    {
+      code: 6012,
+      name: "InvalidSubmissionWindow",
+      msg: "A leaderboard's opening time must be before its closing time",
+    },
+    {
+      code: 6013,
+      name: "InvalidRankRewardTiers",
+      msg: "Rank reward tiers must be non-empty, 1-indexed and non-overlapping",
+    },
+    {
+      code: 6014,
+      name: "SeasonNotClosed",
+      msg: "The leaderboard's current season must be closed for this instruction",
+    },
+    {
+      code: 6015,
+      name: "RankNotEligible",
+      msg: "The player does not hold a rewarded rank",
+    },
+    {
+      code: 6016,
+      name: "InvalidRemainingAccounts",
+      msg: "Remaining accounts don't match the layout expected by this instruction",
+    },
+    {
+      code: 6017,
+      name: "NoProgressTarget",
+      msg: "This achievement doesn't track progress",
+    },
+    {
+      code: 6018,
+      name: "AchievementNotUnlocked",
+      msg: "The player hasn't unlocked this achievement",
+    },
+    {
+      code: 6019,
+      name: "RewardAlreadyClaimed",
+      msg: "The reward for this achievement has already been claimed",
+    },
+    {
+      code: 6020,
+      name: "MergeNotComplete",
+      msg: "All players in the merge must approve it before its data can be aggregated",
+    },
+    {
+      code: 6021,
+      name: "MergeAlreadyComplete",
+      msg: "This merge has already been approved by every player",
+    },
+    {
+      code: 6022,
+      name: "MergeExpired",
+      msg: "This merge has expired",
+    },
+    {
+      code: 6023,
+      name: "MergeNotClosable",
+      msg: "This merge can't be closed yet",
+    },
+    {
+      code: 6024,
+      name: "NoAdminAuthority",
+      msg: "A game must have at least one admin authority",
+    },
+    {
+      code: 6025,
+      name: "ProposalRequired",
+      msg: "This change requires an approved proposal",
+    },
+    {
+      code: 6026,
+      name: "ProposalNotApproved",
+      msg: "The proposal doesn't have enough approvals or has already been executed",
+    },
+    {
+      code: 6027,
+      name: "ProposalActionMismatch",
+      msg: "The proposal doesn't match this instruction",
+    },
+    {
+      code: 6028,
+      name: "InvalidThreshold",
+      msg: "The approval threshold can't exceed the number of admin authorities",
+    },
+    {
+      code: 6029,
+      name: "RewardSpotsRemaining",
+      msg: "The reward still has unclaimed spots",
+    },
+    {
+      code: 6030,
+      name: "AchievementHasReward",
+      msg: "The achievement's reward must be closed first",
+    },
+    {
+      code: 6031,
+      name: "InvalidAttestation",
+      msg: "Missing or invalid ed25519 signature verification for the score attestation",
+    },
+    {
+      code: 6032,
+      name: "AttestationExpired",
+      msg: "The score attestation has expired",
+    },
+    {
+      code: 6033,
+      name: "AttestationReplayed",
+      msg: "The score attestation's nonce has already been used",
+    },
+    {
+      code: 6034,
+      name: "InvalidRetentionPolicy",
+      msg: "Retained score count must be between 1 and the maximum a player scores account can hold",
+    },
+    {
+      code: 6035,
+      name: "InvalidScoringMode",
+      msg: "Only the best scoring mode can allow multiple scores per player",
+    },
+    {
+      code: 6036,
+      name: "InvalidTopEntriesSize",
+      msg: "Top entries must keep at least one score and grow by at most 10KiB per instruction",
+    },
+    {
+      code: 6037,
+      name: "InvalidScoreBounds",
+      msg: "The minimum score can't be greater than the maximum score",
+    },
+    {
+      code: 6038,
+      name: "SubmissionTooSoon",
+      msg: "A score was submitted for this player too recently",
+    },
+    {
+      code: 6039,
+      name: "AttemptLimitReached",
+      msg: "The player has no attempts left for this leaderboard's current window",
+    },
+    {
+      code: 6040,
+      name: "InvalidSeasonEnd",
+      msg: "The season end must be in the future",
+    },
+    {
+      code: 6041,
+      name: "AchievementAbsorbed",
+      msg: "This achievement was merged into another player's account",
+    },
+    {
+      code: 6042,
+      name: "RankedEntriesDropped",
+      msg: "Resizing top entries would drop ranked entries",
+    },
+    {
+      code: 6043,
+      name: "LegacyAccountLayout",
+      msg: "The account is in a legacy layout and must be migrated first",
+    },
+    {
+      code: 6044,
+      name: "TooManyEntriesToArchive",
+      msg: "The top entries rank more scores than a season archive can hold",
+    },
   ],
 };
@@ -1,5 +1,5 @@
 export * from "./idl/soar";
 export * from "./instructions";
-export { Genre, GameType } from "./state";
+export { Genre, GameType, GameAuthorityRole } from "./state";
 export * from "./soar.game";
 export * from "./soar.program";
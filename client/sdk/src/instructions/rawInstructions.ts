@@ -80,7 +80,13 @@ export const addAchievementInstruction = async (
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .addAchievement(args.title, args.description, args.nftMeta)
+    .addAchievement(
+      args.title,
+      args.description,
+      args.nftMeta,
+      args.unlockRule,
+      args.progressTarget
+    )
     .accounts(accounts)
     .preInstructions(pre ?? [])
     .instruction();
@@ -100,7 +106,7 @@ export const addLeaderBoardInstruction = async (
 ): Promise<TransactionInstruction> =>
   program.methods
     .addLeaderboard(args)
-    .accounts(accounts)
+    .accounts({ ...accounts, proposal: null })
     .preInstructions(pre ?? [])
     .instruction();
 
@@ -128,7 +134,7 @@ export const addFtRewardInstruction = async (
         ft: args.kind,
       },
     })
-    .accounts(accounts)
+    .accounts({ ...accounts, proposal: null })
     .preInstructions(pre ?? [])
     .instruction();
 
@@ -156,7 +162,7 @@ export const addNftRewardInstruction = async (
         nft: args.kind,
       },
     })
-    .accounts(accounts)
+    .accounts({ ...accounts, proposal: null })
     .preInstructions(pre ?? [])
     .instruction();
 
@@ -257,7 +263,7 @@ export const submitScoreInstruction = async (
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .submitScore(args.score)
+    .submitScore(args.score, args.secondaryScore, args.context)
     .accounts(accounts)
     .preInstructions(pre ?? [])
     .instruction();
@@ -273,7 +279,12 @@ export const updateAchievementInstruction = async (
   pre?: TransactionInstruction[]
 ): Promise<TransactionInstruction> =>
   program.methods
-    .updateAchievement(args.newTitle, args.newDescription, args.newNftMeta)
+    .updateAchievement(
+      args.newTitle,
+      args.newDescription,
+      args.newNftMeta,
+      args.newUnlockRule
+    )
     .accounts(accounts)
     .preInstructions(pre ?? [])
     .instruction();
@@ -324,20 +335,12 @@ export const updateLeaderBoardInstruction = async (
     topEntries: PublicKey | null;
   },
   pre?: TransactionInstruction[]
-): Promise<TransactionInstruction> => {
-  return program.methods
-    .updateLeaderboard(
-      args.newDescription,
-      args.newNftMeta,
-      args.newMinScore,
-      args.newMaxScore,
-      args.newIsAscending,
-      args.newAllowMultipleScores
-    )
+): Promise<TransactionInstruction> =>
+  program.methods
+    .updateLeaderboard(args)
     .accounts(accounts)
     .preInstructions(pre ?? [])
     .instruction();
-};
 
 export const updatePlayerInstruction = async (
   program: Program<Soar>,
@@ -18,6 +18,7 @@ import {
   AchievementAccount,
   GameAccount,
   type GameAttributes,
+  GameAuthorityRole,
   type GameType,
   type Genre,
   LeaderBoardAccount,
@@ -96,7 +97,10 @@ export class SoarProgram {
           gameType,
           nftMeta,
         },
-        authorities,
+        authorities: authorities.map((key) => ({
+          key,
+          roles: GameAuthorityRole.Admin,
+        })),
       },
       newGame
     );
@@ -153,7 +157,9 @@ export class SoarProgram {
     const step = await this.builder.updateGameStep(
       {
         newMeta: newMeta ?? null,
-        newAuths: newAuths ?? null,
+        newAuths:
+          newAuths?.map((key) => ({ key, roles: GameAuthorityRole.Admin })) ??
+          null,
       },
       game,
       authority
@@ -252,6 +258,8 @@ export class SoarProgram {
         title,
         description,
         nftMeta,
+        unlockRule: null,
+        progressTarget: null,
       },
       gameAddress,
       authority
@@ -291,6 +299,14 @@ export class SoarProgram {
         minScore: minScore ?? null,
         maxScore: maxScore ?? null,
         allowMultipleScores: allowMultipleScores ?? false,
+        seasonEnd: null,
+        opensAt: null,
+        closesAt: null,
+        retention: { unbounded: {} },
+        scoringMode: { best: {} },
+        tieBreak: { earlierWins: {} },
+        scoreType: { unsigned: {} },
+        rateLimit: { minInterval: 0, maxAttempts: 0, attemptWindow: 0 },
       },
       gameAddress,
       authority
@@ -334,6 +350,12 @@ export class SoarProgram {
         newMaxScore: newMaxScore ?? null,
         newIsAscending: newIsAscending ?? null,
         newAllowMultipleScores: newAllowMultipleScores ?? null,
+        newOpensAt: null,
+        newClosesAt: null,
+        newRetention: null,
+        newScoringMode: null,
+        newTieBreak: null,
+        newRateLimit: null,
       },
       authority,
       leaderboard,
@@ -363,12 +385,17 @@ export class SoarProgram {
     user: PublicKey | string,
     authority: PublicKey | string,
     leaderboard: PublicKey | string,
-    score: BN | number
+    score: BN | number,
+    secondaryScore?: BN | number,
+    context?: number[]
   ): Promise<InstructionResult.SubmitScore> {
     this.builder.clean();
     if (typeof score === "number") {
       score = new BN(score);
     }
+    if (typeof secondaryScore === "number") {
+      secondaryScore = new BN(secondaryScore);
+    }
     user = this.getPublicKey(user);
     authority = this.getPublicKey(authority);
     leaderboard = this.getPublicKey(leaderboard);
@@ -376,6 +403,8 @@ export class SoarProgram {
     const step = await this.builder.submitScoreStep(
       {
         score,
+        secondaryScore: secondaryScore ?? null,
+        context: context ?? null,
       },
       user,
       authority,
@@ -411,6 +440,7 @@ export class SoarProgram {
         newTitle: newTitle ?? null,
         newDescription: newDescription ?? null,
         newNftMeta: newNftMeta ?? null,
+        newUnlockRule: null,
       },
       authority,
       achievement
@@ -699,8 +729,13 @@ export class SoarProgram {
   public async fetchLeaderBoardTopEntriesAccount(
     address: PublicKey
   ): Promise<TopEntriesAccount> {
-    const info = await this.program.account.leaderTopEntries.fetch(address);
-    return TopEntriesAccount.fromIdlAccount(info, address);
+    const info = await this.program.account.leaderTopEntriesV2.getAccountInfo(
+      address
+    );
+    if (info === null) {
+      throw new Error(`Account ${address.toBase58()} does not exist`);
+    }
+    return TopEntriesAccount.fromAccountData(this.program, info.data, address);
   }
 
   public async fetchMergedAccount(address: PublicKey): Promise<MergedAccount> {
@@ -12,7 +12,7 @@ export class GameAccount {
 
   public readonly achievementCount: BN;
   public readonly leaderboardCount: BN;
-  public readonly auth: PublicKey[];
+  public readonly auth: GameAuthority[];
 
   protected constructor(
     _address: PublicKey,
@@ -49,7 +49,7 @@ export class GameAccount {
     };
     achievementCount: string;
     leaderboardCount: string;
-    auth: string[];
+    auth: Array<{ key: string; roles: number }>;
   } {
     return {
       address: this.address.toBase58(),
@@ -59,7 +59,10 @@ export class GameAccount {
       },
       achievementCount: this.achievementCount.toString(),
       leaderboardCount: this.leaderboardCount.toString(),
-      auth: this.auth.map((auth) => auth.toBase58()),
+      auth: this.auth.map((auth) => ({
+        key: auth.key.toBase58(),
+        roles: auth.roles,
+      })),
     };
   }
 }
@@ -72,6 +75,20 @@ export interface GameAttributes {
   nftMeta: PublicKey;
 }
 
+export interface GameAuthority {
+  key: PublicKey;
+  /** Bitmask of `GameAuthorityRole`s. */
+  roles: number;
+}
+
+export const enum GameAuthorityRole {
+  Admin = 1 << 0,
+  ScoreSubmitter = 1 << 1,
+  AchievementManager = 1 << 2,
+  RewardManager = 1 << 3,
+  Attester = 1 << 4,
+}
+
 export const enum GameType {
   Mobile = 0,
   Desktop = 1,
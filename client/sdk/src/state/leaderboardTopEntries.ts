@@ -1,9 +1,14 @@
 import { type PublicKey } from "@solana/web3.js";
 import type BN from "bn.js";
-import { type IdlAccounts } from "@coral-xyz/anchor";
+import { type IdlAccounts, type Program } from "@coral-xyz/anchor";
 import { type Soar } from "../idl/soar";
 
-/** Class representing a deserialized on-chain `LeaderTopScores` account. */
+/** Size of the `LeaderTopEntriesV2` header, including the discriminator. */
+const HEADER_SIZE = 8 + 32 + 4 + 1 + 3;
+/** Size of a single `LeaderBoardScore` following the header. */
+const SCORE_SIZE = 32 + 64;
+
+/** Class representing a deserialized on-chain `LeaderTopEntriesV2` account. */
 export class TopEntriesAccount {
   constructor(
     public readonly address: PublicKey,
@@ -11,16 +16,36 @@ export class TopEntriesAccount {
     public readonly topScores: LeaderboardScore[]
   ) {}
 
-  /** Create a new instance from an anchor-deserialized account. */
+  /** Create a new instance from an anchor-deserialized header and its scores. */
   public static fromIdlAccount(
-    account: IdlAccounts<Soar>["leaderTopEntries"],
+    account: IdlAccounts<Soar>["leaderTopEntriesV2"],
+    topScores: LeaderboardScore[],
     address: PublicKey
   ): TopEntriesAccount {
-    return new TopEntriesAccount(
-      address,
-      account.isAscending,
-      account.topScores
-    );
+    return new TopEntriesAccount(address, account.isAscending !== 0, topScores);
+  }
+
+  /** Create a new instance from raw account data. */
+  public static fromAccountData(
+    program: Program<Soar>,
+    data: Buffer,
+    address: PublicKey
+  ): TopEntriesAccount {
+    const header: IdlAccounts<Soar>["leaderTopEntriesV2"] =
+      program.coder.accounts.decode("leaderTopEntriesV2", data);
+
+    const topScores: LeaderboardScore[] = [];
+    for (let i = 0; i < header.capacity; ++i) {
+      const offset = HEADER_SIZE + i * SCORE_SIZE;
+      topScores.push(
+        program.coder.types.decode(
+          "LeaderBoardScore",
+          data.subarray(offset, offset + SCORE_SIZE)
+        )
+      );
+    }
+
+    return TopEntriesAccount.fromIdlAccount(header, topScores, address);
   }
 
   /** Pretty print. */
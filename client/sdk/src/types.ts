@@ -1,7 +1,16 @@
 import { type PublicKey, type Transaction } from "@solana/web3.js";
-import { type GameType, type Genre } from "./state/game";
+import { type IdlTypes } from "@coral-xyz/anchor";
+import { type GameAuthority, type GameType, type Genre } from "./state/game";
+import { type Soar } from "./idl/soar";
 import type BN from "bn.js";
 
+export type AchievementRule = IdlTypes<Soar>["AchievementRule"];
+export type RetentionPolicy = IdlTypes<Soar>["RetentionPolicy"];
+export type ScoringMode = IdlTypes<Soar>["ScoringMode"];
+export type TieBreak = IdlTypes<Soar>["TieBreak"];
+export type ScoreType = IdlTypes<Soar>["ScoreType"];
+export type RateLimit = IdlTypes<Soar>["RateLimit"];
+
 // eslint-disable-next-line @typescript-eslint/no-namespace
 export module InstructionResult {
   export interface InitializeGame {
@@ -76,12 +85,14 @@ export interface InitializeGameArgs {
     gameType: GameType;
     nftMeta: PublicKey;
   };
-  authorities: PublicKey[];
+  authorities: GameAuthority[];
 }
 export interface AddAchievementArgs {
   title: string;
   description: string;
   nftMeta: PublicKey;
+  unlockRule: AchievementRule | null;
+  progressTarget: BN | null;
 }
 export interface AddLeaderBoardArgs {
   description: string;
@@ -92,6 +103,14 @@ export interface AddLeaderBoardArgs {
   scoresToRetain: number;
   isAscending: boolean;
   allowMultipleScores: boolean;
+  seasonEnd: BN | null;
+  opensAt: BN | null;
+  closesAt: BN | null;
+  retention: RetentionPolicy;
+  scoringMode: ScoringMode;
+  tieBreak: TieBreak;
+  scoreType: ScoreType;
+  rateLimit: RateLimit;
 }
 export interface AddNftRewardArgs {
   availableRewards: BN;
@@ -117,11 +136,14 @@ export interface InitMergeArgs {
 }
 export interface SubmitScoreArgs {
   score: BN;
+  secondaryScore: BN | null;
+  context: number[] | null;
 }
 export interface UpdateAchievementArgs {
   newTitle: string | null;
   newDescription: string | null;
   newNftMeta: PublicKey | null;
+  newUnlockRule: AchievementRule | null;
 }
 export interface UpdateGameArgs {
   newMeta: {
@@ -131,7 +153,7 @@ export interface UpdateGameArgs {
     gameType: GameType;
     nftMeta: PublicKey;
   } | null;
-  newAuths: PublicKey[] | null;
+  newAuths: GameAuthority[] | null;
 }
 export interface UpdateLeaderboardArgs {
   newDescription: string | null;
@@ -140,6 +162,12 @@ export interface UpdateLeaderboardArgs {
   newMaxScore: BN | null;
   newIsAscending: boolean | null;
   newAllowMultipleScores: boolean | null;
+  newOpensAt: BN | null;
+  newClosesAt: BN | null;
+  newRetention: RetentionPolicy | null;
+  newScoringMode: ScoringMode | null;
+  newTieBreak: TieBreak | null;
+  newRateLimit: RateLimit | null;
 }
 export interface UpdatePlayerArgs {
   newUsername: string | null;
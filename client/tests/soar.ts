@@ -67,8 +67,12 @@ describe("soar", () => {
     expect(info.leaderboardCount.toNumber()).to.equal(0);
 
     expect(info.auth.length).to.equal(2);
-    expect(info.auth[0].toBase58()).to.equal(auths[0].publicKey.toBase58());
-    expect(info.auth[1].toBase58()).to.equal(auths[1].publicKey.toBase58());
+    expect(info.auth[0].key.toBase58()).to.equal(
+      auths[0].publicKey.toBase58()
+    );
+    expect(info.auth[1].key.toBase58()).to.equal(
+      auths[1].publicKey.toBase58()
+    );
 
     gameClient = new GameClient(client, newGame);
   });
@@ -90,9 +94,15 @@ describe("soar", () => {
     await gameClient.refresh();
     const authKeys = gameClient.account.auth;
     expect(authKeys.length).to.equal(3);
-    expect(authKeys[0].toBase58()).to.equal(newAuths[0].publicKey.toBase58());
-    expect(authKeys[1].toBase58()).to.equal(newAuths[1].publicKey.toBase58());
-    expect(authKeys[2].toBase58()).to.equal(newAuths[2].publicKey.toBase58());
+    expect(authKeys[0].key.toBase58()).to.equal(
+      newAuths[0].publicKey.toBase58()
+    );
+    expect(authKeys[1].key.toBase58()).to.equal(
+      newAuths[1].publicKey.toBase58()
+    );
+    expect(authKeys[2].key.toBase58()).to.equal(
+      newAuths[2].publicKey.toBase58()
+    );
   });
 
   it("Can't set fields with invalid lengths on a game", async () => {
@@ -0,0 +1,428 @@
+use crate::{decode, instructions, pda, Error, Result, TopEntries};
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction, AccountDeserialize};
+use soar::{
+    Achievement, Game, GameProposal, LeaderBoard, Merged, Player, PlayerAchievement,
+    PlayerScoresList, ProposalAction, RankReward, RegisterLeaderBoardInput, Reward, RewardKind,
+    SeasonArchive, UpdateLeaderBoardInput,
+};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    signature::Signature,
+    signer::{Signer, SignerError},
+    transaction::Transaction,
+};
+
+/// Fetches and decodes SOAR accounts, and builds instructions whose accounts depend on
+/// on-chain state.
+pub struct SoarClient {
+    rpc: RpcClient,
+}
+
+impl SoarClient {
+    /// Create a client using `rpc` for all requests.
+    pub fn new(rpc: RpcClient) -> Self {
+        SoarClient { rpc }
+    }
+
+    /// The underlying RPC client.
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    /// Fetch and decode an Anchor account.
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        decode::account(&self.account_data(address)?)
+    }
+
+    fn account_data(&self, address: &Pubkey) -> Result<Vec<u8>> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value
+            .ok_or(Error::AccountNotFound(*address))?;
+        Ok(account.data)
+    }
+
+    pub fn game(&self, game: &Pubkey) -> Result<Game> {
+        self.account(game)
+    }
+
+    pub fn leaderboard(&self, leaderboard: &Pubkey) -> Result<LeaderBoard> {
+        self.account(leaderboard)
+    }
+
+    /// Fetch the top entries of a leaderboard.
+    pub fn top_entries(&self, leaderboard: &Pubkey) -> Result<TopEntries> {
+        let (address, _) = pda::find_top_entries(leaderboard);
+        TopEntries::decode(&self.account_data(&address)?)
+    }
+
+    pub fn achievement(&self, achievement: &Pubkey) -> Result<Achievement> {
+        self.account(achievement)
+    }
+
+    pub fn reward(&self, reward: &Pubkey) -> Result<Reward> {
+        self.account(reward)
+    }
+
+    pub fn merge(&self, merge_account: &Pubkey) -> Result<Merged> {
+        self.account(merge_account)
+    }
+
+    pub fn proposal(&self, proposal: &Pubkey) -> Result<GameProposal> {
+        self.account(proposal)
+    }
+
+    /// Fetch the player account owned by `user`.
+    pub fn player(&self, user: &Pubkey) -> Result<Player> {
+        self.account(&pda::find_player(user).0)
+    }
+
+    /// Fetch `user`'s scores for a leaderboard.
+    pub fn player_scores(&self, user: &Pubkey, leaderboard: &Pubkey) -> Result<PlayerScoresList> {
+        let (player_account, _) = pda::find_player(user);
+        self.account(&pda::find_player_scores(&player_account, leaderboard).0)
+    }
+
+    /// Fetch `user`'s progress on an achievement.
+    pub fn player_achievement(
+        &self,
+        user: &Pubkey,
+        achievement: &Pubkey,
+    ) -> Result<PlayerAchievement> {
+        let (player_account, _) = pda::find_player(user);
+        self.account(&pda::find_player_achievement(&player_account, achievement).0)
+    }
+
+    pub fn season_archive(&self, leaderboard: &Pubkey, season: u64) -> Result<SeasonArchive> {
+        self.account(&pda::find_season_archive(leaderboard, season).0)
+    }
+
+    pub fn rank_reward(&self, leaderboard: &Pubkey, season: u64) -> Result<RankReward> {
+        self.account(&pda::find_rank_reward(leaderboard, season).0)
+    }
+
+    /// Sign `instructions` with `payer` and `signers`, send them and wait for confirmation.
+    pub fn send(
+        &self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> Result<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let mut all_signers = vec![payer];
+        all_signers.extend_from_slice(signers);
+
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&payer.pubkey()));
+        transaction
+            .try_sign(&all_signers, blockhash)
+            .map_err(|error: SignerError| Error::Rpc(Box::new(error.into())))?;
+        Ok(self.rpc.send_and_confirm_transaction(&transaction)?)
+    }
+
+    /// [instructions::create_proposal] using the game's next proposal id.
+    pub fn create_proposal_ix(
+        &self,
+        authority: Pubkey,
+        payer: Pubkey,
+        game: Pubkey,
+        action: ProposalAction,
+    ) -> Result<Instruction> {
+        let id = self.game(&game)?.next_proposal();
+        Ok(instructions::create_proposal(
+            authority, payer, game, id, action,
+        ))
+    }
+
+    /// [instructions::add_leaderboard] using the game's next leaderboard id.
+    pub fn add_leaderboard_ix(
+        &self,
+        authority: Pubkey,
+        payer: Pubkey,
+        game: Pubkey,
+        input: RegisterLeaderBoardInput,
+        proposal: Option<Pubkey>,
+    ) -> Result<Instruction> {
+        let id = self.game(&game)?.next_leaderboard();
+        Ok(instructions::add_leaderboard(
+            authority, payer, game, id, input, proposal,
+        ))
+    }
+
+    /// [instructions::add_achievement] using the game's next achievement id.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_achievement_ix(
+        &self,
+        authority: Pubkey,
+        payer: Pubkey,
+        game: Pubkey,
+        title: String,
+        description: String,
+        nft_meta: Pubkey,
+        unlock_rule: Option<soar::AchievementRule>,
+        progress_target: Option<u64>,
+    ) -> Result<Instruction> {
+        let id = self.game(&game)?.next_achievement();
+        Ok(instructions::add_achievement(
+            authority,
+            payer,
+            game,
+            id,
+            title,
+            description,
+            nft_meta,
+            unlock_rule,
+            progress_target,
+        ))
+    }
+
+    pub fn update_leaderboard_ix(
+        &self,
+        authority: Pubkey,
+        leaderboard: Pubkey,
+        input: UpdateLeaderBoardInput,
+    ) -> Result<Instruction> {
+        let state = self.leaderboard(&leaderboard)?;
+        Ok(instructions::update_leaderboard(
+            authority,
+            state.game,
+            leaderboard,
+            state.top_entries,
+            input,
+        ))
+    }
+
+    pub fn close_season_ix(
+        &self,
+        authority: Pubkey,
+        payer: Pubkey,
+        leaderboard: Pubkey,
+    ) -> Result<Instruction> {
+        let state = self.leaderboard(&leaderboard)?;
+        Ok(instructions::close_season(
+            authority,
+            payer,
+            state.game,
+            leaderboard,
+            state.top_entries,
+            state.season,
+        ))
+    }
+
+    pub fn start_season_ix(
+        &self,
+        authority: Pubkey,
+        leaderboard: Pubkey,
+        season_end: Option<i64>,
+    ) -> Result<Instruction> {
+        let state = self.leaderboard(&leaderboard)?;
+        Ok(instructions::start_season(
+            authority,
+            state.game,
+            leaderboard,
+            state.top_entries,
+            season_end,
+        ))
+    }
+
+    pub fn close_leaderboard_ix(
+        &self,
+        authority: Pubkey,
+        leaderboard: Pubkey,
+        receiver: Pubkey,
+    ) -> Result<Instruction> {
+        let state = self.leaderboard(&leaderboard)?;
+        Ok(instructions::close_leaderboard(
+            authority,
+            state.game,
+            leaderboard,
+            state.top_entries,
+            receiver,
+        ))
+    }
+
+    pub fn register_player_ix(
+        &self,
+        payer: Pubkey,
+        user: Pubkey,
+        leaderboard: Pubkey,
+    ) -> Result<Instruction> {
+        let state = self.leaderboard(&leaderboard)?;
+        Ok(instructions::register_player(
+            payer,
+            user,
+            state.game,
+            leaderboard,
+        ))
+    }
+
+    /// [instructions::submit_score] for `user`'s player, trying to unlock each of `achievements`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_score_ix(
+        &self,
+        payer: Pubkey,
+        authority: Pubkey,
+        user: Pubkey,
+        leaderboard: Pubkey,
+        score: u64,
+        secondary_score: Option<u64>,
+        context: Option<[u8; 32]>,
+        achievements: &[Pubkey],
+    ) -> Result<Instruction> {
+        let state = self.leaderboard(&leaderboard)?;
+        Ok(instructions::submit_score(
+            payer,
+            authority,
+            user,
+            state.game,
+            leaderboard,
+            state.top_entries,
+            score,
+            secondary_score,
+            context,
+            achievements,
+        ))
+    }
+
+    /// [instructions::migrate_player_scores] for `user`'s player.
+    pub fn migrate_player_scores_ix(
+        &self,
+        payer: Pubkey,
+        user: Pubkey,
+        leaderboard: Pubkey,
+    ) -> Result<Instruction> {
+        let state = self.leaderboard(&leaderboard)?;
+        Ok(instructions::migrate_player_scores(
+            payer,
+            pda::find_player(&user).0,
+            leaderboard,
+            state.top_entries,
+        ))
+    }
+
+    /// [instructions::merge_player_scores] for the merge's initiator.
+    pub fn merge_player_scores_ix(
+        &self,
+        payer: Pubkey,
+        merge_account: Pubkey,
+        merged_player_account: Pubkey,
+        leaderboard: Pubkey,
+    ) -> Result<Instruction> {
+        let merge = self.merge(&merge_account)?;
+        let state = self.leaderboard(&leaderboard)?;
+        Ok(instructions::merge_player_scores(
+            payer,
+            merge.initiator,
+            merge_account,
+            merged_player_account,
+            leaderboard,
+            state.top_entries,
+        ))
+    }
+
+    /// [instructions::claim_ft_reward] for the achievement's current reward.
+    pub fn claim_ft_reward_ix(
+        &self,
+        authority: Pubkey,
+        payer: Pubkey,
+        user: Pubkey,
+        achievement: Pubkey,
+    ) -> Result<Instruction> {
+        let (game, reward, state) = self.achievement_reward(&achievement)?;
+        let RewardKind::FungibleToken { mint, account, .. } = state.reward else {
+            return Err(Error::InvalidState(
+                "achievement reward isn't a fungible token",
+            ));
+        };
+        Ok(instructions::claim_ft_reward(
+            authority,
+            payer,
+            user,
+            game,
+            achievement,
+            reward,
+            account,
+            mint,
+        ))
+    }
+
+    /// [instructions::claim_nft_reward] for the achievement's current reward.
+    pub fn claim_nft_reward_ix(
+        &self,
+        authority: Pubkey,
+        payer: Pubkey,
+        user: Pubkey,
+        achievement: Pubkey,
+        new_mint: Pubkey,
+    ) -> Result<Instruction> {
+        let (game, reward, _) = self.achievement_reward(&achievement)?;
+        Ok(instructions::claim_nft_reward(
+            authority,
+            payer,
+            user,
+            game,
+            achievement,
+            reward,
+            new_mint,
+        ))
+    }
+
+    /// [instructions::verify_nft_reward] for an NFT `mint` claimed from the achievement's
+    /// current reward.
+    pub fn verify_nft_reward_ix(
+        &self,
+        payer: Pubkey,
+        user: Pubkey,
+        achievement: Pubkey,
+        mint: Pubkey,
+    ) -> Result<Instruction> {
+        let (game, reward, state) = self.achievement_reward(&achievement)?;
+        let RewardKind::NonFungibleToken {
+            collection: Some(collection_mint),
+            ..
+        } = state.reward
+        else {
+            return Err(Error::InvalidState(
+                "achievement reward isn't an NFT with a collection",
+            ));
+        };
+        Ok(instructions::verify_nft_reward(
+            payer,
+            user,
+            game,
+            achievement,
+            reward,
+            mint,
+            collection_mint,
+        ))
+    }
+
+    /// [instructions::claim_rank_reward] for a closed `season` of the leaderboard.
+    pub fn claim_rank_reward_ix(
+        &self,
+        user: Pubkey,
+        payer: Pubkey,
+        leaderboard: Pubkey,
+        season: u64,
+        rank: u32,
+    ) -> Result<Instruction> {
+        let rank_reward = self.rank_reward(&leaderboard, season)?;
+        Ok(instructions::claim_rank_reward(
+            user,
+            payer,
+            leaderboard,
+            season,
+            rank_reward.account,
+            rank_reward.mint,
+            rank,
+        ))
+    }
+
+    fn achievement_reward(&self, achievement: &Pubkey) -> Result<(Pubkey, Pubkey, Reward)> {
+        let state = self.achievement(achievement)?;
+        let reward = state
+            .reward
+            .ok_or(Error::InvalidState("achievement has no reward"))?;
+        Ok((state.game, reward, self.reward(&reward)?))
+    }
+}
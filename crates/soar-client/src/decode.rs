@@ -0,0 +1,82 @@
+//! Decoding of raw SOAR account data, for data obtained without [SoarClient][crate::SoarClient].
+
+use crate::Result;
+use anchor_lang::{error::ErrorCode, AccountDeserialize, Discriminator};
+use soar::{LeaderBoardScore, LeaderTopEntriesV2};
+
+/// Decode an Anchor account, checking its discriminator.
+pub fn account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    Ok(T::try_deserialize(&mut &data[..])?)
+}
+
+/// A leaderboard's decoded [LeaderTopEntriesV2] header and score slots.
+#[derive(Debug, Clone)]
+pub struct TopEntries {
+    pub header: LeaderTopEntriesV2,
+
+    /// Score slots, best first. Unused slots hold placeholders at the end.
+    pub scores: Vec<LeaderBoardScore>,
+}
+
+impl TopEntries {
+    /// Decode a [LeaderTopEntriesV2] account's data.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        if data.get(..8) != Some(&LeaderTopEntriesV2::DISCRIMINATOR[..]) {
+            return Err(
+                anchor_lang::error::Error::from(ErrorCode::AccountDiscriminatorMismatch).into(),
+            );
+        }
+
+        // RPC buffers carry no alignment guarantees, unlike on-chain account data.
+        let mut aligned = vec![0u64; data.len().div_ceil(8)];
+        let bytes = &mut bytemuck::cast_slice_mut::<u64, u8>(&mut aligned)[..data.len()];
+        bytes.copy_from_slice(data);
+
+        let (header, scores) = LeaderTopEntriesV2::split(bytes)?;
+        Ok(TopEntries {
+            header: *header,
+            scores: scores.to_vec(),
+        })
+    }
+
+    /// Player entries in rank order, skipping unused slots.
+    pub fn ranked(&self) -> impl Iterator<Item = &LeaderBoardScore> {
+        self.scores.iter().filter(|score| !score.is_placeholder())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use soar::ScoreEntry;
+
+    #[test]
+    fn top_entries_decode_from_unaligned_data() {
+        let leaderboard = Pubkey::new_unique();
+        let player = Pubkey::new_unique();
+        let mut data = LeaderTopEntriesV2::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&LeaderTopEntriesV2::new(
+            leaderboard,
+            2,
+            false,
+        )));
+        data.extend_from_slice(bytemuck::bytes_of(&LeaderBoardScore::new(
+            player,
+            ScoreEntry::new(5, 1),
+        )));
+        data.extend_from_slice(bytemuck::bytes_of(&LeaderBoardScore::default()));
+
+        // Shift the data off any alignment the allocator happened to provide.
+        let mut unaligned = vec![0u8];
+        unaligned.extend_from_slice(&data);
+        let top_entries = TopEntries::decode(&unaligned[1..]).unwrap();
+
+        assert_eq!(top_entries.header.leaderboard, leaderboard);
+        assert_eq!(top_entries.scores.len(), 2);
+        let ranked: Vec<_> = top_entries.ranked().map(|s| s.player).collect();
+        assert_eq!(ranked, vec![player]);
+
+        assert!(TopEntries::decode(&unaligned[2..]).is_err());
+    }
+}
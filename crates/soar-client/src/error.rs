@@ -0,0 +1,30 @@
+use anchor_lang::prelude::Pubkey;
+use solana_client::client_error::ClientError;
+
+/// Errors returned by [SoarClient][crate::SoarClient].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The RPC request failed.
+    #[error(transparent)]
+    Rpc(Box<ClientError>),
+
+    /// An account's data couldn't be decoded as the expected type.
+    #[error("failed to decode account: {0}")]
+    Decode(#[from] anchor_lang::error::Error),
+
+    /// An account needed to build an instruction doesn't exist.
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+
+    /// On-chain state doesn't allow building the requested instruction.
+    #[error("{0}")]
+    InvalidState(&'static str),
+}
+
+impl From<ClientError> for Error {
+    fn from(error: ClientError) -> Self {
+        Error::Rpc(Box::new(error))
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,1475 @@
+//! Builders for every SOAR instruction.
+//!
+//! Program-derived accounts are derived from the keys passed in. Accounts that depend on
+//! on-chain state, such as a leaderboard's optional top entries, are taken as arguments;
+//! [SoarClient][crate::SoarClient] fetches them for you.
+
+use crate::pda;
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{
+        ed25519_program,
+        instruction::{AccountMeta, Instruction},
+        system_program, sysvar,
+    },
+    AnchorSerialize, InstructionData, ToAccountMetas,
+};
+use soar::{
+    accounts, instruction, AchievementRule, AddNewRewardInput, AddRankRewardInput, GameAttributes,
+    GameAuthority, ProposalAction, RegisterLeaderBoardInput, ScoreAttestation,
+    UpdateLeaderBoardInput,
+};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: soar::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// `(achievement, player_achievement)` remaining accounts for the achievements a score
+/// submission should try to unlock.
+fn achievement_pairs(player_account: &Pubkey, achievements: &[Pubkey]) -> Vec<AccountMeta> {
+    achievements
+        .iter()
+        .flat_map(|achievement| {
+            let (player_achievement, _) = pda::find_player_achievement(player_account, achievement);
+            [
+                AccountMeta::new_readonly(*achievement, false),
+                AccountMeta::new(player_achievement, false),
+            ]
+        })
+        .collect()
+}
+
+/// Create a [Game][soar::Game] at the address of the new `game` keypair.
+pub fn initialize_game(
+    creator: Pubkey,
+    game: Pubkey,
+    game_meta: GameAttributes,
+    game_auth: Vec<GameAuthority>,
+) -> Instruction {
+    build(
+        accounts::InitializeGame {
+            creator,
+            game,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeGame {
+            game_meta,
+            game_auth,
+        },
+    )
+}
+
+pub fn update_game(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    new_meta: Option<GameAttributes>,
+    new_auth: Option<Vec<GameAuthority>>,
+) -> Instruction {
+    build(
+        accounts::UpdateGame {
+            authority,
+            payer,
+            game,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateGame { new_meta, new_auth },
+    )
+}
+
+/// Create a proposal with the game's next proposal `id`.
+pub fn create_proposal(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    id: u64,
+    action: ProposalAction,
+) -> Instruction {
+    build(
+        accounts::CreateProposal {
+            authority,
+            payer,
+            game,
+            proposal: pda::find_proposal(&game, id).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateProposal { action },
+    )
+}
+
+pub fn approve_proposal(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    proposal: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ApproveProposal {
+            authority,
+            payer,
+            game,
+            proposal,
+            system_program: system_program::ID,
+        },
+        instruction::ApproveProposal {},
+    )
+}
+
+pub fn execute_proposal(payer: Pubkey, game: Pubkey, proposal: Pubkey) -> Instruction {
+    build(
+        accounts::ExecuteProposal {
+            payer,
+            game,
+            proposal,
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteProposal {},
+    )
+}
+
+/// Add an achievement with the game's next achievement `id`.
+#[allow(clippy::too_many_arguments)]
+pub fn add_achievement(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    id: u64,
+    title: String,
+    description: String,
+    nft_meta: Pubkey,
+    unlock_rule: Option<AchievementRule>,
+    progress_target: Option<u64>,
+) -> Instruction {
+    build(
+        accounts::AddAchievement {
+            authority,
+            payer,
+            game,
+            new_achievement: pda::find_achievement(&game, id).0,
+            system_program: system_program::ID,
+        },
+        instruction::AddAchievement {
+            title,
+            description,
+            nft_meta,
+            unlock_rule,
+            progress_target,
+        },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_achievement(
+    authority: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    new_title: Option<String>,
+    new_description: Option<String>,
+    nft_meta: Option<Pubkey>,
+    new_unlock_rule: Option<Option<AchievementRule>>,
+) -> Instruction {
+    build(
+        accounts::UpdateAchievement {
+            authority,
+            game,
+            achievement,
+        },
+        instruction::UpdateAchievement {
+            new_title,
+            new_description,
+            nft_meta,
+            new_unlock_rule,
+        },
+    )
+}
+
+/// Add a leaderboard with the game's next leaderboard `id`, creating its top entries
+/// if `input.scores_to_retain` is non-zero.
+pub fn add_leaderboard(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    id: u64,
+    input: RegisterLeaderBoardInput,
+    proposal: Option<Pubkey>,
+) -> Instruction {
+    let (leaderboard, _) = pda::find_leaderboard(&game, id);
+    let top_entries = (input.scores_to_retain > 0).then(|| pda::find_top_entries(&leaderboard).0);
+    build(
+        accounts::AddLeaderBoard {
+            authority,
+            payer,
+            game,
+            leaderboard,
+            top_entries,
+            system_program: system_program::ID,
+            proposal,
+        },
+        instruction::AddLeaderboard { input },
+    )
+}
+
+/// Update a leaderboard. `top_entries` is the leaderboard's `top_entries`, and is only
+/// required when changing its order or tie-break rule.
+pub fn update_leaderboard(
+    authority: Pubkey,
+    game: Pubkey,
+    leaderboard: Pubkey,
+    top_entries: Option<Pubkey>,
+    input: UpdateLeaderBoardInput,
+) -> Instruction {
+    build(
+        accounts::UpdateLeaderBoard {
+            authority,
+            game,
+            leaderboard,
+            top_entries,
+        },
+        instruction::UpdateLeaderboard { input },
+    )
+}
+
+pub fn resize_top_entries(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    leaderboard: Pubkey,
+    new_len: u16,
+    drop_entries: bool,
+) -> Instruction {
+    build(
+        accounts::ResizeTopEntries {
+            authority,
+            payer,
+            game,
+            leaderboard,
+            top_entries: pda::find_top_entries(&leaderboard).0,
+            system_program: system_program::ID,
+        },
+        instruction::ResizeTopEntries {
+            new_len,
+            drop_entries,
+        },
+    )
+}
+
+/// Convert a game from the legacy layout.
+pub fn migrate_game(payer: Pubkey, game: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateGame {
+            payer,
+            game,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateGame {},
+    )
+}
+
+pub fn migrate_top_entries(payer: Pubkey, leaderboard: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateTopEntries {
+            payer,
+            leaderboard,
+            top_entries: pda::find_top_entries(&leaderboard).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateTopEntries {},
+    )
+}
+
+/// Convert `player_account`'s scores for a leaderboard from the legacy layout.
+pub fn migrate_player_scores(
+    payer: Pubkey,
+    player_account: Pubkey,
+    leaderboard: Pubkey,
+    top_entries: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::MigratePlayerScores {
+            payer,
+            player_account,
+            leaderboard,
+            top_entries,
+            player_scores: pda::find_player_scores(&player_account, &leaderboard).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigratePlayerScores {},
+    )
+}
+
+/// Convert a merge account from the legacy layout.
+pub fn migrate_merge(payer: Pubkey, merge_account: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateMerge {
+            payer,
+            merge_account,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateMerge {},
+    )
+}
+
+/// Convert a player's achievement status from the legacy layout.
+pub fn migrate_player_achievement(
+    payer: Pubkey,
+    player_account: Pubkey,
+    achievement: Pubkey,
+) -> Instruction {
+    build(
+        accounts::MigratePlayerAchievement {
+            payer,
+            player_achievement: pda::find_player_achievement(&player_account, &achievement).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigratePlayerAchievement {},
+    )
+}
+
+/// Close the leaderboard's current `season`, archiving its top entries if it has them.
+pub fn close_season(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    leaderboard: Pubkey,
+    top_entries: Option<Pubkey>,
+    season: u64,
+) -> Instruction {
+    build(
+        accounts::CloseSeason {
+            authority,
+            payer,
+            game,
+            leaderboard,
+            top_entries,
+            archive: pda::find_season_archive(&leaderboard, season).0,
+            system_program: system_program::ID,
+        },
+        instruction::CloseSeason {},
+    )
+}
+
+pub fn start_season(
+    authority: Pubkey,
+    game: Pubkey,
+    leaderboard: Pubkey,
+    top_entries: Option<Pubkey>,
+    season_end: Option<i64>,
+) -> Instruction {
+    build(
+        accounts::StartSeason {
+            authority,
+            game,
+            leaderboard,
+            top_entries,
+        },
+        instruction::StartSeason { season_end },
+    )
+}
+
+pub fn initialize_player(
+    payer: Pubkey,
+    user: Pubkey,
+    username: String,
+    nft_meta: Pubkey,
+) -> Instruction {
+    build(
+        accounts::InitializePlayer {
+            payer,
+            user,
+            player_account: pda::find_player(&user).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitializePlayer { username, nft_meta },
+    )
+}
+
+pub fn update_player(
+    user: Pubkey,
+    username: Option<String>,
+    nft_meta: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::UpdatePlayer {
+            user,
+            player_account: pda::find_player(&user).0,
+        },
+        instruction::UpdatePlayer { username, nft_meta },
+    )
+}
+
+pub fn register_player(
+    payer: Pubkey,
+    user: Pubkey,
+    game: Pubkey,
+    leaderboard: Pubkey,
+) -> Instruction {
+    let (player_account, _) = pda::find_player(&user);
+    build(
+        accounts::RegisterPlayer {
+            payer,
+            user,
+            player_account,
+            game,
+            leaderboard,
+            new_list: pda::find_player_scores(&player_account, &leaderboard).0,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterPlayer {},
+    )
+}
+
+/// Submit a score for `user`'s player, trying to unlock each of `achievements`.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_score(
+    payer: Pubkey,
+    authority: Pubkey,
+    user: Pubkey,
+    game: Pubkey,
+    leaderboard: Pubkey,
+    top_entries: Option<Pubkey>,
+    score: u64,
+    secondary_score: Option<u64>,
+    context: Option<[u8; 32]>,
+    achievements: &[Pubkey],
+) -> Instruction {
+    let (player_account, _) = pda::find_player(&user);
+    let mut ix = build(
+        accounts::SubmitScore {
+            payer,
+            authority,
+            player_account,
+            game,
+            leaderboard,
+            player_scores: pda::find_player_scores(&player_account, &leaderboard).0,
+            top_entries,
+            system_program: system_program::ID,
+        },
+        instruction::SubmitScore {
+            score,
+            secondary_score,
+            context,
+        },
+    );
+    ix.accounts
+        .extend(achievement_pairs(&player_account, achievements));
+    ix
+}
+
+/// Submit an `attestation` signed by a game attester. Must directly follow
+/// [attestation_verification] for the same attestation.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_attested_score(
+    payer: Pubkey,
+    user: Pubkey,
+    game: Pubkey,
+    top_entries: Option<Pubkey>,
+    attestation: &ScoreAttestation,
+    achievements: &[Pubkey],
+) -> Instruction {
+    let player_account = attestation.player_account;
+    let leaderboard = attestation.leaderboard;
+    let mut ix = build(
+        accounts::SubmitAttestedScore {
+            payer,
+            user,
+            player_account,
+            game,
+            leaderboard,
+            player_scores: pda::find_player_scores(&player_account, &leaderboard).0,
+            top_entries,
+            instructions: sysvar::instructions::ID,
+            system_program: system_program::ID,
+        },
+        instruction::SubmitAttestedScore {
+            score: attestation.score,
+            secondary_score: attestation.secondary_score,
+            context: Some(attestation.context),
+            nonce: attestation.nonce,
+            expiry: attestation.expiry,
+        },
+    );
+    ix.accounts
+        .extend(achievement_pairs(&player_account, achievements));
+    ix
+}
+
+/// Ed25519 program instruction verifying `signature` by `attester` over `attestation`.
+pub fn attestation_verification(
+    attester: &Pubkey,
+    signature: &[u8; 64],
+    attestation: &ScoreAttestation,
+) -> Instruction {
+    const HEADER_LEN: u16 = 2 + 14;
+    const PUBKEY_OFFSET: u16 = HEADER_LEN;
+    const SIGNATURE_OFFSET: u16 = PUBKEY_OFFSET + 32;
+    const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+
+    let message = attestation.try_to_vec().unwrap();
+    let mut data = vec![1, 0];
+    for offset in [
+        SIGNATURE_OFFSET,
+        u16::MAX,
+        PUBKEY_OFFSET,
+        u16::MAX,
+        MESSAGE_OFFSET,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(attester.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(&message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Start merging the players in `keys` into `user`'s player, using the new
+/// `merge_account` keypair.
+pub fn initiate_merge(
+    payer: Pubkey,
+    user: Pubkey,
+    merge_account: Pubkey,
+    keys: Vec<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::InitiateMerge {
+            payer,
+            user,
+            player_account: pda::find_player(&user).0,
+            merge_account,
+            system_program: system_program::ID,
+        },
+        instruction::InitiateMerge { keys },
+    )
+}
+
+pub fn approve_merge(user: Pubkey, merge_account: Pubkey) -> Instruction {
+    build(
+        accounts::ApproveMerge {
+            user,
+            player_account: pda::find_player(&user).0,
+            merge_account,
+        },
+        instruction::ApproveMerge {},
+    )
+}
+
+/// Reject a merge, refunding its rent to the merge's `payer`.
+pub fn reject_merge(user: Pubkey, merge_account: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::RejectMerge {
+            user,
+            player_account: pda::find_player(&user).0,
+            merge_account,
+            payer,
+        },
+        instruction::RejectMerge {},
+    )
+}
+
+/// Cancel a merge, refunding its rent to the merge's `payer`.
+pub fn cancel_merge(initiator: Pubkey, merge_account: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::CancelMerge {
+            initiator,
+            merge_account,
+            payer,
+        },
+        instruction::CancelMerge {},
+    )
+}
+
+/// Close a completed merge, refunding its rent to the merge's `payer`.
+pub fn close_merge(authority: Pubkey, merge_account: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        accounts::CloseMerge {
+            authority,
+            merge_account,
+            payer,
+        },
+        instruction::CloseMerge {},
+    )
+}
+
+/// Move a merged player's scores for `leaderboard` into those of the merge's `initiator`.
+pub fn merge_player_scores(
+    payer: Pubkey,
+    initiator: Pubkey,
+    merge_account: Pubkey,
+    merged_player_account: Pubkey,
+    leaderboard: Pubkey,
+    top_entries: Option<Pubkey>,
+) -> Instruction {
+    let (player_account, _) = pda::find_player(&initiator);
+    build(
+        accounts::MergePlayerScores {
+            payer,
+            merge_account,
+            player_account,
+            merged_player_scores: pda::find_player_scores(&merged_player_account, &leaderboard).0,
+            player_scores: pda::find_player_scores(&player_account, &leaderboard).0,
+            leaderboard,
+            top_entries,
+            system_program: system_program::ID,
+        },
+        instruction::MergePlayerScores {},
+    )
+}
+
+/// Move a merged player's achievement into the merge's `initiator`'s player.
+pub fn merge_player_achievement(
+    payer: Pubkey,
+    initiator: Pubkey,
+    merge_account: Pubkey,
+    merged_player_account: Pubkey,
+    achievement: Pubkey,
+) -> Instruction {
+    let (player_account, _) = pda::find_player(&initiator);
+    build(
+        accounts::MergePlayerAchievement {
+            payer,
+            merge_account,
+            player_account,
+            achievement,
+            merged_player_achievement: pda::find_player_achievement(
+                &merged_player_account,
+                &achievement,
+            )
+            .0,
+            player_achievement: pda::find_player_achievement(&player_account, &achievement).0,
+            system_program: system_program::ID,
+        },
+        instruction::MergePlayerAchievement {},
+    )
+}
+
+/// Reassign merged players' entries in a leaderboard's top entries to the merge's `initiator`.
+pub fn merge_top_entries(
+    initiator: Pubkey,
+    merge_account: Pubkey,
+    leaderboard: Pubkey,
+) -> Instruction {
+    build(
+        accounts::MergeTopEntries {
+            merge_account,
+            player_account: pda::find_player(&initiator).0,
+            leaderboard,
+            top_entries: pda::find_top_entries(&leaderboard).0,
+        },
+        instruction::MergeTopEntries {},
+    )
+}
+
+pub fn unlock_player_achievement(
+    authority: Pubkey,
+    payer: Pubkey,
+    user: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+) -> Instruction {
+    let (player_account, _) = pda::find_player(&user);
+    build(
+        accounts::UnlockPlayerAchievement {
+            authority,
+            payer,
+            player_account,
+            game,
+            achievement,
+            player_achievement: pda::find_player_achievement(&player_account, &achievement).0,
+            system_program: system_program::ID,
+        },
+        instruction::UnlockPlayerAchievement {},
+    )
+}
+
+pub fn increment_achievement_progress(
+    authority: Pubkey,
+    payer: Pubkey,
+    user: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (player_account, _) = pda::find_player(&user);
+    build(
+        accounts::IncrementAchievementProgress {
+            authority,
+            payer,
+            player_account,
+            game,
+            achievement,
+            player_achievement: pda::find_player_achievement(&player_account, &achievement).0,
+            system_program: system_program::ID,
+        },
+        instruction::IncrementAchievementProgress { amount },
+    )
+}
+
+/// Add a fungible token reward at the address of the new `new_reward` keypair, delegating
+/// the deposit from `delegate_from_token_account`.
+#[allow(clippy::too_many_arguments)]
+pub fn add_ft_reward(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    new_reward: Pubkey,
+    reward_token_mint: Pubkey,
+    delegate_from_token_account: Pubkey,
+    token_account_owner: Pubkey,
+    input: AddNewRewardInput,
+    proposal: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::AddFtReward {
+            authority,
+            payer,
+            game,
+            achievement,
+            new_reward,
+            reward_token_mint,
+            delegate_from_token_account,
+            token_account_owner,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            proposal,
+        },
+        instruction::AddFtReward { input },
+    )
+}
+
+/// Add an NFT reward at the address of the new `new_reward` keypair. `collection` is the
+/// collection mint and its update authority, if minted NFTs belong to a collection.
+#[allow(clippy::too_many_arguments)]
+pub fn add_nft_reward(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    new_reward: Pubkey,
+    collection: Option<(Pubkey, Pubkey)>,
+    input: AddNewRewardInput,
+    proposal: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::AddNftReward {
+            authority,
+            payer,
+            game,
+            achievement,
+            new_reward,
+            system_program: system_program::ID,
+            reward_collection_mint: collection.map(|(mint, _)| mint),
+            collection_update_auth: collection.map(|(_, update_auth)| update_auth),
+            collection_metadata: collection.map(|(mint, _)| pda::find_metadata(&mint).0),
+            token_metadata_program: collection.map(|_| mpl_token_metadata::ID),
+            proposal,
+        },
+        instruction::AddNftReward { input },
+    )
+}
+
+/// Add a rank reward for a leaderboard's current `season`.
+#[allow(clippy::too_many_arguments)]
+pub fn add_rank_reward(
+    authority: Pubkey,
+    payer: Pubkey,
+    game: Pubkey,
+    leaderboard: Pubkey,
+    season: u64,
+    reward_token_mint: Pubkey,
+    delegate_from_token_account: Pubkey,
+    token_account_owner: Pubkey,
+    input: AddRankRewardInput,
+    proposal: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::AddRankReward {
+            authority,
+            payer,
+            game,
+            leaderboard,
+            top_entries: pda::find_top_entries(&leaderboard).0,
+            rank_reward: pda::find_rank_reward(&leaderboard, season).0,
+            reward_token_mint,
+            delegate_from_token_account,
+            token_account_owner,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            proposal,
+        },
+        instruction::AddRankReward { input },
+    )
+}
+
+/// Claim the reward for `rank` in a leaderboard's closed `season`, paid from the rank
+/// reward's `source_token_account` to `user`'s associated token account for `mint`.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_rank_reward(
+    user: Pubkey,
+    payer: Pubkey,
+    leaderboard: Pubkey,
+    season: u64,
+    source_token_account: Pubkey,
+    mint: Pubkey,
+    rank: u32,
+) -> Instruction {
+    let (rank_reward, _) = pda::find_rank_reward(&leaderboard, season);
+    build(
+        accounts::ClaimRankReward {
+            user,
+            payer,
+            player_account: pda::find_player(&user).0,
+            leaderboard,
+            rank_reward,
+            archive: pda::find_season_archive(&leaderboard, season).0,
+            claim: pda::find_rank_reward_claim(&rank_reward, rank).0,
+            source_token_account,
+            user_token_account: pda::associated_token_address(&user, &mint),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimRankReward { rank },
+    )
+}
+
+/// Claim a fungible token reward, paid from the reward's `source_token_account` to
+/// `user`'s associated token account for `mint`.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_ft_reward(
+    authority: Pubkey,
+    payer: Pubkey,
+    user: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    reward: Pubkey,
+    source_token_account: Pubkey,
+    mint: Pubkey,
+) -> Instruction {
+    let (player_account, _) = pda::find_player(&user);
+    build(
+        accounts::ClaimFtReward {
+            user,
+            authority,
+            payer,
+            game,
+            achievement,
+            reward,
+            player_account,
+            player_achievement: pda::find_player_achievement(&player_account, &achievement).0,
+            source_token_account,
+            user_token_account: pda::associated_token_address(&user, &mint),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimFtReward {},
+    )
+}
+
+/// Claim an NFT reward, minting it to `user` at the address of the new `new_mint` keypair.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_nft_reward(
+    authority: Pubkey,
+    payer: Pubkey,
+    user: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    reward: Pubkey,
+    new_mint: Pubkey,
+) -> Instruction {
+    let (player_account, _) = pda::find_player(&user);
+    build(
+        accounts::ClaimNftReward {
+            user,
+            authority,
+            game,
+            payer,
+            achievement,
+            reward,
+            player_account,
+            player_achievement: pda::find_player_achievement(&player_account, &achievement).0,
+            claim: pda::find_nft_claim(&reward, &new_mint).0,
+            new_mint,
+            new_metadata: pda::find_metadata(&new_mint).0,
+            new_master_edition: pda::find_master_edition(&new_mint).0,
+            mint_to: pda::associated_token_address(&user, &new_mint),
+            token_metadata_program: mpl_token_metadata::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            token_program: anchor_spl::token::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::ClaimNftReward {},
+    )
+}
+
+/// Verify a claimed NFT `mint` as part of the reward's `collection_mint` collection.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_nft_reward(
+    payer: Pubkey,
+    user: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    reward: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+) -> Instruction {
+    let (player_account, _) = pda::find_player(&user);
+    build(
+        accounts::VerifyNftReward {
+            payer,
+            game,
+            achievement,
+            reward,
+            user,
+            player_account,
+            claim: pda::find_nft_claim(&reward, &mint).0,
+            player_achievement: pda::find_player_achievement(&player_account, &achievement).0,
+            mint,
+            metadata_to_verify: pda::find_metadata(&mint).0,
+            collection_mint,
+            collection_metadata: pda::find_metadata(&collection_mint).0,
+            collection_edition: pda::find_master_edition(&collection_mint).0,
+            token_metadata_program: mpl_token_metadata::ID,
+        },
+        instruction::VerifyNftReward {},
+    )
+}
+
+pub fn close_achievement(
+    authority: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    receiver: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseAchievement {
+            authority,
+            game,
+            achievement,
+            receiver,
+        },
+        instruction::CloseAchievement {},
+    )
+}
+
+pub fn close_reward(
+    authority: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    reward: Pubkey,
+    receiver: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseReward {
+            authority,
+            game,
+            achievement,
+            reward,
+            receiver,
+        },
+        instruction::CloseReward {},
+    )
+}
+
+pub fn close_leaderboard(
+    authority: Pubkey,
+    game: Pubkey,
+    leaderboard: Pubkey,
+    top_entries: Option<Pubkey>,
+    receiver: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseLeaderBoard {
+            authority,
+            game,
+            leaderboard,
+            top_entries,
+            receiver,
+        },
+        instruction::CloseLeaderboard {},
+    )
+}
+
+pub fn close_player_scores(user: Pubkey, leaderboard: Pubkey, receiver: Pubkey) -> Instruction {
+    let (player_account, _) = pda::find_player(&user);
+    build(
+        accounts::ClosePlayerScores {
+            user,
+            player_account,
+            leaderboard,
+            player_scores: pda::find_player_scores(&player_account, &leaderboard).0,
+            receiver,
+        },
+        instruction::ClosePlayerScores {},
+    )
+}
+
+pub fn close_player_achievement(
+    authority: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    player_account: Pubkey,
+    receiver: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ClosePlayerAchievement {
+            authority,
+            game,
+            achievement,
+            player_achievement: pda::find_player_achievement(&player_account, &achievement).0,
+            receiver,
+        },
+        instruction::ClosePlayerAchievement {},
+    )
+}
+
+pub fn close_nft_claim(
+    authority: Pubkey,
+    game: Pubkey,
+    achievement: Pubkey,
+    reward: Pubkey,
+    mint: Pubkey,
+    receiver: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseNftClaim {
+            authority,
+            game,
+            achievement,
+            reward,
+            mint,
+            claim: pda::find_nft_claim(&reward, &mint).0,
+            receiver,
+        },
+        instruction::CloseNftClaim {},
+    )
+}
+
+pub fn close_player(user: Pubkey, receiver: Pubkey) -> Instruction {
+    build(
+        accounts::ClosePlayer {
+            user,
+            player_account: pda::find_player(&user).0,
+            receiver,
+        },
+        instruction::ClosePlayer {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use soar::RankRewardTier;
+
+    fn expected(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+        Instruction {
+            program_id: soar::ID,
+            accounts: accounts.to_account_metas(None),
+            data: data.data(),
+        }
+    }
+
+    #[test]
+    fn create_proposal_derives_the_proposal_from_its_id() {
+        let (authority, payer, game) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let action = ProposalAction::SetThreshold { threshold: 2 };
+        assert_eq!(
+            create_proposal(authority, payer, game, 3, action.clone()),
+            expected(
+                accounts::CreateProposal {
+                    authority,
+                    payer,
+                    game,
+                    proposal: pda::find_proposal(&game, 3).0,
+                    system_program: system_program::ID,
+                },
+                instruction::CreateProposal { action },
+            )
+        );
+    }
+
+    #[test]
+    fn add_leaderboard_only_creates_top_entries_when_retaining_scores() {
+        let (authority, payer, game) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let (leaderboard, _) = pda::find_leaderboard(&game, 1);
+        for scores_to_retain in [0, 10] {
+            let input = RegisterLeaderBoardInput {
+                scores_to_retain,
+                ..Default::default()
+            };
+            assert_eq!(
+                add_leaderboard(authority, payer, game, 1, input.clone(), None),
+                expected(
+                    accounts::AddLeaderBoard {
+                        authority,
+                        payer,
+                        game,
+                        leaderboard,
+                        top_entries: (scores_to_retain > 0)
+                            .then(|| pda::find_top_entries(&leaderboard).0),
+                        system_program: system_program::ID,
+                        proposal: None,
+                    },
+                    instruction::AddLeaderboard { input },
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn register_player_derives_the_users_player_and_scores() {
+        let (payer, user, game, leaderboard) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let (player_account, _) = pda::find_player(&user);
+        assert_eq!(
+            register_player(payer, user, game, leaderboard),
+            expected(
+                accounts::RegisterPlayer {
+                    payer,
+                    user,
+                    player_account,
+                    game,
+                    leaderboard,
+                    new_list: pda::find_player_scores(&player_account, &leaderboard).0,
+                    system_program: system_program::ID,
+                },
+                instruction::RegisterPlayer {},
+            )
+        );
+    }
+
+    #[test]
+    fn migrations_derive_the_accounts_they_convert() {
+        let (payer, player_account, leaderboard, achievement) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let (top_entries, _) = pda::find_top_entries(&leaderboard);
+        assert_eq!(
+            migrate_player_scores(payer, player_account, leaderboard, Some(top_entries)),
+            expected(
+                accounts::MigratePlayerScores {
+                    payer,
+                    player_account,
+                    leaderboard,
+                    top_entries: Some(top_entries),
+                    player_scores: pda::find_player_scores(&player_account, &leaderboard).0,
+                    system_program: system_program::ID,
+                },
+                instruction::MigratePlayerScores {},
+            )
+        );
+        assert_eq!(
+            migrate_player_achievement(payer, player_account, achievement),
+            expected(
+                accounts::MigratePlayerAchievement {
+                    payer,
+                    player_achievement: pda::find_player_achievement(&player_account, &achievement)
+                        .0,
+                    system_program: system_program::ID,
+                },
+                instruction::MigratePlayerAchievement {},
+            )
+        );
+        assert_eq!(
+            migrate_top_entries(payer, leaderboard),
+            expected(
+                accounts::MigrateTopEntries {
+                    payer,
+                    leaderboard,
+                    top_entries,
+                    system_program: system_program::ID,
+                },
+                instruction::MigrateTopEntries {},
+            )
+        );
+
+        let (game, merge_account) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(
+            migrate_game(payer, game),
+            expected(
+                accounts::MigrateGame {
+                    payer,
+                    game,
+                    system_program: system_program::ID,
+                },
+                instruction::MigrateGame {},
+            )
+        );
+        assert_eq!(
+            migrate_merge(payer, merge_account),
+            expected(
+                accounts::MigrateMerge {
+                    payer,
+                    merge_account,
+                    system_program: system_program::ID,
+                },
+                instruction::MigrateMerge {},
+            )
+        );
+    }
+
+    #[test]
+    fn season_builders_derive_accounts_from_the_season() {
+        let (authority, payer, game, leaderboard) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let (top_entries, _) = pda::find_top_entries(&leaderboard);
+        assert_eq!(
+            close_season(authority, payer, game, leaderboard, Some(top_entries), 4),
+            expected(
+                accounts::CloseSeason {
+                    authority,
+                    payer,
+                    game,
+                    leaderboard,
+                    top_entries: Some(top_entries),
+                    archive: pda::find_season_archive(&leaderboard, 4).0,
+                    system_program: system_program::ID,
+                },
+                instruction::CloseSeason {},
+            )
+        );
+
+        let (mint, delegate_from, owner) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let input = AddRankRewardInput {
+            deposit: 100,
+            tiers: vec![RankRewardTier {
+                start_rank: 1,
+                end_rank: 3,
+                amount: 10,
+            }],
+        };
+        assert_eq!(
+            add_rank_reward(
+                authority,
+                payer,
+                game,
+                leaderboard,
+                4,
+                mint,
+                delegate_from,
+                owner,
+                input.clone(),
+                None,
+            ),
+            expected(
+                accounts::AddRankReward {
+                    authority,
+                    payer,
+                    game,
+                    leaderboard,
+                    top_entries,
+                    rank_reward: pda::find_rank_reward(&leaderboard, 4).0,
+                    reward_token_mint: mint,
+                    delegate_from_token_account: delegate_from,
+                    token_account_owner: owner,
+                    token_program: anchor_spl::token::ID,
+                    system_program: system_program::ID,
+                    proposal: None,
+                },
+                instruction::AddRankReward { input },
+            )
+        );
+
+        let (user, source) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (rank_reward, _) = pda::find_rank_reward(&leaderboard, 4);
+        assert_eq!(
+            claim_rank_reward(user, payer, leaderboard, 4, source, mint, 2),
+            expected(
+                accounts::ClaimRankReward {
+                    user,
+                    payer,
+                    player_account: pda::find_player(&user).0,
+                    leaderboard,
+                    rank_reward,
+                    archive: pda::find_season_archive(&leaderboard, 4).0,
+                    claim: pda::find_rank_reward_claim(&rank_reward, 2).0,
+                    source_token_account: source,
+                    user_token_account: pda::associated_token_address(&user, &mint),
+                    token_program: anchor_spl::token::ID,
+                    system_program: system_program::ID,
+                },
+                instruction::ClaimRankReward { rank: 2 },
+            )
+        );
+    }
+
+    #[test]
+    fn merge_builders_tell_the_initiator_from_the_merged_player() {
+        let (payer, initiator, merge_account, merged, leaderboard, achievement) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let (player_account, _) = pda::find_player(&initiator);
+        assert_eq!(
+            merge_player_scores(payer, initiator, merge_account, merged, leaderboard, None),
+            expected(
+                accounts::MergePlayerScores {
+                    payer,
+                    merge_account,
+                    player_account,
+                    merged_player_scores: pda::find_player_scores(&merged, &leaderboard).0,
+                    player_scores: pda::find_player_scores(&player_account, &leaderboard).0,
+                    leaderboard,
+                    top_entries: None,
+                    system_program: system_program::ID,
+                },
+                instruction::MergePlayerScores {},
+            )
+        );
+        assert_eq!(
+            merge_player_achievement(payer, initiator, merge_account, merged, achievement),
+            expected(
+                accounts::MergePlayerAchievement {
+                    payer,
+                    merge_account,
+                    player_account,
+                    achievement,
+                    merged_player_achievement: pda::find_player_achievement(&merged, &achievement)
+                        .0,
+                    player_achievement: pda::find_player_achievement(&player_account, &achievement)
+                        .0,
+                    system_program: system_program::ID,
+                },
+                instruction::MergePlayerAchievement {},
+            )
+        );
+    }
+
+    #[test]
+    fn claim_nft_reward_derives_the_new_mints_accounts() {
+        let (authority, payer, user, game, achievement, reward, new_mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let (player_account, _) = pda::find_player(&user);
+        assert_eq!(
+            claim_nft_reward(authority, payer, user, game, achievement, reward, new_mint),
+            expected(
+                accounts::ClaimNftReward {
+                    user,
+                    authority,
+                    game,
+                    payer,
+                    achievement,
+                    reward,
+                    player_account,
+                    player_achievement: pda::find_player_achievement(&player_account, &achievement)
+                        .0,
+                    claim: pda::find_nft_claim(&reward, &new_mint).0,
+                    new_mint,
+                    new_metadata: pda::find_metadata(&new_mint).0,
+                    new_master_edition: pda::find_master_edition(&new_mint).0,
+                    mint_to: pda::associated_token_address(&user, &new_mint),
+                    token_metadata_program: mpl_token_metadata::ID,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                    system_program: system_program::ID,
+                    token_program: anchor_spl::token::ID,
+                    rent: sysvar::rent::ID,
+                },
+                instruction::ClaimNftReward {},
+            )
+        );
+    }
+
+    #[test]
+    fn submit_score_appends_achievement_pairs() {
+        let user = Pubkey::new_unique();
+        let leaderboard = Pubkey::new_unique();
+        let achievement = Pubkey::new_unique();
+        let ix = submit_score(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            user,
+            Pubkey::new_unique(),
+            leaderboard,
+            None,
+            42,
+            None,
+            None,
+            &[achievement],
+        );
+
+        let (player_account, _) = pda::find_player(&user);
+        assert_eq!(ix.accounts[2].pubkey, player_account);
+        assert_eq!(
+            ix.accounts[5].pubkey,
+            pda::find_player_scores(&player_account, &leaderboard).0
+        );
+        // Missing optional accounts are passed as the program id.
+        assert_eq!(ix.accounts[6].pubkey, soar::ID);
+
+        let pair = &ix.accounts[8..];
+        assert_eq!(pair[0].pubkey, achievement);
+        assert_eq!(
+            pair[1].pubkey,
+            pda::find_player_achievement(&player_account, &achievement).0
+        );
+        assert!(pair[1].is_writable);
+
+        assert_eq!(ix.data[..8], instruction::SubmitScore::DISCRIMINATOR);
+        assert_eq!(ix.data[8..16], 42u64.to_le_bytes());
+    }
+
+    #[test]
+    fn attestation_verification_points_at_its_own_data() {
+        let attester = Pubkey::new_unique();
+        let attestation = ScoreAttestation {
+            score: 7,
+            ..Default::default()
+        };
+        let ix = attestation_verification(&attester, &[9; 64], &attestation);
+
+        let read_u16 = |at: usize| u16::from_le_bytes([ix.data[at], ix.data[at + 1]]) as usize;
+        let (signature, pubkey, message, len) =
+            (read_u16(2), read_u16(6), read_u16(10), read_u16(12));
+        assert_eq!(ix.data[pubkey..pubkey + 32], attester.to_bytes());
+        assert_eq!(ix.data[signature..signature + 64], [9; 64]);
+        assert_eq!(
+            ix.data[message..message + len],
+            attestation.try_to_vec().unwrap()
+        );
+    }
+}
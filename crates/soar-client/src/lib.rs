@@ -0,0 +1,19 @@
+//! Rust client for the SOAR program.
+//!
+//! - [pda] derives the address of every account the program creates.
+//! - [instructions] builds each program instruction from the accounts it's given, deriving
+//!   the PDAs itself.
+//! - [SoarClient] fetches and decodes accounts over RPC, and builds the instructions that
+//!   need on-chain state (next ids, top entries, reward mints) to resolve their accounts.
+
+pub mod decode;
+pub mod instructions;
+pub mod pda;
+
+mod client;
+mod error;
+
+pub use client::SoarClient;
+pub use decode::TopEntries;
+pub use error::{Error, Result};
+pub use soar;
@@ -0,0 +1,20 @@
+//! Address derivation for every SOAR program-derived account, plus the Metaplex and token
+//! accounts the program's reward instructions touch.
+
+use anchor_lang::prelude::Pubkey;
+pub use soar_cpi::pda::*;
+
+/// Address of the Metaplex metadata account of `mint`.
+pub fn find_metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::pda::find_metadata_account(mint)
+}
+
+/// Address of the Metaplex master edition account of `mint`.
+pub fn find_master_edition(mint: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::pda::find_master_edition_account(mint)
+}
+
+/// Address of `owner`'s associated token account for `mint`.
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address(owner, mint)
+}
@@ -0,0 +1,118 @@
+//! End-to-end tests against a running validator with SOAR deployed at [soar::ID], e.g.
+//! `solana-test-validator --bpf-program <SOAR_ID> target/deploy/soar.so`.
+//!
+//! Run with `cargo test -p soar-client -- --ignored`. The RPC endpoint is read from
+//! `SOAR_RPC_URL` and defaults to a local validator.
+
+use soar::{GameAttributes, GameAuthority, RegisterLeaderBoardInput};
+use soar_client::{instructions, pda, SoarClient};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+
+fn client() -> SoarClient {
+    let url = std::env::var("SOAR_RPC_URL").unwrap_or_else(|_| "http://localhost:8899".into());
+    SoarClient::new(RpcClient::new_with_commitment(
+        url,
+        CommitmentConfig::confirmed(),
+    ))
+}
+
+fn funded_keypair(client: &SoarClient) -> Keypair {
+    let keypair = Keypair::new();
+    let signature = client
+        .rpc()
+        .request_airdrop(&keypair.pubkey(), 10 * LAMPORTS_PER_SOL)
+        .unwrap();
+    client
+        .rpc()
+        .poll_for_signature_confirmation(&signature, 1)
+        .unwrap();
+    keypair
+}
+
+#[test]
+#[ignore = "requires a validator with SOAR deployed"]
+fn submitted_score_reaches_top_entries() {
+    let client = client();
+    let authority = funded_keypair(&client);
+    let user = funded_keypair(&client);
+    let game = Keypair::new();
+
+    let meta = GameAttributes::new(
+        "Game".into(),
+        "Description".into(),
+        0,
+        0,
+        Default::default(),
+    );
+    let auth = vec![GameAuthority::new(authority.pubkey(), GameAuthority::ADMIN)];
+    let create_game = instructions::initialize_game(authority.pubkey(), game.pubkey(), meta, auth);
+    client.send(&[create_game], &authority, &[&game]).unwrap();
+
+    let input = RegisterLeaderBoardInput {
+        scores_to_retain: 10,
+        ..Default::default()
+    };
+    let add_leaderboard = client
+        .add_leaderboard_ix(
+            authority.pubkey(),
+            authority.pubkey(),
+            game.pubkey(),
+            input,
+            None,
+        )
+        .unwrap();
+    client.send(&[add_leaderboard], &authority, &[]).unwrap();
+    let (leaderboard, _) = pda::find_leaderboard(&game.pubkey(), 1);
+    assert_eq!(client.game(&game.pubkey()).unwrap().leaderboard_count, 1);
+
+    let initialize_player = instructions::initialize_player(
+        user.pubkey(),
+        user.pubkey(),
+        "player".into(),
+        Default::default(),
+    );
+    let register_player = client
+        .register_player_ix(user.pubkey(), user.pubkey(), leaderboard)
+        .unwrap();
+    client
+        .send(&[initialize_player, register_player], &user, &[])
+        .unwrap();
+
+    let submit_score = client
+        .submit_score_ix(
+            authority.pubkey(),
+            authority.pubkey(),
+            user.pubkey(),
+            leaderboard,
+            42,
+            None,
+            None,
+            &[],
+        )
+        .unwrap();
+    client.send(&[submit_score], &authority, &[]).unwrap();
+
+    let scores = client.player_scores(&user.pubkey(), &leaderboard).unwrap();
+    assert_eq!(scores.scores.len(), 1);
+
+    let top_entries = client.top_entries(&leaderboard).unwrap();
+    let ranked: Vec<_> = top_entries.ranked().collect();
+    assert_eq!(ranked.len(), 1);
+    assert_eq!(ranked[0].player, pda::find_player(&user.pubkey()).0);
+    assert_eq!(ranked[0].entry.score, 42);
+}
+
+#[test]
+#[ignore = "requires a validator with SOAR deployed"]
+fn missing_account_is_reported() {
+    let client = client();
+    let game = Keypair::new().pubkey();
+
+    let error = client.game(&game).unwrap_err();
+    assert!(matches!(error, soar_client::Error::AccountNotFound(key) if key == game));
+}
@@ -0,0 +1,290 @@
+use crate::{check_address, check_program, pda};
+use anchor_lang::prelude::*;
+use soar::cpi::{self, accounts};
+
+/// Accounts for [unlock_player_achievement_cpi].
+#[derive(Clone)]
+pub struct UnlockPlayerAchievement<'info> {
+    pub soar_program: AccountInfo<'info>,
+
+    /// A [Game][soar::Game] authority with the achievement manager role.
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub player_account: AccountInfo<'info>,
+    pub game: AccountInfo<'info>,
+    pub achievement: AccountInfo<'info>,
+
+    /// The [PlayerAchievement][soar::PlayerAchievement] to create, derived from
+    /// `player_account` and `achievement`.
+    pub player_achievement: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Unlock an achievement for a player.
+pub fn unlock_player_achievement_cpi(
+    accounts: UnlockPlayerAchievement,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    check_program(&accounts.soar_program)?;
+    check_address(
+        &accounts.player_achievement,
+        pda::find_player_achievement(accounts.player_account.key, accounts.achievement.key).0,
+        "player_achievement",
+    )?;
+
+    let cpi_accounts = accounts::UnlockPlayerAchievement {
+        authority: accounts.authority,
+        payer: accounts.payer,
+        player_account: accounts.player_account,
+        game: accounts.game,
+        achievement: accounts.achievement,
+        player_achievement: accounts.player_achievement,
+        system_program: accounts.system_program,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(accounts.soar_program, cpi_accounts, signer_seeds);
+    cpi::unlock_player_achievement(cpi_ctx)
+}
+
+/// Accounts for [increment_achievement_progress_cpi].
+#[derive(Clone)]
+pub struct IncrementAchievementProgress<'info> {
+    pub soar_program: AccountInfo<'info>,
+
+    /// A [Game][soar::Game] authority with the achievement manager role.
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub player_account: AccountInfo<'info>,
+    pub game: AccountInfo<'info>,
+    pub achievement: AccountInfo<'info>,
+
+    /// The player's [PlayerAchievement][soar::PlayerAchievement], derived from
+    /// `player_account` and `achievement`. Created if it doesn't exist yet.
+    pub player_achievement: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Add `amount` to a player's progress towards an achievement.
+pub fn increment_achievement_progress_cpi(
+    accounts: IncrementAchievementProgress,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    check_program(&accounts.soar_program)?;
+    check_address(
+        &accounts.player_achievement,
+        pda::find_player_achievement(accounts.player_account.key, accounts.achievement.key).0,
+        "player_achievement",
+    )?;
+
+    let cpi_accounts = accounts::IncrementAchievementProgress {
+        authority: accounts.authority,
+        payer: accounts.payer,
+        player_account: accounts.player_account,
+        game: accounts.game,
+        achievement: accounts.achievement,
+        player_achievement: accounts.player_achievement,
+        system_program: accounts.system_program,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(accounts.soar_program, cpi_accounts, signer_seeds);
+    cpi::increment_achievement_progress(cpi_ctx, amount)
+}
+
+/// Accounts for [claim_ft_reward_cpi].
+#[derive(Clone)]
+pub struct ClaimFtReward<'info> {
+    pub soar_program: AccountInfo<'info>,
+    pub user: AccountInfo<'info>,
+
+    /// A [Game][soar::Game] authority with the reward manager role.
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub game: AccountInfo<'info>,
+    pub achievement: AccountInfo<'info>,
+    pub reward: AccountInfo<'info>,
+
+    /// `user`'s [Player][soar::Player] account.
+    pub player_account: AccountInfo<'info>,
+
+    /// The player's [PlayerAchievement][soar::PlayerAchievement], derived from
+    /// `player_account` and `achievement`.
+    pub player_achievement: AccountInfo<'info>,
+    pub source_token_account: AccountInfo<'info>,
+    pub user_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Transfer an achievement's fungible token reward to `user`.
+pub fn claim_ft_reward_cpi(accounts: ClaimFtReward, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    check_program(&accounts.soar_program)?;
+    check_address(
+        &accounts.player_account,
+        pda::find_player(accounts.user.key).0,
+        "player_account",
+    )?;
+    check_address(
+        &accounts.player_achievement,
+        pda::find_player_achievement(accounts.player_account.key, accounts.achievement.key).0,
+        "player_achievement",
+    )?;
+
+    let cpi_accounts = accounts::ClaimFtReward {
+        user: accounts.user,
+        authority: accounts.authority,
+        payer: accounts.payer,
+        game: accounts.game,
+        achievement: accounts.achievement,
+        reward: accounts.reward,
+        player_account: accounts.player_account,
+        player_achievement: accounts.player_achievement,
+        source_token_account: accounts.source_token_account,
+        user_token_account: accounts.user_token_account,
+        token_program: accounts.token_program,
+        system_program: accounts.system_program,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(accounts.soar_program, cpi_accounts, signer_seeds);
+    cpi::claim_ft_reward(cpi_ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{at, expected, invoked, soar_program, unique};
+
+    #[test]
+    fn unlock_player_achievement_accounts() {
+        let (player_account, achievement) = (unique(), unique());
+        let accounts = UnlockPlayerAchievement {
+            soar_program: soar_program(),
+            authority: unique(),
+            payer: unique(),
+            player_achievement: at(pda::find_player_achievement(
+                player_account.key,
+                achievement.key,
+            )
+            .0),
+            player_account,
+            game: unique(),
+            achievement,
+            system_program: at(System::id()),
+        };
+
+        let ix = invoked(|| unlock_player_achievement_cpi(accounts.clone(), &[]));
+        assert_eq!(
+            ix.unwrap(),
+            expected(
+                soar::accounts::UnlockPlayerAchievement {
+                    authority: *accounts.authority.key,
+                    payer: *accounts.payer.key,
+                    player_account: *accounts.player_account.key,
+                    game: *accounts.game.key,
+                    achievement: *accounts.achievement.key,
+                    player_achievement: *accounts.player_achievement.key,
+                    system_program: *accounts.system_program.key,
+                },
+                soar::instruction::UnlockPlayerAchievement {},
+            )
+        );
+
+        let misderived = UnlockPlayerAchievement {
+            player_achievement: unique(),
+            ..accounts
+        };
+        assert!(invoked(|| unlock_player_achievement_cpi(misderived, &[])).is_err());
+    }
+
+    #[test]
+    fn increment_achievement_progress_accounts() {
+        let (player_account, achievement) = (unique(), unique());
+        let accounts = IncrementAchievementProgress {
+            soar_program: soar_program(),
+            authority: unique(),
+            payer: unique(),
+            player_achievement: at(pda::find_player_achievement(
+                player_account.key,
+                achievement.key,
+            )
+            .0),
+            player_account,
+            game: unique(),
+            achievement,
+            system_program: at(System::id()),
+        };
+
+        let ix = invoked(|| increment_achievement_progress_cpi(accounts.clone(), &[], 3));
+        assert_eq!(
+            ix.unwrap(),
+            expected(
+                soar::accounts::IncrementAchievementProgress {
+                    authority: *accounts.authority.key,
+                    payer: *accounts.payer.key,
+                    player_account: *accounts.player_account.key,
+                    game: *accounts.game.key,
+                    achievement: *accounts.achievement.key,
+                    player_achievement: *accounts.player_achievement.key,
+                    system_program: *accounts.system_program.key,
+                },
+                soar::instruction::IncrementAchievementProgress { amount: 3 },
+            )
+        );
+
+        let misderived = IncrementAchievementProgress {
+            player_achievement: unique(),
+            ..accounts
+        };
+        assert!(invoked(|| increment_achievement_progress_cpi(misderived, &[], 3)).is_err());
+    }
+
+    #[test]
+    fn claim_ft_reward_accounts() {
+        let (user, achievement) = (unique(), unique());
+        let player_account = at(pda::find_player(user.key).0);
+        let accounts = ClaimFtReward {
+            soar_program: soar_program(),
+            user,
+            authority: unique(),
+            payer: unique(),
+            game: unique(),
+            player_achievement: at(pda::find_player_achievement(
+                player_account.key,
+                achievement.key,
+            )
+            .0),
+            achievement,
+            reward: unique(),
+            player_account,
+            source_token_account: unique(),
+            user_token_account: unique(),
+            token_program: unique(),
+            system_program: at(System::id()),
+        };
+
+        let ix = invoked(|| claim_ft_reward_cpi(accounts.clone(), &[]));
+        assert_eq!(
+            ix.unwrap(),
+            expected(
+                soar::accounts::ClaimFtReward {
+                    user: *accounts.user.key,
+                    authority: *accounts.authority.key,
+                    payer: *accounts.payer.key,
+                    game: *accounts.game.key,
+                    achievement: *accounts.achievement.key,
+                    reward: *accounts.reward.key,
+                    player_account: *accounts.player_account.key,
+                    player_achievement: *accounts.player_achievement.key,
+                    source_token_account: *accounts.source_token_account.key,
+                    user_token_account: *accounts.user_token_account.key,
+                    token_program: *accounts.token_program.key,
+                    system_program: *accounts.system_program.key,
+                },
+                soar::instruction::ClaimFtReward {},
+            )
+        );
+
+        let misderived = ClaimFtReward {
+            player_account: unique(),
+            ..accounts
+        };
+        assert!(invoked(|| claim_ft_reward_cpi(misderived, &[])).is_err());
+    }
+}
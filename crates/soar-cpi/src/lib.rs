@@ -1,5 +1,38 @@
-use anchor_lang::declare_id;
-use clockwork_anchor_gen::generate_cpi_interface;
+//! CPI helpers for the SOAR program.
+//!
+//! Each `*_cpi` function takes the accounts of a SOAR instruction and the signer seeds of the
+//! calling program. Before invoking SOAR, it derives the instruction's PDAs and checks the
+//! accounts passed in against them.
 
-generate_cpi_interface!(idl_path = "idl.json");
-declare_id!("SoarNNzwQHMwcfdkdLc6kvbkoMSxcHy89gTHrjhJYkk");
+use anchor_lang::prelude::*;
+
+pub mod pda;
+
+mod achievement;
+mod player;
+mod score;
+#[cfg(test)]
+mod test_utils;
+
+pub use achievement::*;
+pub use player::*;
+pub use score::*;
+pub use soar::{self, ID};
+
+/// Fail with [ErrorCode::ConstraintSeeds] if `account` isn't at the `expected` address.
+fn check_address(account: &AccountInfo, expected: Pubkey, name: &str) -> Result<()> {
+    if *account.key != expected {
+        return Err(error!(ErrorCode::ConstraintSeeds)
+            .with_account_name(name)
+            .with_pubkeys((*account.key, expected)));
+    }
+    Ok(())
+}
+
+/// Fail with [ErrorCode::InvalidProgramId] if `program` isn't the SOAR program.
+fn check_program(program: &AccountInfo) -> Result<()> {
+    if *program.key != ID {
+        return Err(error!(ErrorCode::InvalidProgramId).with_pubkeys((*program.key, ID)));
+    }
+    Ok(())
+}
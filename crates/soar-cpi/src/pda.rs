@@ -0,0 +1,125 @@
+//! Address derivation for every SOAR program-derived account.
+
+use anchor_lang::prelude::Pubkey;
+
+/// Address of a [Game][soar::Game]'s leaderboard with the given `id`.
+pub fn find_leaderboard(game: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[soar::LEADER, game.as_ref(), &id.to_le_bytes()], &soar::ID)
+}
+
+/// Address of a leaderboard's [LeaderTopEntriesV2][soar::LeaderTopEntriesV2].
+pub fn find_top_entries(leaderboard: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[soar::LEADER_TOP_ENTRIES, leaderboard.as_ref()], &soar::ID)
+}
+
+/// Address of a [Game][soar::Game]'s achievement with the given `id`.
+pub fn find_achievement(game: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[soar::ACHIEVEMENT, game.as_ref(), &id.to_le_bytes()],
+        &soar::ID,
+    )
+}
+
+/// Address of the [Player][soar::Player] account owned by `user`.
+pub fn find_player(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[soar::PLAYER, user.as_ref()], &soar::ID)
+}
+
+/// Address of a player's [PlayerScoresList][soar::PlayerScoresList] for a leaderboard.
+pub fn find_player_scores(player_account: &Pubkey, leaderboard: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            soar::PLAYER_SCORES,
+            player_account.as_ref(),
+            leaderboard.as_ref(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Address of a player's [PlayerAchievement][soar::PlayerAchievement] for an achievement.
+pub fn find_player_achievement(player_account: &Pubkey, achievement: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            soar::PLAYER_ACHIEVEMENT,
+            player_account.as_ref(),
+            achievement.as_ref(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Address of the [NftClaim][soar::NftClaim] recording that `mint` was claimed from `reward`.
+pub fn find_nft_claim(reward: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[soar::NFT_CLAIM, reward.as_ref(), mint.as_ref()],
+        &soar::ID,
+    )
+}
+
+/// Address of the [SeasonArchive][soar::SeasonArchive] of a leaderboard's closed `season`.
+pub fn find_season_archive(leaderboard: &Pubkey, season: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            soar::SEASON_ARCHIVE,
+            leaderboard.as_ref(),
+            &season.to_le_bytes(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Address of the [RankReward][soar::RankReward] for a leaderboard's `season`.
+pub fn find_rank_reward(leaderboard: &Pubkey, season: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            soar::RANK_REWARD,
+            leaderboard.as_ref(),
+            &season.to_le_bytes(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Address of the [RankRewardClaim][soar::RankRewardClaim] for a `rank` of a rank reward.
+pub fn find_rank_reward_claim(rank_reward: &Pubkey, rank: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            soar::RANK_REWARD_CLAIM,
+            rank_reward.as_ref(),
+            &rank.to_le_bytes(),
+        ],
+        &soar::ID,
+    )
+}
+
+/// Address of a [Game][soar::Game]'s [GameProposal][soar::GameProposal] with the given `id`.
+pub fn find_proposal(game: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[soar::PROPOSAL, game.as_ref(), &id.to_le_bytes()],
+        &soar::ID,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_little_endian_seeds() {
+        let game = Pubkey::new_unique();
+        let (leaderboard, _) = find_leaderboard(&game, 1);
+        let expected = Pubkey::create_program_address(
+            &[
+                b"leaderboard",
+                game.as_ref(),
+                &[1, 0, 0, 0, 0, 0, 0, 0],
+                &[find_leaderboard(&game, 1).1],
+            ],
+            &soar::ID,
+        )
+        .unwrap();
+        assert_eq!(leaderboard, expected);
+        assert_ne!(leaderboard, find_leaderboard(&game, 2).0);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{check_address, check_program, pda};
+use anchor_lang::prelude::*;
+use soar::cpi::{self, accounts};
+
+/// Accounts for [initialize_player_cpi].
+#[derive(Clone)]
+pub struct InitializePlayer<'info> {
+    pub soar_program: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub user: AccountInfo<'info>,
+
+    /// The [Player][soar::Player] account to create, derived from `user`.
+    pub player_account: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Create a [Player][soar::Player] account for `user`.
+pub fn initialize_player_cpi(
+    accounts: InitializePlayer,
+    signer_seeds: &[&[&[u8]]],
+    username: String,
+    nft_meta: Pubkey,
+) -> Result<()> {
+    check_program(&accounts.soar_program)?;
+    check_address(
+        &accounts.player_account,
+        pda::find_player(accounts.user.key).0,
+        "player_account",
+    )?;
+
+    let cpi_accounts = accounts::InitializePlayer {
+        payer: accounts.payer,
+        user: accounts.user,
+        player_account: accounts.player_account,
+        system_program: accounts.system_program,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(accounts.soar_program, cpi_accounts, signer_seeds);
+    cpi::initialize_player(cpi_ctx, username, nft_meta)
+}
+
+/// Accounts for [register_player_cpi].
+#[derive(Clone)]
+pub struct RegisterPlayer<'info> {
+    pub soar_program: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub user: AccountInfo<'info>,
+
+    /// `user`'s [Player][soar::Player] account.
+    pub player_account: AccountInfo<'info>,
+    pub game: AccountInfo<'info>,
+    pub leaderboard: AccountInfo<'info>,
+
+    /// The [PlayerScoresList][soar::PlayerScoresList] to create, derived from
+    /// `player_account` and `leaderboard`.
+    pub player_scores: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Register `user`'s player to a leaderboard.
+pub fn register_player_cpi(accounts: RegisterPlayer, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    check_program(&accounts.soar_program)?;
+    check_address(
+        &accounts.player_account,
+        pda::find_player(accounts.user.key).0,
+        "player_account",
+    )?;
+    check_address(
+        &accounts.player_scores,
+        pda::find_player_scores(accounts.player_account.key, accounts.leaderboard.key).0,
+        "player_scores",
+    )?;
+
+    let cpi_accounts = accounts::RegisterPlayer {
+        payer: accounts.payer,
+        user: accounts.user,
+        player_account: accounts.player_account,
+        game: accounts.game,
+        leaderboard: accounts.leaderboard,
+        new_list: accounts.player_scores,
+        system_program: accounts.system_program,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(accounts.soar_program, cpi_accounts, signer_seeds);
+    cpi::register_player(cpi_ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{at, expected, invoked, soar_program, unique};
+
+    #[test]
+    fn initialize_player_accounts() {
+        let user = unique();
+        let accounts = InitializePlayer {
+            soar_program: soar_program(),
+            payer: unique(),
+            player_account: at(pda::find_player(user.key).0),
+            user,
+            system_program: at(System::id()),
+        };
+        let nft_meta = Pubkey::new_unique();
+
+        let ix = invoked(|| initialize_player_cpi(accounts.clone(), &[], "a".into(), nft_meta));
+        assert_eq!(
+            ix.unwrap(),
+            expected(
+                soar::accounts::InitializePlayer {
+                    payer: *accounts.payer.key,
+                    user: *accounts.user.key,
+                    player_account: *accounts.player_account.key,
+                    system_program: *accounts.system_program.key,
+                },
+                soar::instruction::InitializePlayer {
+                    username: "a".into(),
+                    nft_meta,
+                },
+            )
+        );
+
+        let misderived = InitializePlayer {
+            player_account: unique(),
+            ..accounts
+        };
+        assert!(invoked(|| initialize_player_cpi(misderived, &[], "a".into(), nft_meta)).is_err());
+    }
+
+    #[test]
+    fn register_player_accounts() {
+        let (user, leaderboard) = (unique(), unique());
+        let player_account = at(pda::find_player(user.key).0);
+        let accounts = RegisterPlayer {
+            soar_program: soar_program(),
+            payer: unique(),
+            player_scores: at(pda::find_player_scores(player_account.key, leaderboard.key).0),
+            user,
+            player_account,
+            game: unique(),
+            leaderboard,
+            system_program: at(System::id()),
+        };
+
+        let ix = invoked(|| register_player_cpi(accounts.clone(), &[]));
+        assert_eq!(
+            ix.unwrap(),
+            expected(
+                soar::accounts::RegisterPlayer {
+                    payer: *accounts.payer.key,
+                    user: *accounts.user.key,
+                    player_account: *accounts.player_account.key,
+                    game: *accounts.game.key,
+                    leaderboard: *accounts.leaderboard.key,
+                    new_list: *accounts.player_scores.key,
+                    system_program: *accounts.system_program.key,
+                },
+                soar::instruction::RegisterPlayer {},
+            )
+        );
+
+        let misderived = RegisterPlayer {
+            player_scores: unique(),
+            ..accounts
+        };
+        assert!(invoked(|| register_player_cpi(misderived, &[])).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{check_address, check_program, pda};
+use anchor_lang::prelude::*;
+use soar::{
+    cpi::{self, accounts},
+    LeaderBoard, SoarError,
+};
+
+/// Accounts for [submit_score_cpi].
+#[derive(Clone)]
+pub struct SubmitScore<'info> {
+    pub soar_program: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+
+    /// A [Game][soar::Game] authority with the score submitter role.
+    pub authority: AccountInfo<'info>,
+    pub player_account: AccountInfo<'info>,
+    pub game: AccountInfo<'info>,
+    pub leaderboard: AccountInfo<'info>,
+
+    /// The player's [PlayerScoresList][soar::PlayerScoresList], derived from `player_account`
+    /// and `leaderboard`.
+    pub player_scores: AccountInfo<'info>,
+
+    /// The leaderboard's [LeaderTopEntriesV2][soar::LeaderTopEntriesV2], derived from
+    /// `leaderboard`. Required if the leaderboard keeps top entries.
+    pub top_entries: Option<AccountInfo<'info>>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Submit `score` for a player.
+pub fn submit_score_cpi(
+    accounts: SubmitScore,
+    signer_seeds: &[&[&[u8]]],
+    score: u64,
+) -> Result<()> {
+    submit_detailed_score_cpi(accounts, signer_seeds, score, None, None)
+}
+
+/// Submit `score` for a player along with its `secondary_score` and `context`.
+pub fn submit_detailed_score_cpi(
+    accounts: SubmitScore,
+    signer_seeds: &[&[&[u8]]],
+    score: u64,
+    secondary_score: Option<u64>,
+    context: Option<[u8; 32]>,
+) -> Result<()> {
+    check_program(&accounts.soar_program)?;
+    check_address(
+        &accounts.player_scores,
+        pda::find_player_scores(accounts.player_account.key, accounts.leaderboard.key).0,
+        "player_scores",
+    )?;
+    match &accounts.top_entries {
+        Some(top_entries) => check_address(
+            top_entries,
+            pda::find_top_entries(accounts.leaderboard.key).0,
+            "top_entries",
+        )?,
+        None => {
+            let data = accounts.leaderboard.try_borrow_data()?;
+            let leaderboard = LeaderBoard::try_deserialize(&mut &data[..])?;
+            require!(
+                leaderboard.top_entries.is_none(),
+                SoarError::MissingExpectedAccount
+            );
+        }
+    }
+
+    let cpi_accounts = accounts::SubmitScore {
+        payer: accounts.payer,
+        authority: accounts.authority,
+        player_account: accounts.player_account,
+        game: accounts.game,
+        leaderboard: accounts.leaderboard,
+        player_scores: accounts.player_scores,
+        top_entries: accounts.top_entries,
+        system_program: accounts.system_program,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(accounts.soar_program, cpi_accounts, signer_seeds);
+    cpi::submit_score(cpi_ctx, score, secondary_score, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{account, at, expected, invoked, soar_program, unique};
+    use anchor_lang::solana_program::instruction::Instruction;
+
+    /// Accounts for a leaderboard that keeps top entries if `ranked`, passing them if
+    /// `with_top_entries`.
+    fn submit_score_accounts(ranked: bool, with_top_entries: bool) -> SubmitScore<'static> {
+        let leaderboard = Pubkey::new_unique();
+        let top_entries = pda::find_top_entries(&leaderboard).0;
+        let mut data = Vec::new();
+        LeaderBoard {
+            top_entries: ranked.then_some(top_entries),
+            ..Default::default()
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+
+        let player_account = unique();
+        SubmitScore {
+            soar_program: soar_program(),
+            payer: unique(),
+            authority: unique(),
+            player_scores: at(pda::find_player_scores(player_account.key, &leaderboard).0),
+            player_account,
+            game: unique(),
+            leaderboard: account(leaderboard, soar::ID, data),
+            top_entries: with_top_entries.then(|| at(top_entries)),
+            system_program: at(System::id()),
+        }
+    }
+
+    fn expected_submit_score(
+        accounts: &SubmitScore,
+        score: u64,
+        secondary_score: Option<u64>,
+        context: Option<[u8; 32]>,
+    ) -> Instruction {
+        expected(
+            soar::accounts::SubmitScore {
+                payer: *accounts.payer.key,
+                authority: *accounts.authority.key,
+                player_account: *accounts.player_account.key,
+                game: *accounts.game.key,
+                leaderboard: *accounts.leaderboard.key,
+                player_scores: *accounts.player_scores.key,
+                top_entries: accounts.top_entries.as_ref().map(|t| *t.key),
+                system_program: *accounts.system_program.key,
+            },
+            soar::instruction::SubmitScore {
+                score,
+                secondary_score,
+                context,
+            },
+        )
+    }
+
+    #[test]
+    fn submit_score_passes_top_entries() {
+        let accounts = submit_score_accounts(true, true);
+
+        let ix = invoked(|| submit_score_cpi(accounts.clone(), &[], 7)).unwrap();
+        assert_eq!(ix, expected_submit_score(&accounts, 7, None, None));
+
+        let context = Some([1; 32]);
+        let ix = invoked(|| submit_detailed_score_cpi(accounts.clone(), &[], 7, Some(2), context))
+            .unwrap();
+        assert_eq!(ix, expected_submit_score(&accounts, 7, Some(2), context));
+    }
+
+    #[test]
+    fn submit_score_without_top_entries() {
+        let accounts = submit_score_accounts(false, false);
+
+        let ix = invoked(|| submit_score_cpi(accounts.clone(), &[], 7)).unwrap();
+        assert_eq!(ix, expected_submit_score(&accounts, 7, None, None));
+    }
+
+    #[test]
+    fn missing_top_entries_are_rejected() {
+        let accounts = submit_score_accounts(true, false);
+
+        let err = invoked(|| submit_score_cpi(accounts, &[], 7)).unwrap_err();
+        assert_eq!(err, SoarError::MissingExpectedAccount.into());
+    }
+
+    #[test]
+    fn misderived_accounts_are_rejected() {
+        let accounts = submit_score_accounts(true, true);
+        let wrong_scores = SubmitScore {
+            player_scores: unique(),
+            ..accounts.clone()
+        };
+        assert!(invoked(|| submit_score_cpi(wrong_scores, &[], 7)).is_err());
+
+        let wrong_top_entries = SubmitScore {
+            top_entries: Some(unique()),
+            ..accounts.clone()
+        };
+        assert!(invoked(|| submit_score_cpi(wrong_top_entries, &[], 7)).is_err());
+
+        let wrong_program = SubmitScore {
+            soar_program: unique(),
+            ..accounts
+        };
+        assert!(invoked(|| submit_score_cpi(wrong_program, &[], 7)).is_err());
+    }
+}
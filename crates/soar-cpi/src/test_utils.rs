@@ -0,0 +1,82 @@
+//! Helpers for checking the instructions the CPI wrappers invoke without a runtime.
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        entrypoint::ProgramResult,
+        instruction::Instruction,
+        program_stubs::{self, SyscallStubs},
+    },
+    InstructionData,
+};
+use std::{cell::RefCell, sync::Once};
+
+thread_local! {
+    static INVOKED: RefCell<Option<Instruction>> = const { RefCell::new(None) };
+}
+
+/// Records invoked instructions for the current thread instead of executing them.
+struct RecordInvokes;
+
+impl SyscallStubs for RecordInvokes {
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        _account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        INVOKED.with(|invoked| *invoked.borrow_mut() = Some(instruction.clone()));
+        Ok(())
+    }
+}
+
+/// Run `cpi`, returning the instruction it invoked.
+pub fn invoked(cpi: impl FnOnce() -> Result<()>) -> Result<Instruction> {
+    static STUBS: Once = Once::new();
+    STUBS.call_once(|| {
+        program_stubs::set_syscall_stubs(Box::new(RecordInvokes));
+    });
+
+    cpi()?;
+    Ok(INVOKED
+        .with(|invoked| invoked.borrow_mut().take())
+        .expect("no instruction was invoked"))
+}
+
+/// A writable account at `key` owned by `owner`, leaked so it lives for the whole test.
+pub fn account(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+    AccountInfo::new(
+        Box::leak(Box::new(key)),
+        false,
+        true,
+        Box::leak(Box::new(0)),
+        Box::leak(data.into_boxed_slice()),
+        Box::leak(Box::new(owner)),
+        false,
+        0,
+    )
+}
+
+/// An empty account at a new unique address.
+pub fn unique() -> AccountInfo<'static> {
+    account(Pubkey::new_unique(), Pubkey::default(), Vec::new())
+}
+
+/// An empty account at `key`.
+pub fn at(key: Pubkey) -> AccountInfo<'static> {
+    account(key, Pubkey::default(), Vec::new())
+}
+
+/// The SOAR program account.
+pub fn soar_program() -> AccountInfo<'static> {
+    at(soar::ID)
+}
+
+/// The instruction SOAR expects for `accounts` and `data`.
+pub fn expected(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: soar::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
@@ -0,0 +1,137 @@
+//! CSV and JSON output of rankings.
+
+use crate::{standings::Standing, Result};
+use anchor_lang::prelude::Pubkey;
+use serde::Serialize;
+use soar::{LeaderBoard, Player};
+use std::{collections::HashMap, fmt::Write as _, io::Write};
+
+/// A leaderboard's ranking, in the shape it's exported.
+#[derive(Serialize)]
+pub struct LeaderBoardExport {
+    pub leaderboard: String,
+    pub game: String,
+    pub description: String,
+    pub season: u64,
+    pub decimals: u8,
+    pub scoring_mode: String,
+    pub entries: Vec<Row>,
+}
+
+/// A ranked entry with its player's details.
+#[derive(Serialize)]
+pub struct Row {
+    pub rank: u32,
+    pub player_account: String,
+
+    /// The player's wallet and username, if their [Player] account was in the snapshot.
+    pub user: Option<String>,
+    pub username: Option<String>,
+
+    /// The ranked value, interpreted according to the leaderboard's score type.
+    pub score: i128,
+    pub secondary_score: Option<u64>,
+    pub timestamp: i64,
+
+    /// Hex-encoded entry context.
+    pub context: String,
+}
+
+impl LeaderBoardExport {
+    pub fn new(
+        address: &Pubkey,
+        leaderboard: &LeaderBoard,
+        standings: &[Standing],
+        players: &HashMap<Pubkey, Player>,
+    ) -> Self {
+        let entries = standings
+            .iter()
+            .map(|standing| {
+                let player = players.get(&standing.player_account);
+                Row {
+                    rank: standing.rank,
+                    player_account: standing.player_account.to_string(),
+                    user: player.map(|player| player.user.to_string()),
+                    username: player.map(|player| player.username.clone()),
+                    score: leaderboard.score_type.value(standing.entry.score),
+                    secondary_score: standing.entry.secondary_score(),
+                    timestamp: standing.entry.timestamp,
+                    context: hex(&standing.entry.context),
+                }
+            })
+            .collect();
+
+        LeaderBoardExport {
+            leaderboard: address.to_string(),
+            game: leaderboard.game.to_string(),
+            description: leaderboard.description.clone(),
+            season: leaderboard.season,
+            decimals: leaderboard.decimals,
+            scoring_mode: format!("{:?}", leaderboard.scoring_mode),
+            entries,
+        }
+    }
+}
+
+/// Write `exports` as a JSON array.
+pub fn write_json(writer: impl Write, exports: &[LeaderBoardExport]) -> Result<()> {
+    serde_json::to_writer_pretty(writer, exports)?;
+    Ok(())
+}
+
+/// Write `exports` as CSV, one row per ranked entry.
+pub fn write_csv(mut writer: impl Write, exports: &[LeaderBoardExport]) -> Result<()> {
+    writeln!(
+        writer,
+        "leaderboard,season,rank,player_account,user,username,score,secondary_score,timestamp,context"
+    )?;
+    for export in exports {
+        for row in &export.entries {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{},{},{}",
+                export.leaderboard,
+                export.season,
+                row.rank,
+                row.player_account,
+                row.user.as_deref().unwrap_or_default(),
+                csv_field(row.username.as_deref().unwrap_or_default()),
+                row.score,
+                row.secondary_score
+                    .map(|score| score.to_string())
+                    .unwrap_or_default(),
+                row.timestamp,
+                row.context,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Quote `value` if it contains characters that would break a CSV row.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usernames_are_quoted_when_needed() {
+        assert_eq!(csv_field("alice"), "alice");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
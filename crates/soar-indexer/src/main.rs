@@ -0,0 +1,193 @@
+//! Rebuilds complete SOAR leaderboard rankings from account snapshots and exports them.
+//!
+//! A leaderboard's top entries only hold its best `N` players. This ranks every player
+//! registered to it from their [PlayerScoresList][soar::PlayerScoresList]s, using the same
+//! ordering rules as score submission.
+//!
+//! ```sh
+//! # Save every SOAR account for later runs.
+//! soar-indexer snapshot --url http://localhost:8899 --out accounts.json
+//!
+//! # Export the ranking of one leaderboard around a player.
+//! soar-indexer export --snapshot accounts.json --leaderboard <ADDRESS> \
+//!     --around <PLAYER> --radius 10 --format csv
+//! ```
+
+mod export;
+mod snapshot;
+mod standings;
+
+use anchor_lang::prelude::Pubkey;
+use clap::{Arg, ArgGroup, ArgMatches, Command};
+use export::LeaderBoardExport;
+use snapshot::Snapshot;
+use solana_client::rpc_client::RpcClient;
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    str::FromStr,
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const DEFAULT_URL: &str = "http://localhost:8899";
+
+fn main() {
+    let matches = cli().get_matches();
+    let result = match matches.subcommand() {
+        Some(("snapshot", args)) => run_snapshot(args),
+        Some(("export", args)) => run_export(args),
+        _ => unreachable!("a subcommand is required"),
+    };
+    if let Err(error) = result {
+        eprintln!("error: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn cli() -> Command<'static> {
+    let url = Arg::new("url")
+        .long("url")
+        .takes_value(true)
+        .help("RPC endpoint to fetch SOAR accounts from");
+    let out = Arg::new("out")
+        .long("out")
+        .takes_value(true)
+        .help("File to write to instead of stdout");
+
+    Command::new("soar-indexer")
+        .about("Rebuilds and exports complete SOAR leaderboard rankings")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("snapshot")
+                .about("Save every SOAR account to a JSON dump")
+                .arg(url.clone().default_value(DEFAULT_URL))
+                .arg(out.clone()),
+        )
+        .subcommand(
+            Command::new("export")
+                .about("Export leaderboard rankings as CSV or JSON")
+                .arg(url)
+                .arg(
+                    Arg::new("snapshot")
+                        .long("snapshot")
+                        .takes_value(true)
+                        .help("JSON dump of SOAR accounts to read instead of an RPC endpoint"),
+                )
+                .group(ArgGroup::new("source").args(&["url", "snapshot"]))
+                .arg(
+                    Arg::new("leaderboard")
+                        .long("leaderboard")
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .help("Leaderboard to export. Defaults to every leaderboard"),
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(["csv", "json"])
+                        .default_value("csv"),
+                )
+                .arg(
+                    Arg::new("around")
+                        .long("around")
+                        .takes_value(true)
+                        .help("Only export entries around this player or player account"),
+                )
+                .arg(
+                    Arg::new("radius")
+                        .long("radius")
+                        .takes_value(true)
+                        .requires("around")
+                        .default_value("5")
+                        .help("Number of entries above and below the player to export"),
+                )
+                .arg(
+                    Arg::new("all-seasons")
+                        .long("all-seasons")
+                        .help("Rank scores from every season instead of the current one"),
+                )
+                .arg(out),
+        )
+}
+
+fn run_snapshot(args: &ArgMatches) -> Result<()> {
+    let rpc = RpcClient::new(args.value_of("url").unwrap().to_owned());
+    let accounts = snapshot::fetch(&rpc)?;
+    snapshot::write_dump(output(args)?, &accounts)?;
+    eprintln!("Saved {} accounts.", accounts.len());
+    Ok(())
+}
+
+fn run_export(args: &ArgMatches) -> Result<()> {
+    let accounts = match args.value_of("snapshot") {
+        Some(path) => snapshot::read_dump(BufReader::new(File::open(path)?))?,
+        None => {
+            let url = args.value_of("url").unwrap_or(DEFAULT_URL);
+            snapshot::fetch(&RpcClient::new(url.to_owned()))?
+        }
+    };
+    let snapshot = Snapshot::decode(&accounts)?;
+
+    let leaderboards = match args.values_of("leaderboard") {
+        Some(values) => values
+            .map(Pubkey::from_str)
+            .collect::<std::result::Result<_, _>>()?,
+        None => snapshot.leaderboards.keys().copied().collect::<Vec<_>>(),
+    };
+    let around = match args.value_of("around") {
+        Some(key) => {
+            let key = Pubkey::from_str(key)?;
+            // Accept either a player account or the wallet that owns it.
+            if snapshot.players.contains_key(&key) {
+                Some(key)
+            } else {
+                Some(soar_client::pda::find_player(&key).0)
+            }
+        }
+        None => None,
+    };
+    let radius: usize = args.value_of_t("radius")?;
+
+    let mut exports = Vec::with_capacity(leaderboards.len());
+    for address in &leaderboards {
+        let leaderboard = snapshot
+            .leaderboards
+            .get(address)
+            .ok_or_else(|| format!("leaderboard {address} isn't in the snapshot"))?;
+        let since = (!args.is_present("all-seasons")).then_some(leaderboard.season_start);
+
+        let standings = standings::rank(
+            leaderboard,
+            snapshot.is_ascending(leaderboard),
+            snapshot.scores_for(address),
+            since,
+        )?;
+        let standings = match &around {
+            Some(player_account) => standings::around(&standings, player_account, radius),
+            None => &standings,
+        };
+        exports.push(LeaderBoardExport::new(
+            address,
+            leaderboard,
+            standings,
+            &snapshot.players,
+        ));
+    }
+
+    let mut writer = output(args)?;
+    match args.value_of("format") {
+        Some("json") => export::write_json(&mut writer, &exports)?,
+        _ => export::write_csv(&mut writer, &exports)?,
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn output(args: &ArgMatches) -> Result<Box<dyn Write>> {
+    Ok(match args.value_of("out") {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    })
+}
@@ -0,0 +1,236 @@
+//! Loading SOAR accounts from a validator or a JSON dump.
+
+use crate::Result;
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use soar::{LeaderBoard, LeaderTopEntriesV2, LegacyPlayerScoresList, Player, PlayerScoresList};
+use soar_client::{decode, TopEntries};
+use solana_client::rpc_client::RpcClient;
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::{Read, Write},
+    str::FromStr,
+};
+
+/// The SOAR accounts needed to rank players, keyed by address.
+#[derive(Default)]
+pub struct Snapshot {
+    pub leaderboards: BTreeMap<Pubkey, LeaderBoard>,
+    pub top_entries: HashMap<Pubkey, TopEntries>,
+    pub players: HashMap<Pubkey, Player>,
+    pub player_scores: BTreeMap<Pubkey, PlayerScoresList>,
+}
+
+/// An account as returned by `getProgramAccounts` with base64 encoding.
+#[derive(Serialize, Deserialize)]
+struct KeyedAccount {
+    pubkey: String,
+    account: AccountData,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AccountData {
+    /// The encoded data and its encoding.
+    data: (String, String),
+}
+
+/// A JSON-RPC response wrapping a list of accounts.
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: Vec<KeyedAccount>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Dump {
+    Accounts(Vec<KeyedAccount>),
+    Response(RpcResponse),
+}
+
+/// Fetch the raw data of every account owned by the SOAR program.
+pub fn fetch(rpc: &RpcClient) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+    Ok(rpc
+        .get_program_accounts(&soar::ID)?
+        .into_iter()
+        .map(|(pubkey, account)| (pubkey, account.data))
+        .collect())
+}
+
+/// Read raw accounts from a JSON array of `getProgramAccounts` results, or a full JSON-RPC
+/// response holding one. Data must be base64-encoded.
+pub fn read_dump(reader: impl Read) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+    let accounts = match serde_json::from_reader(reader)? {
+        Dump::Accounts(accounts) => accounts,
+        Dump::Response(response) => response.result,
+    };
+
+    accounts
+        .into_iter()
+        .map(|keyed| {
+            let (data, encoding) = keyed.account.data;
+            if encoding != "base64" {
+                return Err(format!("{}: unsupported encoding {encoding}", keyed.pubkey).into());
+            }
+            Ok((Pubkey::from_str(&keyed.pubkey)?, STANDARD.decode(data)?))
+        })
+        .collect()
+}
+
+/// Write raw accounts in the format read by [read_dump].
+pub fn write_dump(writer: impl Write, accounts: &[(Pubkey, Vec<u8>)]) -> Result<()> {
+    let accounts: Vec<_> = accounts
+        .iter()
+        .map(|(pubkey, data)| KeyedAccount {
+            pubkey: pubkey.to_string(),
+            account: AccountData {
+                data: (STANDARD.encode(data), "base64".to_owned()),
+            },
+        })
+        .collect();
+    serde_json::to_writer(writer, &accounts)?;
+    Ok(())
+}
+
+impl Snapshot {
+    /// Decode the accounts this indexer uses, skipping every other kind.
+    pub fn decode(accounts: &[(Pubkey, Vec<u8>)]) -> Result<Self> {
+        let mut snapshot = Snapshot::default();
+        let mut legacy_lists = Vec::new();
+        for (pubkey, data) in accounts {
+            let Some(discriminator) = data.get(..8) else {
+                continue;
+            };
+            if discriminator == LeaderBoard::DISCRIMINATOR {
+                snapshot
+                    .leaderboards
+                    .insert(*pubkey, decode::account(data)?);
+            } else if discriminator == LeaderTopEntriesV2::DISCRIMINATOR {
+                snapshot
+                    .top_entries
+                    .insert(*pubkey, TopEntries::decode(data)?);
+            } else if discriminator == Player::DISCRIMINATOR {
+                snapshot.players.insert(*pubkey, decode::account(data)?);
+            } else if discriminator == PlayerScoresList::DISCRIMINATOR {
+                match LegacyPlayerScoresList::try_from_data(data) {
+                    Ok(list) => legacy_lists.push((*pubkey, list)),
+                    Err(_) => {
+                        snapshot
+                            .player_scores
+                            .insert(*pubkey, decode::account(data)?);
+                    }
+                }
+            }
+        }
+
+        // Convert unmigrated lists as `migrate_player_scores` would, once their leaderboard
+        // is known.
+        for (pubkey, list) in legacy_lists {
+            let Some(leaderboard) = snapshot.leaderboards.get(&list.leaderboard) else {
+                continue;
+            };
+            let list = PlayerScoresList::from_legacy(
+                list,
+                leaderboard.season,
+                &leaderboard.retention,
+                leaderboard.score_type,
+                snapshot.is_ascending(leaderboard),
+            );
+            snapshot.player_scores.insert(pubkey, list);
+        }
+        Ok(snapshot)
+    }
+
+    /// The score lists of every player registered to `leaderboard`.
+    pub fn scores_for<'a>(
+        &'a self,
+        leaderboard: &'a Pubkey,
+    ) -> impl Iterator<Item = &'a PlayerScoresList> {
+        self.player_scores
+            .values()
+            .filter(move |list| list.leaderboard == *leaderboard)
+    }
+
+    /// Whether `leaderboard`'s top entries are in ascending order, defaulting to descending
+    /// like score submission does when it has none.
+    pub fn is_ascending(&self, leaderboard: &LeaderBoard) -> bool {
+        leaderboard
+            .top_entries
+            .and_then(|address| self.top_entries.get(&address))
+            .is_some_and(|top_entries| top_entries.header.is_ascending())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::AccountSerialize;
+    use soar::ScoreEntry;
+
+    fn serialize(account: &impl AccountSerialize) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn dumps_round_trip_into_a_snapshot() {
+        let leaderboard = Pubkey::new_unique();
+        let player_scores = Pubkey::new_unique();
+        let list = PlayerScoresList {
+            leaderboard,
+            scores: vec![ScoreEntry::new(7, 1)],
+            ..PlayerScoresList::default()
+        };
+        let accounts = vec![
+            (leaderboard, serialize(&LeaderBoard::default())),
+            (player_scores, serialize(&list)),
+            (Pubkey::new_unique(), vec![1, 2, 3]),
+        ];
+
+        let mut dump = Vec::new();
+        write_dump(&mut dump, &accounts).unwrap();
+        let read = read_dump(&dump[..]).unwrap();
+        assert_eq!(read, accounts);
+
+        let snapshot = Snapshot::decode(&read).unwrap();
+        assert!(snapshot.leaderboards.contains_key(&leaderboard));
+        let lists: Vec<_> = snapshot.scores_for(&leaderboard).collect();
+        assert_eq!(lists.len(), 1);
+        assert_eq!(lists[0].scores[0].score, 7);
+    }
+
+    #[test]
+    fn legacy_score_lists_are_converted() {
+        let leaderboard = Pubkey::new_unique();
+        let mut data = PlayerScoresList::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(leaderboard.as_ref());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&9u64.to_le_bytes());
+        data.extend_from_slice(&3i64.to_le_bytes());
+
+        let accounts = vec![
+            (leaderboard, serialize(&LeaderBoard::default())),
+            (Pubkey::new_unique(), data),
+        ];
+        let snapshot = Snapshot::decode(&accounts).unwrap();
+        let lists: Vec<_> = snapshot.scores_for(&leaderboard).collect();
+        assert_eq!(lists.len(), 1);
+        assert_eq!(lists[0].scores[0].score, 9);
+        assert_eq!(lists[0].submission_count, 1);
+    }
+
+    #[test]
+    fn rpc_responses_are_accepted() {
+        let pubkey = Pubkey::new_unique();
+        let response = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"result":[{{"pubkey":"{pubkey}","account":{{"data":["AQI=","base64"],"lamports":1}}}}]}}"#
+        );
+        assert_eq!(
+            read_dump(response.as_bytes()).unwrap(),
+            [(pubkey, vec![1, 2])]
+        );
+    }
+}
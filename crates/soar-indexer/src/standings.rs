@@ -0,0 +1,372 @@
+//! Complete leaderboard rankings rebuilt from every player's [PlayerScoresList].
+//!
+//! Each player's entries are ranked the way `submit_score` would have placed them in top
+//! entries with unlimited capacity.
+
+use crate::Result;
+use anchor_lang::prelude::Pubkey;
+use soar::{LeaderBoard, LeaderBoardScore, PlayerScoresList, Ranking, ScoreEntry, ScoringMode};
+
+/// A player's entry at a position of a leaderboard's ranking.
+#[derive(Debug, Clone, Copy)]
+pub struct Standing {
+    /// 1-based position, as reported in `ScoreSubmitted` events.
+    pub rank: u32,
+    pub player_account: Pubkey,
+
+    /// The entry ranked, holding the player's ranked value rather than a submitted score
+    /// for [ScoringMode::Cumulative] and [ScoringMode::Count].
+    pub entry: ScoreEntry,
+}
+
+/// Rank every player of `leaderboard` by the entries in `lists` submitted at or after `since`.
+///
+/// `is_ascending` is the arrangement order of the leaderboard's top entries. Fails if a
+/// player's ranked value under [ScoringMode::Cumulative] or [ScoringMode::Count] depends
+/// on scores the leaderboard's retention policy dropped.
+pub fn rank<'a>(
+    leaderboard: &LeaderBoard,
+    is_ascending: bool,
+    lists: impl IntoIterator<Item = &'a PlayerScoresList>,
+    since: Option<i64>,
+) -> Result<Vec<Standing>> {
+    let mut scores = Vec::new();
+    for list in lists {
+        scores.extend(
+            ranked_entries(leaderboard, is_ascending, list, since)?
+                .into_iter()
+                .map(|entry| LeaderBoardScore::new(list.player_account, entry)),
+        );
+    }
+
+    Ranking::new(
+        is_ascending,
+        leaderboard.tie_break,
+        leaderboard.score_type,
+        &mut scores,
+    )
+    .sort();
+
+    Ok(scores
+        .into_iter()
+        .enumerate()
+        .map(|(index, score)| Standing {
+            rank: index as u32 + 1,
+            player_account: score.player,
+            entry: score.entry,
+        })
+        .collect())
+}
+
+/// The standings within `radius` positions of `player_account`'s highest-ranked entry.
+pub fn around<'a>(
+    standings: &'a [Standing],
+    player_account: &Pubkey,
+    radius: usize,
+) -> &'a [Standing] {
+    match standings
+        .iter()
+        .position(|standing| standing.player_account == *player_account)
+    {
+        Some(index) => {
+            let start = index.saturating_sub(radius);
+            let end = standings.len().min(index + radius + 1);
+            &standings[start..end]
+        }
+        None => &[],
+    }
+}
+
+/// The entries a player holds in the leaderboard's top entries under its scoring mode.
+///
+/// Only the scores kept under the leaderboard's retention policy are known, so entries
+/// for [ScoringMode::Best] and [ScoringMode::Latest] are picked among those.
+/// [ScoringMode::Cumulative] and [ScoringMode::Count] use the list's lifetime or season
+/// aggregates when `since` matches them, and the kept scores otherwise.
+fn ranked_entries(
+    leaderboard: &LeaderBoard,
+    is_ascending: bool,
+    list: &PlayerScoresList,
+    since: Option<i64>,
+) -> Result<Vec<ScoreEntry>> {
+    let in_range = |entry: &&ScoreEntry| since.is_none_or(|since| entry.timestamp >= since);
+    let mut entries = list.scores.iter().filter(in_range);
+    let (score_type, tie_break) = (leaderboard.score_type, leaderboard.tie_break);
+
+    Ok(match leaderboard.scoring_mode {
+        ScoringMode::Best if leaderboard.allow_multiple_scores => entries.copied().collect(),
+        ScoringMode::Best => {
+            let mut none = [];
+            let ranking = Ranking::new(is_ascending, tie_break, score_type, &mut none);
+            entries
+                .min_by(|a, b| ranking.compare_entries(a, b))
+                .copied()
+                .into_iter()
+                .collect()
+        }
+        ScoringMode::Latest => entries.next_back().copied().into_iter().collect(),
+        mode @ (ScoringMode::Cumulative | ScoringMode::Count) => {
+            let in_season = list.season == leaderboard.season;
+            let (total, count, latest) = match since {
+                None => (list.total_score, list.submission_count, None),
+                Some(since) if since == leaderboard.season_start && in_season => {
+                    (list.season_total_score, list.season_submission_count, None)
+                }
+                Some(since) => {
+                    check_retained(leaderboard, list, since)?;
+                    let latest = entries.clone().next_back().copied();
+                    let (total, count) = entries.fold((0, 0), |(total, count), entry| {
+                        (total + score_type.value(entry.score), count + 1)
+                    });
+                    (total, count, latest)
+                }
+            };
+            if count == 0 {
+                return Ok(Vec::new());
+            }
+
+            // The latest entry may have been dropped, in which case only its time is known.
+            let latest = latest.unwrap_or_else(|| match list.scores.last() {
+                Some(entry) if entry.timestamp == list.last_submission => *entry,
+                _ => ScoreEntry {
+                    timestamp: list.last_submission,
+                    ..ScoreEntry::default()
+                },
+            });
+            let score = match mode {
+                ScoringMode::Cumulative => score_type.from_value(total),
+                _ => count,
+            };
+            vec![ScoreEntry { score, ..latest }]
+        }
+    })
+}
+
+/// Check that `list` still holds every score submitted at or after `since`.
+///
+/// That's the case if no score was ever dropped, or if `since` is within the current
+/// season and none of the season's scores were.
+fn check_retained(leaderboard: &LeaderBoard, list: &PlayerScoresList, since: i64) -> Result<()> {
+    let complete = if list.scores.len() as u64 == list.submission_count {
+        true
+    } else if since < leaderboard.season_start {
+        false
+    } else if list.season != leaderboard.season {
+        // Nothing was submitted this season.
+        true
+    } else {
+        let season_start = leaderboard.season_start;
+        let retained = list
+            .scores
+            .iter()
+            .filter(|entry| entry.timestamp >= season_start)
+            .count();
+        retained as u64 == list.season_submission_count
+    };
+
+    if complete {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}: scores submitted since {since} were dropped by the leaderboard's retention policy",
+            list.player_account
+        )
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soar::{ScoreType, TieBreak};
+
+    fn entry(score: u64, timestamp: i64) -> ScoreEntry {
+        ScoreEntry {
+            score,
+            timestamp,
+            ..ScoreEntry::default()
+        }
+    }
+
+    fn list(
+        player_account: Pubkey,
+        scores: &[ScoreEntry],
+        score_type: ScoreType,
+    ) -> PlayerScoresList {
+        let mut list = PlayerScoresList {
+            player_account,
+            scores: scores.to_vec(),
+            ..PlayerScoresList::default()
+        };
+        for entry in scores {
+            list.record(entry, score_type, false);
+        }
+        list
+    }
+
+    fn players(standings: &[Standing]) -> Vec<Pubkey> {
+        standings.iter().map(|s| s.player_account).collect()
+    }
+
+    #[test]
+    fn best_mode_ranks_each_players_best_entry() {
+        let leaderboard = LeaderBoard::default();
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let lists = [
+            list(a, &[entry(10, 1), entry(30, 2)], ScoreType::Unsigned),
+            list(b, &[entry(20, 1)], ScoreType::Unsigned),
+        ];
+
+        let standings = rank(&leaderboard, false, &lists, None).unwrap();
+        assert_eq!(players(&standings), [a, b]);
+        assert_eq!(standings[0].entry.score, 30);
+        assert_eq!(standings[1].rank, 2);
+
+        let standings = rank(&leaderboard, true, &lists, None).unwrap();
+        assert_eq!(players(&standings), [a, b]);
+        assert_eq!(standings[0].entry.score, 10);
+    }
+
+    #[test]
+    fn multiple_scores_are_ranked_separately() {
+        let leaderboard = LeaderBoard {
+            allow_multiple_scores: true,
+            ..LeaderBoard::default()
+        };
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let lists = [
+            list(a, &[entry(10, 1), entry(30, 2)], ScoreType::Unsigned),
+            list(b, &[entry(20, 1)], ScoreType::Unsigned),
+        ];
+
+        let standings = rank(&leaderboard, false, &lists, None).unwrap();
+        assert_eq!(players(&standings), [a, b, a]);
+    }
+
+    #[test]
+    fn ties_follow_the_leaderboards_rule() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let lists = [
+            list(a, &[entry(10, 1)], ScoreType::Unsigned),
+            list(b, &[entry(10, 2)], ScoreType::Unsigned),
+        ];
+
+        let earlier = LeaderBoard::default();
+        assert_eq!(
+            players(&rank(&earlier, false, &lists, None).unwrap()),
+            [a, b]
+        );
+
+        let later = LeaderBoard {
+            tie_break: TieBreak::LaterWins,
+            ..LeaderBoard::default()
+        };
+        assert_eq!(players(&rank(&later, false, &lists, None).unwrap()), [b, a]);
+    }
+
+    #[test]
+    fn cumulative_mode_ranks_signed_totals() {
+        let leaderboard = LeaderBoard {
+            scoring_mode: ScoringMode::Cumulative,
+            score_type: ScoreType::Signed,
+            ..LeaderBoard::default()
+        };
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let lists = [
+            list(a, &[entry(5, 1), entry(-8i64 as u64, 2)], ScoreType::Signed),
+            list(b, &[entry(-1i64 as u64, 1)], ScoreType::Signed),
+        ];
+
+        let standings = rank(&leaderboard, false, &lists, None).unwrap();
+        assert_eq!(players(&standings), [b, a]);
+        assert_eq!(standings[1].entry.score, -3i64 as u64);
+        assert_eq!(standings[1].entry.timestamp, 2);
+    }
+
+    #[test]
+    fn entries_before_since_are_ignored() {
+        let leaderboard = LeaderBoard::default();
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let lists = [
+            list(a, &[entry(30, 1), entry(10, 5)], ScoreType::Unsigned),
+            list(b, &[entry(20, 2)], ScoreType::Unsigned),
+        ];
+
+        let standings = rank(&leaderboard, false, &lists, Some(5)).unwrap();
+        assert_eq!(players(&standings), [a]);
+        assert_eq!(standings[0].entry.score, 10);
+    }
+
+    #[test]
+    fn count_mode_counts_scores_since() {
+        let leaderboard = LeaderBoard {
+            scoring_mode: ScoringMode::Count,
+            ..LeaderBoard::default()
+        };
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let lists = [
+            list(
+                a,
+                &[entry(1, 1), entry(1, 2), entry(1, 3)],
+                ScoreType::Unsigned,
+            ),
+            list(b, &[entry(1, 4), entry(1, 5)], ScoreType::Unsigned),
+        ];
+
+        let standings = rank(&leaderboard, false, &lists, None).unwrap();
+        assert_eq!(players(&standings), [a, b]);
+        assert_eq!(standings[0].entry.score, 3);
+
+        let standings = rank(&leaderboard, false, &lists, Some(3)).unwrap();
+        assert_eq!(players(&standings), [b, a]);
+        assert_eq!(standings[1].entry.score, 1);
+        assert_eq!(standings[1].entry.timestamp, 3);
+    }
+
+    #[test]
+    fn dropped_scores_are_only_ranked_through_season_aggregates() {
+        let leaderboard = LeaderBoard {
+            scoring_mode: ScoringMode::Cumulative,
+            season: 1,
+            season_start: 10,
+            ..LeaderBoard::default()
+        };
+        let a = Pubkey::new_unique();
+        let mut scores = list(a, &[entry(5, 1)], ScoreType::Unsigned);
+        scores.roll_season(1);
+        for score in [entry(7, 11), entry(9, 12)] {
+            scores.record(&score, ScoreType::Unsigned, false);
+        }
+        // Only the latest score is kept.
+        scores.scores = vec![entry(9, 12)];
+
+        let standings = rank(&leaderboard, false, [&scores], Some(10)).unwrap();
+        assert_eq!(standings[0].entry.score, 16);
+        let standings = rank(&leaderboard, false, [&scores], None).unwrap();
+        assert_eq!(standings[0].entry.score, 21);
+
+        assert!(rank(&leaderboard, false, [&scores], Some(11)).is_err());
+        assert!(rank(&leaderboard, false, [&scores], Some(0)).is_err());
+
+        scores.scores = vec![entry(7, 11), entry(9, 12)];
+        let standings = rank(&leaderboard, false, [&scores], Some(12)).unwrap();
+        assert_eq!(standings[0].entry.score, 9);
+        assert!(rank(&leaderboard, false, [&scores], Some(0)).is_err());
+    }
+
+    #[test]
+    fn around_is_clamped_to_the_ranking() {
+        let leaderboard = LeaderBoard::default();
+        let keys: Vec<_> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let lists: Vec<_> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| list(*key, &[entry(100 - i as u64, 1)], ScoreType::Unsigned))
+            .collect();
+        let standings = rank(&leaderboard, false, &lists, None).unwrap();
+
+        assert_eq!(players(around(&standings, &keys[2], 1)), keys[1..4]);
+        assert_eq!(players(around(&standings, &keys[0], 2)), keys[..3]);
+        assert!(around(&standings, &Pubkey::new_unique(), 2).is_empty());
+    }
+}
@@ -1,9 +1,8 @@
 #![allow(clippy::result_large_err)]
 
 use anchor_lang::prelude::*;
-use soar::cpi::accounts::{ClaimFtReward, SubmitScore};
-use soar::cpi::{self};
-use soar::{LeaderTopEntries, PlayerScoresList};
+use soar_cpi::soar::{self, LeaderTopEntriesV2, PlayerScoresList};
+use soar_cpi::{ClaimFtReward, SubmitScore};
 
 declare_id!("Tensgwm3DY3UJ8nhF7xnD2Wo65VcnLTXjjoyEvs6Zyk");
 
@@ -39,6 +38,7 @@ mod tens {
             msg!(" You won this round! ");
 
             let accounts = SubmitScore {
+                soar_program: ctx.accounts.soar_program.to_account_info(),
                 payer: ctx.accounts.user.to_account_info(),
                 authority: tens.to_account_info(),
                 player_account: ctx.accounts.soar_player_account.to_account_info(),
@@ -57,10 +57,8 @@ mod tens {
             let seeds = &[b"tens".as_ref(), &[state_bump]];
             let signer = &[&seeds[..]];
 
-            let cpi_ctx = CpiContext::new(ctx.accounts.soar_program.to_account_info(), accounts)
-                .with_signer(signer);
             msg!("Submitting score {} for user.", tens.counter);
-            cpi::submit_score(cpi_ctx, tens.counter)?;
+            soar_cpi::submit_score_cpi(accounts, signer, tens.counter)?;
         }
 
         Ok(())
@@ -69,15 +67,15 @@ mod tens {
     pub fn claim_reward(ctx: Context<Claim>) -> Result<()> {
         // We claim a reward if the user's score is present in the top-entries account.
         let player = &ctx.accounts.player_account;
-        let top_entries = &ctx.accounts.soar_top_entries;
-        if top_entries
-            .top_scores
-            .iter()
-            .any(|score| score.player == player.key())
-            .eq(&true)
-        {
+        let has_top_score = {
+            let data = ctx.accounts.soar_top_entries.as_ref().try_borrow_data()?;
+            let (_, top_scores) = LeaderTopEntriesV2::split(&data)?;
+            top_scores.iter().any(|score| score.player == player.key())
+        };
+        if has_top_score {
             msg!("Player has a top score!..Claiming reward: ");
             let accounts = ClaimFtReward {
+                soar_program: ctx.accounts.soar_program.to_account_info(),
                 user: ctx.accounts.user.to_account_info(),
                 authority: ctx.accounts.tens_state.to_account_info(),
                 payer: ctx.accounts.user.to_account_info(),
@@ -96,10 +94,7 @@ mod tens {
             let seeds = &[b"tens".as_ref(), &[state_bump]];
             let signer = &[&seeds[..]];
 
-            let cpi_ctx = CpiContext::new(ctx.accounts.soar_program.to_account_info(), accounts)
-                .with_signer(signer);
-
-            cpi::claim_ft_reward(cpi_ctx)?;
+            soar_cpi::claim_ft_reward_cpi(accounts, signer)?;
         } else {
             msg!("This user isn't eligible for a reward!");
         }
@@ -171,7 +166,7 @@ pub struct Claim<'info> {
     )]
     pub soar_player_scores: Account<'info, PlayerScoresList>,
     #[account(constraint = tens_state.soar.top_entries == soar_top_entries.key())]
-    pub soar_top_entries: Account<'info, LeaderTopEntries>,
+    pub soar_top_entries: AccountLoader<'info, LeaderTopEntriesV2>,
     /// CHECK: The SOAR game for this tens program.
     pub soar_state: UncheckedAccount<'info>,
     /// CHECK: The SOAR achievement.
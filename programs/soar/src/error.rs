@@ -31,4 +31,114 @@ pub enum SoarError {
 
     #[msg("No more rewards are being given out for this game")]
     NoAvailableRewards,
+
+    #[msg("The leaderboard's current season has ended")]
+    SeasonEnded,
+
+    #[msg("The leaderboard's current season is still active")]
+    SeasonStillActive,
+
+    #[msg("Scores are not being accepted for this leaderboard at this time")]
+    SubmissionWindowClosed,
+
+    #[msg("A leaderboard's opening time must be before its closing time")]
+    InvalidSubmissionWindow,
+
+    #[msg("Rank reward tiers must be non-empty, 1-indexed and non-overlapping")]
+    InvalidRankRewardTiers,
+
+    #[msg("The leaderboard's current season must be closed for this instruction")]
+    SeasonNotClosed,
+
+    #[msg("The player does not hold a rewarded rank")]
+    RankNotEligible,
+
+    #[msg("Remaining accounts don't match the layout expected by this instruction")]
+    InvalidRemainingAccounts,
+
+    #[msg("This achievement doesn't track progress")]
+    NoProgressTarget,
+
+    #[msg("The player hasn't unlocked this achievement")]
+    AchievementNotUnlocked,
+
+    #[msg("The reward for this achievement has already been claimed")]
+    RewardAlreadyClaimed,
+
+    #[msg("All players in the merge must approve it before its data can be aggregated")]
+    MergeNotComplete,
+
+    #[msg("This merge has already been approved by every player")]
+    MergeAlreadyComplete,
+
+    #[msg("This merge has expired")]
+    MergeExpired,
+
+    #[msg("This merge can't be closed yet")]
+    MergeNotClosable,
+
+    #[msg("A game must have at least one admin authority")]
+    NoAdminAuthority,
+
+    #[msg("This change requires an approved proposal")]
+    ProposalRequired,
+
+    #[msg("The proposal doesn't have enough approvals or has already been executed")]
+    ProposalNotApproved,
+
+    #[msg("The proposal doesn't match this instruction")]
+    ProposalActionMismatch,
+
+    #[msg("The approval threshold can't exceed the number of admin authorities")]
+    InvalidThreshold,
+
+    #[msg("The reward still has unclaimed spots")]
+    RewardSpotsRemaining,
+
+    #[msg("The achievement's reward must be closed first")]
+    AchievementHasReward,
+
+    #[msg("Missing or invalid ed25519 signature verification for the score attestation")]
+    InvalidAttestation,
+
+    #[msg("The score attestation has expired")]
+    AttestationExpired,
+
+    #[msg("The score attestation's nonce has already been used")]
+    AttestationReplayed,
+
+    #[msg(
+        "Retained score count must be between 1 and the maximum a player scores account can hold"
+    )]
+    InvalidRetentionPolicy,
+
+    #[msg("Only the best scoring mode can allow multiple scores per player")]
+    InvalidScoringMode,
+
+    #[msg("Top entries must keep at least one score and grow by at most 10KiB per instruction")]
+    InvalidTopEntriesSize,
+
+    #[msg("The minimum score can't be greater than the maximum score")]
+    InvalidScoreBounds,
+
+    #[msg("A score was submitted for this player too recently")]
+    SubmissionTooSoon,
+
+    #[msg("The player has no attempts left for this leaderboard's current window")]
+    AttemptLimitReached,
+
+    #[msg("The season end must be in the future")]
+    InvalidSeasonEnd,
+
+    #[msg("This achievement was merged into another player's account")]
+    AchievementAbsorbed,
+
+    #[msg("Resizing top entries would drop ranked entries")]
+    RankedEntriesDropped,
+
+    #[msg("The account is in a legacy layout and must be migrated first")]
+    LegacyAccountLayout,
+
+    #[msg("The top entries rank more scores than a season archive can hold")]
+    TooManyEntriesToArchive,
 }
@@ -0,0 +1,370 @@
+use crate::state::{
+    AchievementRule, GameAttributes, GameAuthority, ProposalAction, RewardKind,
+    UpdateLeaderBoardInput,
+};
+use anchor_lang::prelude::*;
+
+/// Emitted when a new [Game][crate::Game] is created.
+#[event]
+pub struct GameCreated {
+    pub game: Pubkey,
+}
+
+/// Emitted when a [Game][crate::Game]'s attributes, authorities or approval threshold change.
+#[event]
+pub struct GameUpdated {
+    pub game: Pubkey,
+
+    /// The new attributes, or [None] if unchanged.
+    pub new_attributes: Option<GameAttributes>,
+
+    /// The new authority list, or [None] if unchanged.
+    pub new_auth: Option<Vec<GameAuthority>>,
+
+    /// The new approval threshold, or [None] if unchanged.
+    pub new_threshold: Option<u8>,
+}
+
+/// Emitted when a [GameProposal][crate::GameProposal] is created.
+#[event]
+pub struct ProposalCreated {
+    pub game: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub action: ProposalAction,
+}
+
+/// Emitted when a game admin approves a [GameProposal][crate::GameProposal].
+#[event]
+pub struct ProposalApproved {
+    pub proposal: Pubkey,
+    pub authority: Pubkey,
+    pub approvals: u32,
+}
+
+/// Emitted when a [LeaderBoard][crate::LeaderBoard] is added to a game.
+#[event]
+pub struct LeaderBoardCreated {
+    pub game: Pubkey,
+    pub leaderboard: Pubkey,
+    pub id: u64,
+    pub top_entries: Option<Pubkey>,
+}
+
+/// Emitted when a [LeaderBoard][crate::LeaderBoard]'s settings change.
+#[event]
+pub struct LeaderBoardUpdated {
+    pub leaderboard: Pubkey,
+
+    /// The update as applied. Fields set to [None] were left unchanged.
+    pub changes: UpdateLeaderBoardInput,
+}
+
+/// Emitted when a [LeaderBoard][crate::LeaderBoard]'s season is closed and archived.
+#[event]
+pub struct SeasonClosed {
+    pub leaderboard: Pubkey,
+    pub season: u64,
+    pub end: i64,
+}
+
+/// Emitted when a [LeaderBoard][crate::LeaderBoard]'s next season starts.
+#[event]
+pub struct SeasonStarted {
+    pub leaderboard: Pubkey,
+    pub season: u64,
+    pub start: i64,
+    pub end: Option<i64>,
+}
+
+/// Emitted when a leaderboard's top entries change capacity.
+#[event]
+pub struct TopEntriesResized {
+    pub leaderboard: Pubkey,
+    pub top_entries: Pubkey,
+    pub previous_len: u32,
+    pub new_len: u32,
+}
+
+/// Emitted when a game is migrated to the current layout.
+#[event]
+pub struct GameMigrated {
+    pub game: Pubkey,
+    pub auth: Vec<Pubkey>,
+}
+
+/// Emitted when a player's achievement status is migrated to the current layout.
+#[event]
+pub struct PlayerAchievementMigrated {
+    pub player_achievement: Pubkey,
+    pub player_account: Pubkey,
+    pub achievement: Pubkey,
+}
+
+/// Emitted when a merge account is migrated to the current layout.
+#[event]
+pub struct MergeMigrated {
+    pub merge_account: Pubkey,
+    pub expires_at: i64,
+}
+
+/// Emitted when a player's scores are migrated to the current layout.
+#[event]
+pub struct PlayerScoresMigrated {
+    pub leaderboard: Pubkey,
+    pub player_account: Pubkey,
+    pub player_scores: Pubkey,
+    pub submission_count: u64,
+}
+
+/// Emitted when a leaderboard's top entries are migrated to the current layout.
+#[event]
+pub struct TopEntriesMigrated {
+    pub leaderboard: Pubkey,
+    pub top_entries: Pubkey,
+    pub capacity: u32,
+}
+
+/// Emitted when a merged player's entries in a leaderboard's top entries are reassigned.
+#[event]
+pub struct TopEntriesMerged {
+    pub merge_account: Pubkey,
+    pub leaderboard: Pubkey,
+    pub player_account: Pubkey,
+}
+
+/// Emitted when a [LeaderBoard][crate::LeaderBoard] is closed.
+#[event]
+pub struct LeaderBoardClosed {
+    pub game: Pubkey,
+    pub leaderboard: Pubkey,
+}
+
+/// Emitted when an [Achievement][crate::Achievement] is added to a game.
+#[event]
+pub struct AchievementCreated {
+    pub game: Pubkey,
+    pub achievement: Pubkey,
+    pub id: u64,
+}
+
+/// Emitted when an [Achievement][crate::Achievement]'s details change.
+#[event]
+pub struct AchievementUpdated {
+    pub achievement: Pubkey,
+
+    /// The new title, or [None] if unchanged.
+    pub new_title: Option<String>,
+
+    /// The new description, or [None] if unchanged.
+    pub new_description: Option<String>,
+
+    /// The new nft metadata, or [None] if unchanged.
+    pub new_meta: Option<Pubkey>,
+
+    /// The new unlock rule, or [None] if unchanged.
+    pub new_unlock_rule: Option<Option<AchievementRule>>,
+}
+
+/// Emitted when an [Achievement][crate::Achievement] is closed.
+#[event]
+pub struct AchievementClosed {
+    pub game: Pubkey,
+    pub achievement: Pubkey,
+}
+
+/// Emitted when a [Player][crate::Player] account is created.
+#[event]
+pub struct PlayerCreated {
+    pub player_account: Pubkey,
+    pub user: Pubkey,
+    pub username: String,
+    pub nft_meta: Pubkey,
+}
+
+/// Emitted when a [Player][crate::Player]'s details change.
+#[event]
+pub struct PlayerUpdated {
+    pub player_account: Pubkey,
+
+    /// The new username, or [None] if unchanged.
+    pub new_username: Option<String>,
+
+    /// The new nft metadata, or [None] if unchanged.
+    pub new_nft_meta: Option<Pubkey>,
+}
+
+/// Emitted when a player registers to a [LeaderBoard][crate::LeaderBoard].
+#[event]
+pub struct PlayerRegistered {
+    pub leaderboard: Pubkey,
+    pub player_account: Pubkey,
+    pub player_scores: Pubkey,
+}
+
+/// Emitted when a [Player][crate::Player] account is closed.
+#[event]
+pub struct PlayerClosed {
+    pub player_account: Pubkey,
+}
+
+/// Emitted when a player's [PlayerScoresList][crate::PlayerScoresList] is closed.
+#[event]
+pub struct PlayerScoresClosed {
+    pub leaderboard: Pubkey,
+    pub player_account: Pubkey,
+    pub player_scores: Pubkey,
+}
+
+/// Emitted when a player's [PlayerAchievement][crate::PlayerAchievement] is closed.
+#[event]
+pub struct PlayerAchievementClosed {
+    pub achievement: Pubkey,
+    pub player_account: Pubkey,
+    pub player_achievement: Pubkey,
+}
+
+/// Emitted for every score recorded for a player.
+#[event]
+pub struct ScoreSubmitted {
+    pub leaderboard: Pubkey,
+    pub player_account: Pubkey,
+    pub season: u64,
+    pub score: u64,
+    pub secondary_score: Option<u64>,
+    pub context: [u8; 32],
+    pub timestamp: i64,
+
+    /// The 1-based rank the player was placed at in the leaderboard's top entries,
+    /// or [None] if the score didn't enter them.
+    pub rank: Option<u32>,
+}
+
+/// Emitted when a player unlocks an [Achievement][crate::Achievement].
+#[event]
+pub struct AchievementUnlocked {
+    pub achievement: Pubkey,
+    pub player_account: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a [Reward][crate::Reward] is added to an achievement.
+#[event]
+pub struct RewardAdded {
+    pub achievement: Pubkey,
+    pub reward: Pubkey,
+    pub available_spots: u64,
+    pub kind: RewardKind,
+}
+
+/// Emitted when a [Reward][crate::Reward] is closed.
+#[event]
+pub struct RewardClosed {
+    pub achievement: Pubkey,
+    pub reward: Pubkey,
+}
+
+/// Emitted when the claim record of an NFT reward is closed.
+#[event]
+pub struct NftClaimClosed {
+    pub reward: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// Emitted when a player claims an achievement's [Reward][crate::Reward].
+#[event]
+pub struct RewardClaimed {
+    pub achievement: Pubkey,
+    pub reward: Pubkey,
+    pub player_account: Pubkey,
+
+    /// The token mint transferred from, or the newly minted NFT.
+    pub mint: Pubkey,
+}
+
+/// Emitted when a [RankReward][crate::RankReward] is added for a leaderboard season.
+#[event]
+pub struct RankRewardAdded {
+    pub leaderboard: Pubkey,
+    pub rank_reward: Pubkey,
+    pub season: u64,
+    pub mint: Pubkey,
+    pub deposit: u64,
+}
+
+/// Emitted when a player claims a [RankReward][crate::RankReward].
+#[event]
+pub struct RankRewardClaimed {
+    pub leaderboard: Pubkey,
+    pub rank_reward: Pubkey,
+    pub player_account: Pubkey,
+    pub rank: u32,
+    pub amount: u64,
+}
+
+/// Emitted when a claimed NFT reward is verified as part of its collection.
+#[event]
+pub struct NftRewardVerified {
+    pub achievement: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// Emitted when a player starts a [Merged][crate::Merged] account.
+#[event]
+pub struct MergeInitiated {
+    pub merge_account: Pubkey,
+    pub player_account: Pubkey,
+
+    /// The other players whose approval is needed.
+    pub others: Vec<Pubkey>,
+
+    /// Whether no approvals were needed.
+    pub merge_complete: bool,
+}
+
+/// Emitted when a player approves a [Merged][crate::Merged] account.
+#[event]
+pub struct MergeApproved {
+    pub merge_account: Pubkey,
+    pub player_account: Pubkey,
+
+    /// Whether this was the last approval needed.
+    pub merge_complete: bool,
+}
+
+/// Emitted when a player rejects a [Merged][crate::Merged] account, closing it.
+#[event]
+pub struct MergeRejected {
+    pub merge_account: Pubkey,
+    pub player_account: Pubkey,
+}
+
+/// Emitted when the initiator cancels a [Merged][crate::Merged] account, closing it.
+#[event]
+pub struct MergeCancelled {
+    pub merge_account: Pubkey,
+}
+
+/// Emitted when a completed or expired [Merged][crate::Merged] account is closed.
+#[event]
+pub struct MergeClosed {
+    pub merge_account: Pubkey,
+}
+
+/// Emitted when a merged player's scores for a leaderboard are moved into the initiator's.
+#[event]
+pub struct PlayerScoresMerged {
+    pub merge_account: Pubkey,
+    pub leaderboard: Pubkey,
+    pub player_scores: Pubkey,
+    pub merged_player_scores: Pubkey,
+}
+
+/// Emitted when a merged player's status for an achievement is folded into the initiator's.
+#[event]
+pub struct PlayerAchievementMerged {
+    pub merge_account: Pubkey,
+    pub achievement: Pubkey,
+    pub player_achievement: Pubkey,
+    pub merged_player_achievement: Pubkey,
+}
@@ -1,6 +1,6 @@
 use crate::{
-    state::{Achievement, FieldsCheck},
-    AddAchievement,
+    state::{Achievement, AchievementRule, FieldsCheck},
+    AchievementCreated, AddAchievement,
 };
 use anchor_lang::prelude::*;
 
@@ -9,6 +9,8 @@ pub fn handler(
     title: String,
     description: String,
     nft_meta: Pubkey,
+    unlock_rule: Option<AchievementRule>,
+    progress_target: Option<u64>,
 ) -> Result<()> {
     let game = &mut ctx.accounts.game;
     game.achievement_count = game.next_achievement();
@@ -18,9 +20,16 @@ pub fn handler(
         description,
         nft_meta,
         game.achievement_count,
+        unlock_rule,
+        progress_target,
     );
 
     obj.check()?;
     ctx.accounts.new_achievement.set_inner(obj);
+    emit!(AchievementCreated {
+        game: game.key(),
+        achievement: ctx.accounts.new_achievement.key(),
+        id: game.achievement_count,
+    });
     Ok(())
 }
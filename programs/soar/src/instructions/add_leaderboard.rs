@@ -1,10 +1,17 @@
 use crate::error::SoarError;
-use crate::state::{FieldsCheck, LeaderBoardScore, RegisterLeaderBoardInput};
-use crate::AddLeaderBoard;
+use crate::state::{
+    FieldsCheck, LeaderTopEntriesV2, ProposalAction, Ranking, RegisterLeaderBoardInput,
+};
+use crate::{utils, AddLeaderBoard, LeaderBoardCreated};
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<AddLeaderBoard>, input: RegisterLeaderBoardInput) -> Result<()> {
     input.check()?;
+    utils::authorize_with_proposal(
+        &ctx.accounts.game,
+        ctx.accounts.proposal.as_mut(),
+        &ProposalAction::AddLeaderBoard,
+    )?;
 
     let game = &ctx.accounts.game;
     let new_count = game.next_leaderboard();
@@ -16,6 +23,7 @@ pub fn handler(ctx: Context<AddLeaderBoard>, input: RegisterLeaderBoardInput) ->
     ctx.accounts.leaderboard.set_inner(leaderboard);
     ctx.accounts.leaderboard.id = new_count;
     ctx.accounts.leaderboard.game = game.key();
+    ctx.accounts.leaderboard.season_start = Clock::get().unwrap().unix_timestamp;
     ctx.accounts.game.leaderboard_count = new_count;
 
     if retain_count > 0 {
@@ -25,19 +33,29 @@ pub fn handler(ctx: Context<AddLeaderBoard>, input: RegisterLeaderBoardInput) ->
         );
     }
 
-    if retain_count > 0 && ctx.accounts.top_entries.is_some() {
-        let top_entries = &mut ctx.accounts.top_entries.as_mut().unwrap();
-
-        top_entries.is_ascending = order;
-        top_entries.top_scores = vec![LeaderBoardScore::default(); retain_count as usize];
-
-        if top_entries.is_ascending {
-            top_entries.top_scores.iter_mut().for_each(|s| {
-                s.entry.score = ctx.accounts.leaderboard.max_score;
-            });
-        }
-        ctx.accounts.leaderboard.top_entries = Some(top_entries.key());
+    if let Some(top_entries) = ctx
+        .accounts
+        .top_entries
+        .as_ref()
+        .filter(|_| retain_count > 0)
+    {
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        *top_entries.load_init()? =
+            LeaderTopEntriesV2::new(leaderboard.key(), retain_count as usize, order);
+
+        let mut data = top_entries.as_ref().try_borrow_mut_data()?;
+        let (_, scores) = LeaderTopEntriesV2::split_mut(&mut data)?;
+        Ranking::new(order, leaderboard.tie_break, leaderboard.score_type, scores)
+            .clear(leaderboard.max_score);
+        leaderboard.top_entries = Some(top_entries.key());
     }
 
+    let leaderboard = &ctx.accounts.leaderboard;
+    emit!(LeaderBoardCreated {
+        game: leaderboard.game,
+        leaderboard: leaderboard.key(),
+        id: leaderboard.id,
+        top_entries: leaderboard.top_entries,
+    });
     Ok(())
 }
@@ -0,0 +1,55 @@
+use crate::{
+    error::SoarError,
+    state::{AddRankRewardInput, FieldsCheck, ProposalAction, RankReward},
+    utils, AddRankReward, RankRewardAdded,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve};
+
+pub fn handler(ctx: Context<AddRankReward>, input: AddRankRewardInput) -> Result<()> {
+    input.check()?;
+    let capacity = ctx.accounts.top_entries.load()?.capacity;
+    require!(
+        input.tiers.iter().all(|tier| tier.end_rank <= capacity),
+        SoarError::InvalidRankRewardTiers
+    );
+    utils::authorize_with_proposal(
+        &ctx.accounts.game,
+        ctx.accounts.proposal.as_deref_mut(),
+        &ProposalAction::AddRankReward {
+            leaderboard: ctx.accounts.leaderboard.key(),
+        },
+    )?;
+
+    let leaderboard = &ctx.accounts.leaderboard;
+    let token_account = &ctx.accounts.delegate_from_token_account;
+
+    let rank_reward = &mut ctx.accounts.rank_reward;
+    rank_reward.set_inner(RankReward {
+        leaderboard: leaderboard.key(),
+        season: leaderboard.season,
+        mint: ctx.accounts.reward_token_mint.key(),
+        account: token_account.key(),
+        tiers: input.tiers,
+    });
+
+    // Delegate authority to spend some amount of tokens to the `rank_reward` PDA.
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Approve {
+            to: token_account.to_account_info(),
+            delegate: rank_reward.to_account_info(),
+            authority: ctx.accounts.token_account_owner.to_account_info(),
+        },
+    );
+    token::approve(cpi_ctx, input.deposit)?;
+
+    emit!(RankRewardAdded {
+        leaderboard: leaderboard.key(),
+        rank_reward: rank_reward.key(),
+        season: rank_reward.season,
+        mint: rank_reward.mint,
+        deposit: input.deposit,
+    });
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::{
     error::SoarError,
-    state::{AddNewRewardInput, RewardKind, RewardKindInput},
-    utils, FieldsCheck,
+    state::{AddNewRewardInput, ProposalAction, RewardKind, RewardKindInput},
+    utils, FieldsCheck, RewardAdded,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Approve};
@@ -11,6 +11,14 @@ pub mod ft {
     use crate::AddFtReward;
 
     pub fn handler(ctx: Context<AddFtReward>, input: AddNewRewardInput) -> Result<()> {
+        utils::authorize_with_proposal(
+            &ctx.accounts.game,
+            ctx.accounts.proposal.as_deref_mut(),
+            &ProposalAction::AddReward {
+                achievement: ctx.accounts.achievement.key(),
+            },
+        )?;
+
         let new_reward = &mut ctx.accounts.new_reward;
         new_reward.achievement = ctx.accounts.achievement.key();
         new_reward.available_spots = input.available_spots;
@@ -46,6 +54,12 @@ pub mod ft {
                 let achievement = &mut ctx.accounts.achievement;
                 achievement.reward = Some(new_reward.key());
 
+                emit!(RewardAdded {
+                    achievement: achievement.key(),
+                    reward: new_reward.key(),
+                    available_spots: new_reward.available_spots,
+                    kind: new_reward.reward.clone(),
+                });
                 Ok(())
             }
             RewardKindInput::Nft {
@@ -62,6 +76,14 @@ pub mod nft {
     use crate::AddNftReward;
 
     pub fn handler(ctx: Context<AddNftReward>, input: AddNewRewardInput) -> Result<()> {
+        utils::authorize_with_proposal(
+            &ctx.accounts.game,
+            ctx.accounts.proposal.as_deref_mut(),
+            &ProposalAction::AddReward {
+                achievement: ctx.accounts.achievement.key(),
+            },
+        )?;
+
         let new_reward = &mut ctx.accounts.new_reward;
         new_reward.achievement = ctx.accounts.achievement.key();
         new_reward.available_spots = input.available_spots;
@@ -123,6 +145,12 @@ pub mod nft {
                 let achievement = &mut ctx.accounts.achievement;
                 achievement.reward = Some(new_reward.key());
 
+                emit!(RewardAdded {
+                    achievement: achievement.key(),
+                    reward: new_reward.key(),
+                    available_spots: new_reward.available_spots,
+                    kind: new_reward.reward.clone(),
+                });
                 Ok(())
             }
             RewardKindInput::Ft {
@@ -1,8 +1,14 @@
-use crate::{error::SoarError, ApproveMerge};
+use crate::{error::SoarError, ApproveMerge, MergeApproved};
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<ApproveMerge>) -> Result<()> {
     let merge_account = &mut ctx.accounts.merge_account;
+    let clock = Clock::get().unwrap();
+    require!(
+        !merge_account.is_expired(clock.unix_timestamp),
+        SoarError::MergeExpired
+    );
+
     let player_account = &ctx.accounts.player_account;
     let approvals = &mut merge_account.approvals;
 
@@ -22,5 +28,10 @@ pub fn handler(ctx: Context<ApproveMerge>) -> Result<()> {
         merge_account.merge_complete = true;
     }
 
+    emit!(MergeApproved {
+        merge_account: merge_account.key(),
+        player_account: player_account.key(),
+        merge_complete: merge_account.merge_complete,
+    });
     Ok(())
 }
@@ -0,0 +1,30 @@
+use crate::{error::SoarError, state::GameProposal, utils, ApproveProposal, ProposalApproved};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<ApproveProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let authority = ctx.accounts.authority.key();
+    require!(!proposal.executed, SoarError::ProposalNotApproved);
+
+    if proposal.approvals.contains(&authority) {
+        return Ok(());
+    }
+
+    let new_size = GameProposal::size(&proposal.action, proposal.approvals.len() + 1);
+    if new_size > proposal.to_account_info().data_len() {
+        utils::resize_account(
+            &proposal.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_size,
+        )?;
+    }
+    proposal.approvals.push(authority);
+
+    emit!(ProposalApproved {
+        proposal: proposal.key(),
+        authority,
+        approvals: u32::try_from(proposal.approvals.len()).unwrap(),
+    });
+    Ok(())
+}
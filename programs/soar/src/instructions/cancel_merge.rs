@@ -0,0 +1,9 @@
+use crate::{CancelMerge, MergeCancelled};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<CancelMerge>) -> Result<()> {
+    emit!(MergeCancelled {
+        merge_account: ctx.accounts.merge_account.key(),
+    });
+    Ok(())
+}
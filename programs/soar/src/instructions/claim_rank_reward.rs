@@ -0,0 +1,55 @@
+use crate::{error::SoarError, seeds, state::RankRewardClaim, ClaimRankReward, RankRewardClaimed};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+pub fn handler(ctx: Context<ClaimRankReward>, rank: u32) -> Result<()> {
+    let player_key = ctx.accounts.player_account.key();
+    let rank_reward = &ctx.accounts.rank_reward;
+
+    let amount = rank_reward
+        .amount_for_rank(rank)
+        .ok_or(SoarError::RankNotEligible)?;
+
+    // The archive is written once when the season closes, so later changes to the
+    // leaderboard's top entries can't affect who holds a rank.
+    let holder = rank
+        .checked_sub(1)
+        .and_then(|index| ctx.accounts.archive.top_scores.get(index as usize))
+        .ok_or(SoarError::RankNotEligible)?;
+    require_keys_eq!(holder.player, player_key, SoarError::RankNotEligible);
+
+    let leaderboard_key = ctx.accounts.leaderboard.key();
+    let season = rank_reward.season.to_le_bytes();
+    let rank_reward_seeds = &[
+        seeds::RANK_REWARD,
+        leaderboard_key.as_ref(),
+        &season,
+        &[ctx.bumps.rank_reward],
+    ];
+    let signer = &[&rank_reward_seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.source_token_account.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: rank_reward.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    ctx.accounts.claim.set_inner(RankRewardClaim {
+        player_account: player_key,
+        rank,
+    });
+
+    emit!(RankRewardClaimed {
+        leaderboard: leaderboard_key,
+        rank_reward: rank_reward.key(),
+        player_account: player_key,
+        rank,
+        amount,
+    });
+    Ok(())
+}
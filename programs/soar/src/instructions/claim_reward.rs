@@ -1,11 +1,49 @@
 use crate::{
     error::SoarError,
-    state::{PlayerAchievement, RewardKind},
-    utils,
+    state::{Achievement, PlayerAchievement, RewardKind},
+    utils, AchievementUnlocked, RewardClaimed,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
 
+/// Mark a [PlayerAchievement] as claimed, initializing it as unlocked if it was just created.
+///
+/// Existing accounts must already be unlocked, unclaimed and not merged into another player. Achievements with a
+/// `progress_target` can only be claimed once the target has been reached.
+fn record_claim(
+    player_achievement: &mut Account<PlayerAchievement>,
+    player_account: Pubkey,
+    achievement: &Account<Achievement>,
+) -> Result<()> {
+    if player_achievement.player_account == Pubkey::default() {
+        require!(
+            achievement.progress_target.is_none(),
+            SoarError::AchievementNotUnlocked
+        );
+        let timestamp = Clock::get().unwrap().unix_timestamp;
+        player_achievement.set_inner(PlayerAchievement::new(
+            player_account,
+            achievement.key(),
+            timestamp,
+        ));
+        emit!(AchievementUnlocked {
+            achievement: achievement.key(),
+            player_account,
+            timestamp,
+        });
+    }
+
+    require!(!player_achievement.absorbed, SoarError::AchievementAbsorbed);
+    require!(
+        player_achievement.unlocked,
+        SoarError::AchievementNotUnlocked
+    );
+    require!(!player_achievement.claimed, SoarError::RewardAlreadyClaimed);
+    player_achievement.claimed = true;
+
+    Ok(())
+}
+
 pub mod ft {
     use super::*;
     use crate::ClaimFtReward;
@@ -31,7 +69,7 @@ pub mod ft {
         let reward = &mut reward_account.reward;
         match reward {
             RewardKind::FungibleToken {
-                mint: _,
+                mint,
                 account,
                 amount,
             } => {
@@ -50,16 +88,22 @@ pub mod ft {
                 );
 
                 token::transfer(cpi_ctx, *amount)?;
+                let mint = *mint;
 
-                player_achievement.set_inner(PlayerAchievement::new(
+                record_claim(
+                    player_achievement,
                     ctx.accounts.player_account.key(),
-                    ctx.accounts.achievement.key(),
-                    Clock::get().unwrap().unix_timestamp,
-                ));
-                player_achievement.claimed = true;
+                    achievement_account,
+                )?;
 
                 reward_account.available_spots =
                     reward_account.available_spots.checked_sub(1).unwrap();
+                emit!(RewardClaimed {
+                    achievement: achievement_account.key(),
+                    reward: reward_account.key(),
+                    player_account: ctx.accounts.player_account.key(),
+                    mint,
+                });
                 Ok(())
             }
             RewardKind::NonFungibleToken {
@@ -181,15 +225,20 @@ pub mod nft {
 
                 *minted = minted.checked_add(1).unwrap();
 
-                player_achievement.set_inner(PlayerAchievement::new(
+                record_claim(
+                    player_achievement,
                     ctx.accounts.player_account.key(),
-                    ctx.accounts.achievement.key(),
-                    Clock::get().unwrap().unix_timestamp,
-                ));
-                player_achievement.claimed = true;
+                    achievement_account,
+                )?;
 
                 reward_account.available_spots =
                     reward_account.available_spots.checked_sub(1).unwrap();
+                emit!(RewardClaimed {
+                    achievement: achievement_account.key(),
+                    reward: reward_account.key(),
+                    player_account: ctx.accounts.player_account.key(),
+                    mint: mint.key(),
+                });
                 Ok(())
             }
             RewardKind::FungibleToken {
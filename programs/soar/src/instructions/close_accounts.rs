@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+pub mod achievement {
+    use super::*;
+    use crate::{AchievementClosed, CloseAchievement};
+
+    pub fn handler(ctx: Context<CloseAchievement>) -> Result<()> {
+        emit!(AchievementClosed {
+            game: ctx.accounts.game.key(),
+            achievement: ctx.accounts.achievement.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod reward {
+    use super::*;
+    use crate::{CloseReward, RewardClosed};
+
+    pub fn handler(ctx: Context<CloseReward>) -> Result<()> {
+        let achievement = &mut ctx.accounts.achievement;
+        if achievement.reward == Some(ctx.accounts.reward.key()) {
+            achievement.reward = None;
+        }
+
+        emit!(RewardClosed {
+            achievement: achievement.key(),
+            reward: ctx.accounts.reward.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod leaderboard {
+    use super::*;
+    use crate::{error::SoarError, CloseLeaderBoard, LeaderBoardClosed};
+
+    pub fn handler(ctx: Context<CloseLeaderBoard>) -> Result<()> {
+        let expected = ctx.accounts.leaderboard.top_entries;
+        let provided = ctx.accounts.top_entries.as_ref().map(|t| t.key());
+        require!(expected == provided, SoarError::MissingExpectedAccount);
+
+        emit!(LeaderBoardClosed {
+            game: ctx.accounts.game.key(),
+            leaderboard: ctx.accounts.leaderboard.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod player_scores {
+    use super::*;
+    use crate::{error::SoarError, state::LeaderBoard, ClosePlayerScores, PlayerScoresClosed};
+
+    pub fn handler(ctx: Context<ClosePlayerScores>) -> Result<()> {
+        // Closed leaderboards are left empty and owned by the system program.
+        let data = ctx.accounts.leaderboard.try_borrow_data()?;
+        if !data.is_empty() {
+            let leaderboard = LeaderBoard::try_deserialize(&mut &data[..])?;
+            require!(leaderboard.is_frozen, SoarError::SeasonStillActive);
+        }
+
+        emit!(PlayerScoresClosed {
+            leaderboard: ctx.accounts.leaderboard.key(),
+            player_account: ctx.accounts.player_account.key(),
+            player_scores: ctx.accounts.player_scores.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod player_achievement {
+    use super::*;
+    use crate::{ClosePlayerAchievement, PlayerAchievementClosed};
+
+    pub fn handler(ctx: Context<ClosePlayerAchievement>) -> Result<()> {
+        emit!(PlayerAchievementClosed {
+            achievement: ctx.accounts.achievement.key(),
+            player_account: ctx.accounts.player_achievement.player_account,
+            player_achievement: ctx.accounts.player_achievement.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod nft_claim {
+    use super::*;
+    use crate::{CloseNftClaim, NftClaimClosed};
+
+    pub fn handler(ctx: Context<CloseNftClaim>) -> Result<()> {
+        emit!(NftClaimClosed {
+            reward: ctx.accounts.reward.key(),
+            mint: ctx.accounts.mint.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod player {
+    use super::*;
+    use crate::{ClosePlayer, PlayerClosed};
+
+    pub fn handler(ctx: Context<ClosePlayer>) -> Result<()> {
+        emit!(PlayerClosed {
+            player_account: ctx.accounts.player_account.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{error::SoarError, CloseMerge, MergeClosed};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<CloseMerge>) -> Result<()> {
+    let merge_account = &ctx.accounts.merge_account;
+    let clock = Clock::get().unwrap();
+
+    let closable = if merge_account.merge_complete {
+        ctx.accounts.authority.key() == merge_account.initiator
+    } else {
+        merge_account.is_expired(clock.unix_timestamp)
+    };
+    require!(closable, SoarError::MergeNotClosable);
+
+    emit!(MergeClosed {
+        merge_account: merge_account.key(),
+    });
+    Ok(())
+}
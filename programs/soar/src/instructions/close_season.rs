@@ -0,0 +1,56 @@
+use crate::{
+    error::SoarError,
+    state::{LeaderTopEntriesV2, SeasonArchive},
+    CloseSeason, SeasonClosed,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<CloseSeason>) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let clock = Clock::get().unwrap();
+
+    let expected = leaderboard.top_entries;
+    let provided = ctx.accounts.top_entries.as_ref().map(|t| t.key());
+    require!(expected == provided, SoarError::MissingExpectedAccount);
+
+    let (is_ascending, top_scores) = match &ctx.accounts.top_entries {
+        Some(top_entries) => {
+            let data = top_entries.as_ref().try_borrow_data()?;
+            let (header, scores) = LeaderTopEntriesV2::split(&data)?;
+            let ranked = scores.iter().filter(|s| !s.is_placeholder()).count();
+            require!(
+                ranked <= SeasonArchive::MAX_SCORES,
+                SoarError::TooManyEntriesToArchive
+            );
+
+            // Unused slots are ranked last, so every ranked entry is kept.
+            let archived = scores.len().min(SeasonArchive::MAX_SCORES);
+            (header.is_ascending(), scores[..archived].to_vec())
+        }
+        None => (false, Vec::new()),
+    };
+
+    let end = match leaderboard.season_end {
+        Some(end) if end < clock.unix_timestamp => end,
+        _ => clock.unix_timestamp,
+    };
+
+    ctx.accounts.archive.set_inner(SeasonArchive {
+        leaderboard: leaderboard.key(),
+        season: leaderboard.season,
+        start: leaderboard.season_start,
+        end,
+        is_ascending,
+        top_scores,
+    });
+
+    leaderboard.season_end = Some(end);
+    leaderboard.is_frozen = true;
+
+    emit!(SeasonClosed {
+        leaderboard: leaderboard.key(),
+        season: leaderboard.season,
+        end,
+    });
+    Ok(())
+}
@@ -1,15 +1,22 @@
 use crate::{
-    state::{FieldsCheck, Game, GameAttributes},
-    InitializeGame,
+    error::SoarError,
+    state::{FieldsCheck, Game, GameAttributes, GameAuthority},
+    GameCreated, InitializeGame,
 };
 use anchor_lang::prelude::*;
 
 pub fn handler(
     ctx: Context<InitializeGame>,
     game_meta_input: GameAttributes,
-    game_auth_input: Vec<Pubkey>,
+    game_auth_input: Vec<GameAuthority>,
 ) -> Result<()> {
     game_meta_input.check()?;
+    require!(
+        game_auth_input
+            .iter()
+            .any(|auth| auth.has_role(GameAuthority::ADMIN)),
+        SoarError::NoAdminAuthority
+    );
 
     let game_account = &mut ctx.accounts.game;
     let mut game_object = Game::default();
@@ -20,6 +27,9 @@ pub fn handler(
     game_object.auth = game_auth_input;
 
     game_account.set_inner(game_object);
+    emit!(GameCreated {
+        game: game_account.key(),
+    });
 
     Ok(())
 }
@@ -1,14 +1,21 @@
 use crate::{
     state::{FieldsCheck, Player},
-    InitializePlayer,
+    InitializePlayer, PlayerCreated,
 };
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<InitializePlayer>, username: String, nft_meta: Pubkey) -> Result<()> {
     let user = &ctx.accounts.user.key();
-    let player = Player::new(username, nft_meta, *user);
+    let player = Player::new(username.clone(), nft_meta, *user);
     player.check()?;
 
     ctx.accounts.player_account.set_inner(player);
+
+    emit!(PlayerCreated {
+        player_account: ctx.accounts.player_account.key(),
+        user: *user,
+        username,
+        nft_meta,
+    });
     Ok(())
 }
@@ -0,0 +1,28 @@
+use crate::{
+    state::{GameProposal, ProposalAction},
+    CreateProposal, ProposalCreated,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    game.proposal_count = game.next_proposal();
+
+    let proposal = GameProposal {
+        game: game.key(),
+        id: game.proposal_count,
+        action: action.clone(),
+        approvals: vec![ctx.accounts.authority.key()],
+        executed: false,
+    };
+
+    ctx.accounts.proposal.set_inner(proposal);
+
+    emit!(ProposalCreated {
+        game: game.key(),
+        proposal: ctx.accounts.proposal.key(),
+        id: game.proposal_count,
+        action,
+    });
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::{
+    error::SoarError, instructions::update_game, state::ProposalAction, ExecuteProposal,
+    GameUpdated,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(proposal.is_approved(game), SoarError::ProposalNotApproved);
+
+    let mut event = GameUpdated {
+        game: game.key(),
+        new_attributes: None,
+        new_auth: None,
+        new_threshold: None,
+    };
+    match proposal.action.clone() {
+        ProposalAction::UpdateAuth { new_auth } => {
+            update_game::set_auth(
+                game,
+                new_auth.clone(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+            event.new_auth = Some(new_auth);
+        }
+        ProposalAction::SetThreshold { threshold } => {
+            require!(
+                usize::from(threshold) <= game.admin_count(),
+                SoarError::InvalidThreshold
+            );
+            game.threshold = threshold;
+            event.new_threshold = Some(threshold);
+        }
+        _ => return Err(SoarError::ProposalActionMismatch.into()),
+    }
+
+    proposal.executed = true;
+    emit!(event);
+    Ok(())
+}
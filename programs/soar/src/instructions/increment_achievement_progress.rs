@@ -0,0 +1,27 @@
+use crate::{AchievementUnlocked, IncrementAchievementProgress};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<IncrementAchievementProgress>, amount: u64) -> Result<()> {
+    let player_achievement = &mut ctx.accounts.player_achievement;
+    let achievement = &ctx.accounts.achievement;
+
+    if player_achievement.player_account == Pubkey::default() {
+        player_achievement.player_account = ctx.accounts.player_account.key();
+        player_achievement.achievement = achievement.key();
+    }
+
+    let target = achievement.progress_target.unwrap();
+    let clock = Clock::get().unwrap();
+    let was_unlocked = player_achievement.unlocked;
+    player_achievement.add_progress(amount, target, clock.unix_timestamp);
+
+    if !was_unlocked && player_achievement.unlocked {
+        emit!(AchievementUnlocked {
+            achievement: achievement.key(),
+            player_account: player_achievement.player_account,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use crate::{state::MergeApproval, InitiateMerge};
+use crate::{
+    state::{MergeApproval, Merged},
+    InitiateMerge, MergeInitiated,
+};
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<InitiateMerge>, keys: Vec<Pubkey>) -> Result<()> {
@@ -12,6 +15,18 @@ pub fn handler(ctx: Context<InitiateMerge>, keys: Vec<Pubkey>) -> Result<()> {
         .map(MergeApproval::new)
         .collect();
     merge_account.merge_complete = merge_account.approvals.is_empty();
+    merge_account.payer = ctx.accounts.payer.key();
+    merge_account.expires_at = Clock::get()
+        .unwrap()
+        .unix_timestamp
+        .checked_add(Merged::EXPIRY_SECONDS)
+        .unwrap();
 
+    emit!(MergeInitiated {
+        merge_account: merge_account.key(),
+        player_account: *player_key,
+        others: merge_account.approvals.iter().map(|one| one.key).collect(),
+        merge_complete: merge_account.merge_complete,
+    });
     Ok(())
 }
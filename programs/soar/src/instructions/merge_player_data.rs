@@ -0,0 +1,118 @@
+use crate::{state::ScoreEntry, utils};
+use anchor_lang::prelude::*;
+
+pub mod scores {
+    use super::*;
+    use crate::{error::SoarError, MergePlayerScores, PlayerScoresMerged};
+
+    pub fn handler(ctx: Context<MergePlayerScores>) -> Result<()> {
+        let expected = ctx.accounts.leaderboard.top_entries;
+        let provided = ctx.accounts.top_entries.as_ref().map(|t| t.key());
+        require!(expected == provided, SoarError::MissingExpectedAccount);
+
+        let merged_scores = &mut ctx.accounts.merged_player_scores;
+        let player_scores = &mut ctx.accounts.player_scores;
+        let retention = &ctx.accounts.leaderboard.retention;
+        let score_type = ctx.accounts.leaderboard.score_type;
+        let is_ascending = match &ctx.accounts.top_entries {
+            Some(top_entries) => top_entries.load()?.is_ascending(),
+            None => false,
+        };
+
+        player_scores.absorb_aggregates(merged_scores, score_type, is_ascending);
+        player_scores.scores.append(&mut merged_scores.scores);
+        player_scores.scores.sort_by_key(|entry| entry.timestamp);
+        player_scores.apply_retention(retention, score_type, is_ascending);
+
+        if let Some(alloc_count) = player_scores.required_alloc_count(retention) {
+            let size = player_scores.current_size();
+            let to_add = (alloc_count - player_scores.alloc_count as usize)
+                .checked_mul(ScoreEntry::SIZE)
+                .unwrap();
+
+            utils::resize_account(
+                &player_scores.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                size.checked_add(to_add).unwrap(),
+            )?;
+            player_scores.alloc_count = u16::try_from(alloc_count).unwrap();
+        }
+
+        emit!(PlayerScoresMerged {
+            merge_account: ctx.accounts.merge_account.key(),
+            leaderboard: ctx.accounts.leaderboard.key(),
+            player_scores: player_scores.key(),
+            merged_player_scores: merged_scores.key(),
+        });
+        Ok(())
+    }
+}
+
+pub mod achievement {
+    use super::*;
+    use crate::{AchievementUnlocked, MergePlayerAchievement, PlayerAchievementMerged};
+
+    pub fn handler(ctx: Context<MergePlayerAchievement>) -> Result<()> {
+        let player_achievement = &mut ctx.accounts.player_achievement;
+
+        if player_achievement.player_account == Pubkey::default() {
+            player_achievement.player_account = ctx.accounts.player_account.key();
+            player_achievement.achievement = ctx.accounts.achievement.key();
+        }
+        let was_unlocked = player_achievement.unlocked;
+        player_achievement.absorb(&mut ctx.accounts.merged_player_achievement);
+
+        emit!(PlayerAchievementMerged {
+            merge_account: ctx.accounts.merge_account.key(),
+            achievement: ctx.accounts.achievement.key(),
+            player_achievement: player_achievement.key(),
+            merged_player_achievement: ctx.accounts.merged_player_achievement.key(),
+        });
+        if player_achievement.unlocked && !was_unlocked {
+            emit!(AchievementUnlocked {
+                achievement: ctx.accounts.achievement.key(),
+                player_account: player_achievement.player_account,
+                timestamp: player_achievement.timestamp,
+            });
+        }
+        Ok(())
+    }
+}
+
+pub mod top_entries {
+    use super::*;
+    use crate::{
+        state::{LeaderTopEntriesV2, Ranking},
+        MergeTopEntries, TopEntriesMerged,
+    };
+
+    pub fn handler(ctx: Context<MergeTopEntries>) -> Result<()> {
+        let merge_account = &ctx.accounts.merge_account;
+        let leaderboard = &ctx.accounts.leaderboard;
+
+        let mut data = ctx.accounts.top_entries.as_ref().try_borrow_mut_data()?;
+        let (header, scores) = LeaderTopEntriesV2::split_mut(&mut data)?;
+
+        Ranking::new(
+            header.is_ascending(),
+            leaderboard.tie_break,
+            leaderboard.score_type,
+            scores,
+        )
+        .reassign(
+            |key| merge_account.contains(key),
+            ctx.accounts.player_account.key(),
+            leaderboard.allow_multiple_scores,
+            leaderboard.scoring_mode,
+            leaderboard.max_score,
+        );
+
+        emit!(TopEntriesMerged {
+            merge_account: merge_account.key(),
+            leaderboard: leaderboard.key(),
+            player_account: ctx.accounts.player_account.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    state::{Game, LegacyGame},
+    utils, GameMigrated, MigrateGame,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<MigrateGame>) -> Result<()> {
+    let game_info = ctx.accounts.game.to_account_info();
+    let legacy = {
+        let data = game_info.try_borrow_data()?;
+        LegacyGame::try_from_data(&data)?
+    };
+    let auth = legacy.auth.clone();
+
+    let game = Game::from_legacy(legacy);
+    utils::resize_account(
+        &game_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Game::size(game.auth.len()),
+    )?;
+
+    let mut data = game_info.try_borrow_mut_data()?;
+    game.try_serialize(&mut &mut data[..])?;
+
+    emit!(GameMigrated {
+        game: game_info.key(),
+        auth,
+    });
+    Ok(())
+}
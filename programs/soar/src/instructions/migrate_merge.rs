@@ -0,0 +1,30 @@
+use crate::{
+    state::{LegacyMerged, Merged},
+    utils, MergeMigrated, MigrateMerge,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<MigrateMerge>) -> Result<()> {
+    let merge_info = ctx.accounts.merge_account.to_account_info();
+    let legacy = {
+        let data = merge_info.try_borrow_data()?;
+        LegacyMerged::try_from_data(&data)?
+    };
+
+    let merge = Merged::from_legacy(legacy, Clock::get()?.unix_timestamp);
+    utils::resize_account(
+        &merge_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Merged::size(merge.approvals.len()),
+    )?;
+
+    let mut data = merge_info.try_borrow_mut_data()?;
+    merge.try_serialize(&mut &mut data[..])?;
+
+    emit!(MergeMigrated {
+        merge_account: merge_info.key(),
+        expires_at: merge.expires_at,
+    });
+    Ok(())
+}
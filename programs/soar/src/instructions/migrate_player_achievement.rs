@@ -0,0 +1,31 @@
+use crate::{
+    state::{LegacyPlayerAchievement, PlayerAchievement},
+    utils, MigratePlayerAchievement, PlayerAchievementMigrated,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<MigratePlayerAchievement>) -> Result<()> {
+    let player_achievement_info = ctx.accounts.player_achievement.to_account_info();
+    let legacy = {
+        let data = player_achievement_info.try_borrow_data()?;
+        LegacyPlayerAchievement::try_from_data(&data)?
+    };
+
+    let player_achievement = PlayerAchievement::from(legacy);
+    utils::resize_account(
+        &player_achievement_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        PlayerAchievement::SIZE,
+    )?;
+
+    let mut data = player_achievement_info.try_borrow_mut_data()?;
+    player_achievement.try_serialize(&mut &mut data[..])?;
+
+    emit!(PlayerAchievementMigrated {
+        player_achievement: player_achievement_info.key(),
+        player_account: player_achievement.player_account,
+        achievement: player_achievement.achievement,
+    });
+    Ok(())
+}
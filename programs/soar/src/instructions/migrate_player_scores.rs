@@ -0,0 +1,52 @@
+use crate::{
+    error::SoarError,
+    state::{LegacyPlayerScoresList, PlayerScoresList},
+    utils, MigratePlayerScores, PlayerScoresMigrated,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<MigratePlayerScores>) -> Result<()> {
+    let leaderboard = &ctx.accounts.leaderboard;
+    let expected = leaderboard.top_entries;
+    let provided = ctx.accounts.top_entries.as_ref().map(|t| t.key());
+    require!(expected == provided, SoarError::MissingExpectedAccount);
+
+    let player_scores_info = ctx.accounts.player_scores.to_account_info();
+    let legacy = {
+        let data = player_scores_info.try_borrow_data()?;
+        LegacyPlayerScoresList::try_from_data(&data)?
+    };
+    let is_ascending = match &ctx.accounts.top_entries {
+        Some(top_entries) => top_entries.load()?.is_ascending(),
+        None => false,
+    };
+
+    let player_scores = PlayerScoresList::from_legacy(
+        legacy,
+        leaderboard.season,
+        &leaderboard.retention,
+        leaderboard.score_type,
+        is_ascending,
+    );
+    let resized = utils::resize_account_step(
+        &player_scores_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        player_scores.current_size(),
+    )?;
+    if !resized {
+        msg!("Grew account to {} bytes", player_scores_info.data_len());
+        return Ok(());
+    }
+
+    let mut data = player_scores_info.try_borrow_mut_data()?;
+    player_scores.try_serialize(&mut &mut data[..])?;
+
+    emit!(PlayerScoresMigrated {
+        leaderboard: leaderboard.key(),
+        player_account: player_scores.player_account,
+        player_scores: player_scores_info.key(),
+        submission_count: player_scores.submission_count,
+    });
+    Ok(())
+}
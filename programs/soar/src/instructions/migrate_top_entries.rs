@@ -0,0 +1,54 @@
+use crate::{
+    state::{LeaderTopEntries, LeaderTopEntriesV2, Ranking},
+    utils, MigrateTopEntries, TopEntriesMigrated,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+pub fn handler(ctx: Context<MigrateTopEntries>) -> Result<()> {
+    let top_entries_info = ctx.accounts.top_entries.to_account_info();
+    let legacy = {
+        let data = top_entries_info.try_borrow_data()?;
+        LeaderTopEntries::try_deserialize(&mut &data[..])?
+    };
+
+    // Legacy entries are still readable while the account grows over several calls.
+    let capacity = legacy.top_scores.len();
+    let resized = utils::resize_account_step(
+        &top_entries_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        LeaderTopEntriesV2::size(capacity),
+    )?;
+    if !resized {
+        msg!("Grew account to {} bytes", top_entries_info.data_len());
+        return Ok(());
+    }
+
+    let mut data = top_entries_info.try_borrow_mut_data()?;
+    data[..8].copy_from_slice(&LeaderTopEntriesV2::discriminator());
+    let (header, scores) = LeaderTopEntriesV2::split_mut(&mut data)?;
+    *header = LeaderTopEntriesV2::new(
+        ctx.accounts.leaderboard.key(),
+        capacity,
+        legacy.is_ascending,
+    );
+    for (score, legacy_score) in scores.iter_mut().zip(legacy.top_scores) {
+        *score = legacy_score.into();
+    }
+
+    // Re-sort under the current ranking rules, which place unused slots last.
+    Ranking::new(
+        legacy.is_ascending,
+        ctx.accounts.leaderboard.tie_break,
+        ctx.accounts.leaderboard.score_type,
+        scores,
+    )
+    .sort();
+
+    emit!(TopEntriesMigrated {
+        leaderboard: ctx.accounts.leaderboard.key(),
+        top_entries: top_entries_info.key(),
+        capacity: u32::try_from(capacity).unwrap(),
+    });
+    Ok(())
+}
@@ -2,13 +2,33 @@
 
 pub mod add_achievement;
 pub mod add_leaderboard;
+pub mod add_rank_reward;
 pub mod add_reward;
 pub mod approve_merge;
+pub mod approve_proposal;
+pub mod cancel_merge;
+pub mod claim_rank_reward;
 pub mod claim_reward;
+pub mod close_accounts;
+pub mod close_merge;
+pub mod close_season;
 pub mod create_game;
 pub mod create_player;
+pub mod create_proposal;
+pub mod execute_proposal;
+pub mod increment_achievement_progress;
 pub mod initiate_merge;
+pub mod merge_player_data;
+pub mod migrate_game;
+pub mod migrate_merge;
+pub mod migrate_player_achievement;
+pub mod migrate_player_scores;
+pub mod migrate_top_entries;
 pub mod register_player;
+pub mod reject_merge;
+pub mod resize_top_entries;
+pub mod start_season;
+pub mod submit_attested_score;
 pub mod submit_score;
 pub mod unlock_player_achievement;
 pub mod update_achievement;
@@ -19,12 +39,32 @@ pub mod verify_reward;
 
 pub use add_achievement::*;
 pub use add_leaderboard::*;
+pub use add_rank_reward::*;
 pub use add_reward::*;
 pub use approve_merge::*;
+pub use approve_proposal::*;
+pub use cancel_merge::*;
+pub use claim_rank_reward::*;
 pub use claim_reward::*;
+pub use close_accounts::*;
+pub use close_merge::*;
+pub use close_season::*;
 pub use create_game::*;
 pub use create_player::*;
+pub use create_proposal::*;
+pub use execute_proposal::*;
+pub use increment_achievement_progress::*;
+pub use merge_player_data::*;
+pub use migrate_game::*;
+pub use migrate_merge::*;
+pub use migrate_player_achievement::*;
+pub use migrate_player_scores::*;
+pub use migrate_top_entries::*;
 pub use register_player::*;
+pub use reject_merge::*;
+pub use resize_top_entries::*;
+pub use start_season::*;
+pub use submit_attested_score::*;
 pub use submit_score::*;
 pub use unlock_player_achievement::*;
 pub use update_achievement::*;
@@ -1,13 +1,19 @@
-use crate::{state::PlayerScoresList, RegisterPlayer};
+use crate::{state::PlayerScoresList, PlayerRegistered, RegisterPlayer};
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<RegisterPlayer>) -> Result<()> {
     let player_info = ctx.accounts.player_account.key();
-    let leaderboard = ctx.accounts.leaderboard.key();
+    let leaderboard = &ctx.accounts.leaderboard;
 
     let new_list = &mut ctx.accounts.new_list;
-    let obj = PlayerScoresList::new(player_info, leaderboard);
+    let obj = PlayerScoresList::new(player_info, leaderboard.key(), &leaderboard.retention);
 
     new_list.set_inner(obj);
+
+    emit!(PlayerRegistered {
+        leaderboard: leaderboard.key(),
+        player_account: player_info,
+        player_scores: new_list.key(),
+    });
     Ok(())
 }
@@ -0,0 +1,10 @@
+use crate::{MergeRejected, RejectMerge};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<RejectMerge>) -> Result<()> {
+    emit!(MergeRejected {
+        merge_account: ctx.accounts.merge_account.key(),
+        player_account: ctx.accounts.player_account.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::{
+    state::{LeaderTopEntriesV2, Ranking},
+    utils, ResizeTopEntries, TopEntriesResized,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<ResizeTopEntries>, new_len: u16, drop_entries: bool) -> Result<()> {
+    let max_score = ctx.accounts.leaderboard.max_score;
+    let top_entries_info = ctx.accounts.top_entries.to_account_info();
+    let payer_info = ctx.accounts.payer.to_account_info();
+
+    let (current_len, is_ascending) = {
+        let data = top_entries_info.try_borrow_data()?;
+        let (header, scores) = LeaderTopEntriesV2::split(&data)?;
+        LeaderTopEntriesV2::check_resize(scores, new_len as usize, drop_entries)?;
+        (header.capacity as usize, header.is_ascending())
+    };
+    let new_len = new_len as usize;
+    let current_size = LeaderTopEntriesV2::size(current_len);
+    let new_size = LeaderTopEntriesV2::size(new_len);
+
+    if new_size > current_size {
+        utils::resize_account(
+            &top_entries_info,
+            &payer_info,
+            &ctx.accounts.system_program.to_account_info(),
+            new_size,
+        )?;
+    }
+
+    {
+        let mut header = ctx.accounts.top_entries.load_mut()?;
+        header.capacity = u32::try_from(new_len).unwrap();
+    }
+
+    if new_len > current_len {
+        let mut data = top_entries_info.try_borrow_mut_data()?;
+        let (_, scores) = LeaderTopEntriesV2::split_mut(&mut data)?;
+        let leaderboard = &ctx.accounts.leaderboard;
+        let ranking = Ranking::new(
+            is_ascending,
+            leaderboard.tie_break,
+            leaderboard.score_type,
+            scores,
+        );
+        let placeholder = ranking.placeholder(max_score);
+        ranking.scores[current_len..].fill(placeholder);
+    } else if new_size < current_size {
+        utils::shrink_account(&top_entries_info, &payer_info, new_size)?;
+    }
+
+    emit!(TopEntriesResized {
+        leaderboard: ctx.accounts.leaderboard.key(),
+        top_entries: top_entries_info.key(),
+        previous_len: u32::try_from(current_len).unwrap(),
+        new_len: u32::try_from(new_len).unwrap(),
+    });
+    Ok(())
+}
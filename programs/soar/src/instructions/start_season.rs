@@ -0,0 +1,41 @@
+use crate::{
+    error::SoarError,
+    state::{LeaderTopEntriesV2, Ranking},
+    SeasonStarted, StartSeason,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<StartSeason>, season_end: Option<i64>) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    require!(leaderboard.is_frozen, SoarError::SeasonStillActive);
+
+    let clock = Clock::get().unwrap();
+    if let Some(end) = season_end {
+        require!(end > clock.unix_timestamp, SoarError::InvalidSeasonEnd);
+    }
+
+    if let Some(top_entries) = &ctx.accounts.top_entries {
+        let mut data = top_entries.as_ref().try_borrow_mut_data()?;
+        let (header, scores) = LeaderTopEntriesV2::split_mut(&mut data)?;
+        Ranking::new(
+            header.is_ascending(),
+            leaderboard.tie_break,
+            leaderboard.score_type,
+            scores,
+        )
+        .clear(leaderboard.max_score);
+    }
+
+    leaderboard.season = leaderboard.season.checked_add(1).unwrap();
+    leaderboard.season_start = clock.unix_timestamp;
+    leaderboard.season_end = season_end;
+    leaderboard.is_frozen = false;
+
+    emit!(SeasonStarted {
+        leaderboard: leaderboard.key(),
+        season: leaderboard.season,
+        start: leaderboard.season_start,
+        end: season_end,
+    });
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use crate::{
+    error::SoarError,
+    instructions::submit_score::{self, ScoreAccounts},
+    state::{Game, GameAuthority, ScoreAttestation, ScoreEntry},
+    utils, SubmitAttestedScore,
+};
+use anchor_lang::prelude::*;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SubmitAttestedScore<'info>>,
+    score: u64,
+    secondary_score: Option<u64>,
+    context: Option<[u8; 32]>,
+    nonce: u64,
+    expiry: i64,
+) -> Result<()> {
+    let context = context.unwrap_or_default();
+    let accounts = ctx.accounts;
+
+    let (attester, message) = utils::read_ed25519_verification(&accounts.instructions)?;
+    let attestation = ScoreAttestation {
+        player_account: accounts.player_account.key(),
+        leaderboard: accounts.leaderboard.key(),
+        score,
+        secondary_score,
+        context,
+        nonce,
+        expiry,
+    };
+
+    let clock = Clock::get().unwrap();
+    check_attestation(
+        &accounts.game,
+        &attester,
+        &message,
+        &attestation,
+        clock.unix_timestamp,
+        accounts.player_scores.last_nonce,
+    )?;
+    accounts.player_scores.last_nonce = nonce;
+
+    let score_accounts = ScoreAccounts {
+        payer: accounts.payer.to_account_info(),
+        player_account: &accounts.player_account,
+        game: &accounts.game,
+        leaderboard: &accounts.leaderboard,
+        player_scores: &mut accounts.player_scores,
+        top_entries: accounts.top_entries.as_ref(),
+        system_program: accounts.system_program.to_account_info(),
+    };
+
+    let entry = ScoreEntry::with_details(score, clock.unix_timestamp, secondary_score, context);
+    submit_score::record_score(score_accounts, ctx.remaining_accounts, entry)
+}
+
+/// Check that `message`, signed by `attester`, is `attestation` and that it can still be
+/// submitted at `now` for a player whose last used nonce is `last_nonce`.
+fn check_attestation(
+    game: &Game,
+    attester: &Pubkey,
+    message: &[u8],
+    attestation: &ScoreAttestation,
+    now: i64,
+    last_nonce: u64,
+) -> Result<()> {
+    require!(
+        game.check_role(attester, GameAuthority::ATTESTER),
+        SoarError::InvalidAuthority
+    );
+    require!(
+        attestation.try_to_vec()? == message,
+        SoarError::InvalidAttestation
+    );
+    require!(now <= attestation.expiry, SoarError::AttestationExpired);
+    require!(
+        attestation.nonce > last_nonce,
+        SoarError::AttestationReplayed
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::{
+        ed25519_program,
+        sysvar::instructions::{
+            self as ix_sysvar, construct_instructions_data, BorrowedAccountMeta,
+            BorrowedInstruction,
+        },
+    };
+
+    /// Ed25519 instruction data holding one signature, with its offsets pointing within
+    /// the instruction as `ix_index` says.
+    fn ed25519_data(attester: &Pubkey, message: &[u8], ix_index: u16) -> Vec<u8> {
+        let (pubkey_offset, signature_offset, message_offset) = (16u16, 48u16, 112u16);
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset,
+            ix_index,
+            pubkey_offset,
+            ix_index,
+            message_offset,
+            message.len() as u16,
+            ix_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(attester.as_ref());
+        data.extend_from_slice(&[7; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    /// Read the verification preceding a placeholder SOAR instruction.
+    fn read(ed25519_program_id: &Pubkey, ed25519_data: &[u8]) -> Result<(Pubkey, Vec<u8>)> {
+        let instructions = [
+            BorrowedInstruction {
+                program_id: ed25519_program_id,
+                accounts: Vec::<BorrowedAccountMeta>::new(),
+                data: ed25519_data,
+            },
+            BorrowedInstruction {
+                program_id: &crate::ID,
+                accounts: Vec::new(),
+                data: &[],
+            },
+        ];
+        let mut data = construct_instructions_data(&instructions);
+        ix_sysvar::store_current_index(&mut data, 1);
+
+        let (key, owner, mut lamports) = (ix_sysvar::ID, Pubkey::default(), 0);
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        utils::read_ed25519_verification(&info)
+    }
+
+    fn setup() -> (Game, Pubkey, ScoreAttestation) {
+        let attester = Pubkey::new_unique();
+        let game = Game {
+            auth: vec![GameAuthority {
+                key: attester,
+                roles: GameAuthority::ATTESTER,
+            }],
+            ..Game::default()
+        };
+        let attestation = ScoreAttestation {
+            score: 10,
+            nonce: 5,
+            expiry: 100,
+            ..ScoreAttestation::default()
+        };
+        (game, attester, attestation)
+    }
+
+    #[test]
+    fn reads_a_verification_of_its_own_data() {
+        let (game, attester, attestation) = setup();
+        let message = attestation.try_to_vec().unwrap();
+        let (signer, read) = read(
+            &ed25519_program::ID,
+            &ed25519_data(&attester, &message, u16::MAX),
+        )
+        .unwrap();
+        assert_eq!((signer, &read), (attester, &message));
+        assert!(check_attestation(&game, &signer, &read, &attestation, 100, 4).is_ok());
+    }
+
+    #[test]
+    fn rejects_offsets_into_other_instructions() {
+        let (_, attester, attestation) = setup();
+        let message = attestation.try_to_vec().unwrap();
+        // Pointing at another instruction would verify data other than what's read.
+        for index in [0, 1] {
+            assert!(read(
+                &ed25519_program::ID,
+                &ed25519_data(&attester, &message, index)
+            )
+            .is_err());
+        }
+
+        let mut forged = ed25519_data(&attester, &message, u16::MAX);
+        forged[10..12].copy_from_slice(&u16::MAX.to_le_bytes()); // message offset
+        assert!(read(&ed25519_program::ID, &forged).is_err());
+
+        let other_program = Pubkey::new_unique();
+        let data = ed25519_data(&attester, &message, u16::MAX);
+        assert!(read(&other_program, &data).is_err());
+    }
+
+    #[test]
+    fn rejects_signers_without_the_attester_role() {
+        let (game, _, attestation) = setup();
+        let message = attestation.try_to_vec().unwrap();
+        let error = check_attestation(&game, &Pubkey::new_unique(), &message, &attestation, 0, 0);
+        assert_eq!(error.unwrap_err(), SoarError::InvalidAuthority.into());
+    }
+
+    #[test]
+    fn rejects_tampered_expired_and_replayed_attestations() {
+        let (game, attester, attestation) = setup();
+        let message = attestation.try_to_vec().unwrap();
+        let check = |attestation: &ScoreAttestation, now, last_nonce| {
+            check_attestation(&game, &attester, &message, attestation, now, last_nonce).unwrap_err()
+        };
+
+        let tampered = ScoreAttestation {
+            score: 11,
+            ..attestation.clone()
+        };
+        assert_eq!(check(&tampered, 0, 0), SoarError::InvalidAttestation.into());
+        assert_eq!(
+            check(&attestation, 101, 0),
+            SoarError::AttestationExpired.into()
+        );
+        assert_eq!(
+            check(&attestation, 0, 5),
+            SoarError::AttestationReplayed.into()
+        );
+    }
+}
@@ -1,44 +1,120 @@
 use crate::{
     error::SoarError,
-    state::{LeaderBoardScore, PlayerScoresList, ScoreEntry},
-    utils, SubmitScore,
+    seeds,
+    state::{
+        Achievement, Game, LeaderBoard, LeaderBoardScore, LeaderTopEntriesV2, Player,
+        PlayerAchievement, PlayerScoresList, Ranking, ScoreEntry,
+    },
+    utils, AchievementUnlocked, ScoreSubmitted, SubmitScore,
 };
 use anchor_lang::prelude::*;
 
-pub fn handler(ctx: Context<SubmitScore>, score: u64) -> Result<()> {
-    let player_scores = &mut ctx.accounts.player_scores;
-    let leaderboard = &ctx.accounts.leaderboard;
+/// Accounts involved in recording a score, shared by every score submission instruction.
+pub struct ScoreAccounts<'a, 'info> {
+    pub payer: AccountInfo<'info>,
+    pub player_account: &'a Account<'info, Player>,
+    pub game: &'a Account<'info, Game>,
+    pub leaderboard: &'a Account<'info, LeaderBoard>,
+    pub player_scores: &'a mut Account<'info, PlayerScoresList>,
+    pub top_entries: Option<&'a AccountLoader<'info, LeaderTopEntriesV2>>,
+    pub system_program: AccountInfo<'info>,
+}
+
+pub fn handler<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, SubmitScore<'info>>,
+    score: u64,
+    secondary_score: Option<u64>,
+    context: Option<[u8; 32]>,
+) -> Result<()> {
+    let accounts = &mut ctx.accounts;
+    let score_accounts = ScoreAccounts {
+        payer: accounts.payer.to_account_info(),
+        player_account: &accounts.player_account,
+        game: &accounts.game,
+        leaderboard: &accounts.leaderboard,
+        player_scores: &mut accounts.player_scores,
+        top_entries: accounts.top_entries.as_ref(),
+        system_program: accounts.system_program.to_account_info(),
+    };
+
+    let entry = ScoreEntry::with_details(
+        score,
+        Clock::get()?.unix_timestamp,
+        secondary_score,
+        context.unwrap_or_default(),
+    );
+    record_score(score_accounts, ctx.remaining_accounts, entry)
+}
+
+/// Validate and record a timestamped `entry` for a player, updating the leaderboard's top
+/// entries and unlocking any achievements passed in `remaining_accounts`.
+pub fn record_score<'info>(
+    accounts: ScoreAccounts<'_, 'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    entry: ScoreEntry,
+) -> Result<()> {
+    let leaderboard = accounts.leaderboard;
+    let score_type = leaderboard.score_type;
+    let player_scores = &mut *accounts.player_scores;
 
-    if score < leaderboard.min_score || score > leaderboard.max_score {
+    // Rankings and best scores follow the top entries' order, so they can't be left out.
+    let expected = leaderboard.top_entries;
+    let provided = accounts.top_entries.map(|t| t.key());
+    require!(expected == provided, SoarError::MissingExpectedAccount);
+
+    if !leaderboard.is_within_bounds(entry.score) {
         return Err(SoarError::ScoreNotWithinBounds.into());
     }
 
-    let clock = Clock::get().unwrap();
-    let entry = ScoreEntry::new(score, clock.unix_timestamp);
+    require!(
+        leaderboard.is_season_active(entry.timestamp),
+        SoarError::SeasonEnded
+    );
+    require!(
+        leaderboard.is_open(entry.timestamp),
+        SoarError::SubmissionWindowClosed
+    );
+
+    let rate_limit = &leaderboard.rate_limit;
+    require!(
+        player_scores.is_past_min_interval(rate_limit, entry.timestamp),
+        SoarError::SubmissionTooSoon
+    );
+    require!(
+        player_scores.has_attempts_left(rate_limit, entry.timestamp),
+        SoarError::AttemptLimitReached
+    );
+    player_scores.count_attempt(rate_limit, entry.timestamp);
+    player_scores.roll_season(leaderboard.season);
+
+    let is_ascending = match accounts.top_entries {
+        Some(top_entries) => top_entries.load()?.is_ascending(),
+        None => false,
+    };
+    player_scores.record(&entry, score_type, is_ascending);
+    player_scores.scores.push(entry);
+    player_scores.apply_retention(&leaderboard.retention, score_type, is_ascending);
 
-    let count = player_scores.scores.len();
-    if count == player_scores.alloc_count as usize {
-        let window = PlayerScoresList::REALLOC_WINDOW;
+    if let Some(alloc_count) = player_scores.required_alloc_count(&leaderboard.retention) {
         msg!(
             "count: {}. Reallocating space for {} entries",
-            count,
-            window
+            player_scores.scores.len(),
+            alloc_count
         );
 
         let size = player_scores.current_size();
-        let to_add = window.checked_mul(ScoreEntry::SIZE).unwrap();
+        let to_add = (alloc_count - player_scores.alloc_count as usize)
+            .checked_mul(ScoreEntry::SIZE)
+            .unwrap();
         let new_size = size.checked_add(to_add).unwrap();
 
         utils::resize_account(
             &player_scores.to_account_info(),
-            &ctx.accounts.payer.to_account_info(),
-            &ctx.accounts.system_program.to_account_info(),
+            &accounts.payer,
+            &accounts.system_program,
             new_size,
         )?;
-        player_scores.alloc_count = player_scores
-            .alloc_count
-            .checked_add(PlayerScoresList::REALLOC_WINDOW as u16)
-            .unwrap();
+        player_scores.alloc_count = u16::try_from(alloc_count).unwrap();
 
         let new_space = player_scores.to_account_info().data_len();
         msg!(
@@ -48,45 +124,119 @@ pub fn handler(ctx: Context<SubmitScore>, score: u64) -> Result<()> {
         );
     }
 
-    player_scores.scores.push(entry);
-    let player_key = ctx.accounts.player_account.key();
-
-    if let Some(top_entries) = &mut ctx.accounts.top_entries {
-        require_keys_eq!(leaderboard.top_entries.unwrap(), top_entries.key());
-        let mut score_entry = LeaderBoardScore::new(player_key, entry);
-        let is_ascending = top_entries.is_ascending;
-
-        let last_index = top_entries.top_scores.len() - 1;
-        let mut index = last_index;
-
-        let scores = &mut top_entries.top_scores;
-        if is_ascending && entry.score < scores[last_index].entry.score {
-            if !ctx.accounts.leaderboard.allow_multiple_scores {
-                if let Some(idx) = scores.iter().position(|s| s.player == player_key) {
-                    index = idx;
-                    score_entry = if score_entry.entry.score < scores[idx].entry.score {
-                        score_entry
-                    } else {
-                        scores[idx].clone()
-                    };
-                }
-            }
-            scores[index] = score_entry;
-            scores.sort_by(|a, b| a.entry.score.cmp(&b.entry.score));
-        } else if !is_ascending && entry.score > scores[last_index].entry.score {
-            if !ctx.accounts.leaderboard.allow_multiple_scores {
-                if let Some(idx) = scores.iter().position(|s| s.player == player_key) {
-                    index = idx;
-                    score_entry = if score_entry.entry.score > scores[idx].entry.score {
-                        score_entry
-                    } else {
-                        scores[idx].clone()
-                    };
-                }
-            }
-            scores[index] = score_entry;
-            scores.sort_by(|a, b| b.entry.score.cmp(&a.entry.score));
+    let submissions = player_scores.submission_count;
+    let player_key = accounts.player_account.key();
+
+    let mut rank = None;
+    if let Some(top_entries) = accounts.top_entries {
+        let scoring_mode = leaderboard.scoring_mode;
+        let ranked = ScoreEntry {
+            score: scoring_mode.ranked_value(&entry, player_scores, score_type),
+            ..entry
+        };
+
+        let mut data = top_entries.as_ref().try_borrow_mut_data()?;
+        let (_, scores) = LeaderTopEntriesV2::split_mut(&mut data)?;
+        rank = Ranking::new(is_ascending, leaderboard.tie_break, score_type, scores)
+            .insert(
+                LeaderBoardScore::new(player_key, ranked),
+                leaderboard.allow_multiple_scores,
+                scoring_mode.replaces_previous(),
+            )
+            .map(|index| index as u32 + 1);
+    }
+
+    emit!(ScoreSubmitted {
+        leaderboard: leaderboard.key(),
+        player_account: player_key,
+        season: leaderboard.season,
+        score: entry.score,
+        secondary_score: entry.secondary_score(),
+        context: entry.context,
+        timestamp: entry.timestamp,
+        rank,
+    });
+
+    unlock_achievements(&accounts, remaining_accounts, &entry, submissions)?;
+
+    Ok(())
+}
+
+/// Create [PlayerAchievement] accounts for every `(achievement, player_achievement)` pair
+/// in `remaining_accounts` whose [Achievement]'s unlock rule is satisfied by `entry`.
+///
+/// Pairs for achievements that are already unlocked or whose rule isn't satisfied are skipped.
+fn unlock_achievements<'info>(
+    accounts: &ScoreAccounts<'_, 'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    entry: &ScoreEntry,
+    submissions: u64,
+) -> Result<()> {
+    let pairs = remaining_accounts.chunks_exact(2);
+    require!(
+        pairs.remainder().is_empty(),
+        SoarError::InvalidRemainingAccounts
+    );
+
+    let game_key = accounts.game.key();
+    let leaderboard_key = accounts.leaderboard.key();
+    let score_type = accounts.leaderboard.score_type;
+    let player_key = accounts.player_account.key();
+
+    for pair in pairs {
+        let achievement_info = &pair[0];
+        let player_achievement_info = &pair[1];
+
+        let achievement = Account::<Achievement>::try_from(achievement_info)?;
+        require_keys_eq!(
+            achievement.game,
+            game_key,
+            SoarError::InvalidRemainingAccounts
+        );
+
+        let (expected, bump) = Pubkey::find_program_address(
+            &[
+                seeds::PLAYER_ACHIEVEMENT,
+                player_key.as_ref(),
+                achievement_info.key.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            expected,
+            player_achievement_info.key(),
+            SoarError::InvalidRemainingAccounts
+        );
+
+        let satisfied = matches!(
+            achievement.unlock_rule,
+            Some(rule) if rule.is_satisfied(&leaderboard_key, score_type, entry, submissions)
+        );
+        if !satisfied || player_achievement_info.owner == &crate::ID {
+            continue;
         }
+
+        utils::create_pda_account(
+            &accounts.payer,
+            player_achievement_info,
+            &accounts.system_program,
+            PlayerAchievement::SIZE,
+            &[
+                seeds::PLAYER_ACHIEVEMENT,
+                player_key.as_ref(),
+                achievement_info.key.as_ref(),
+                &[bump],
+            ],
+        )?;
+
+        let unlocked = PlayerAchievement::new(player_key, achievement_info.key(), entry.timestamp);
+        let mut data = player_achievement_info.try_borrow_mut_data()?;
+        unlocked.try_serialize(&mut &mut data[..])?;
+        emit!(AchievementUnlocked {
+            achievement: achievement_info.key(),
+            player_account: player_key,
+            timestamp: entry.timestamp,
+        });
     }
 
     Ok(())
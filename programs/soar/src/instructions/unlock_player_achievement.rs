@@ -1,4 +1,4 @@
-use crate::{state::PlayerAchievement, UnlockPlayerAchievement};
+use crate::{state::PlayerAchievement, AchievementUnlocked, UnlockPlayerAchievement};
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<UnlockPlayerAchievement>) -> Result<()> {
@@ -12,5 +12,10 @@ pub fn handler(ctx: Context<UnlockPlayerAchievement>) -> Result<()> {
     );
 
     pa_account.set_inner(obj);
+    emit!(AchievementUnlocked {
+        achievement: ctx.accounts.achievement.key(),
+        player_account: ctx.accounts.player_account.key(),
+        timestamp: clock.unix_timestamp,
+    });
     Ok(())
 }
@@ -1,5 +1,5 @@
-use crate::state::FieldsCheck;
-use crate::UpdateAchievement;
+use crate::state::{AchievementRule, FieldsCheck};
+use crate::{AchievementUpdated, UpdateAchievement};
 use anchor_lang::prelude::*;
 
 pub fn handler(
@@ -7,19 +7,30 @@ pub fn handler(
     new_title: Option<String>,
     new_description: Option<String>,
     new_meta: Option<Pubkey>,
+    new_unlock_rule: Option<Option<AchievementRule>>,
 ) -> Result<()> {
     let achievement = &mut ctx.accounts.achievement;
 
-    if let Some(title) = new_title {
-        achievement.title = title;
+    if let Some(title) = &new_title {
+        achievement.title = title.clone();
     }
-    if let Some(description) = new_description {
-        achievement.description = description;
+    if let Some(description) = &new_description {
+        achievement.description = description.clone();
     }
     if let Some(meta) = new_meta {
         achievement.nft_meta = meta;
     }
+    if let Some(unlock_rule) = new_unlock_rule {
+        achievement.unlock_rule = unlock_rule;
+    }
 
     achievement.check()?;
+    emit!(AchievementUpdated {
+        achievement: achievement.key(),
+        new_title,
+        new_description,
+        new_meta,
+        new_unlock_rule,
+    });
     Ok(())
 }
@@ -1,40 +1,74 @@
 use crate::{
-    state::{FieldsCheck, Game, GameAttributes},
-    utils, UpdateGame,
+    error::SoarError,
+    state::{FieldsCheck, Game, GameAttributes, GameAuthority},
+    utils, GameUpdated, UpdateGame,
 };
 use anchor_lang::prelude::*;
 
 pub fn handler(
     ctx: Context<UpdateGame>,
     new_attributes: Option<GameAttributes>,
-    new_auth: Option<Vec<Pubkey>>,
+    new_auth: Option<Vec<GameAuthority>>,
 ) -> Result<()> {
     let game_account = &mut ctx.accounts.game;
 
-    if let Some(attr) = new_attributes {
+    if let Some(attr) = &new_attributes {
         attr.check()?;
-        game_account.set_attributes(attr);
+        game_account.set_attributes(attr.clone());
     }
 
-    if let Some(new_auth) = new_auth {
-        let initial_auth_len = game_account.auth.len();
-        let prev_size = Game::size(initial_auth_len);
-
-        let new_size = prev_size
-            .checked_sub(initial_auth_len * 32)
-            .unwrap()
-            .checked_add(new_auth.len() * 32)
-            .unwrap();
-
-        utils::resize_account(
-            &game_account.to_account_info(),
+    if let Some(new_auth) = &new_auth {
+        require!(
+            !game_account.requires_proposal(),
+            SoarError::ProposalRequired
+        );
+        set_auth(
+            game_account,
+            new_auth.clone(),
             &ctx.accounts.payer.to_account_info(),
             &ctx.accounts.system_program.to_account_info(),
-            new_size,
         )?;
-
-        game_account.auth = new_auth;
     };
 
+    emit!(GameUpdated {
+        game: game_account.key(),
+        new_attributes,
+        new_auth,
+        new_threshold: None,
+    });
+    Ok(())
+}
+
+/// Replace a game's authorities, resizing the account as needed.
+pub fn set_auth<'info>(
+    game_account: &mut Account<'info, Game>,
+    new_auth: Vec<GameAuthority>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let initial_auth_len = game_account.auth.len();
+    let prev_size = Game::size(initial_auth_len);
+
+    let new_size = prev_size
+        .checked_sub(initial_auth_len * GameAuthority::SIZE)
+        .unwrap()
+        .checked_add(new_auth.len() * GameAuthority::SIZE)
+        .unwrap();
+
+    utils::resize_account(
+        &game_account.to_account_info(),
+        payer,
+        system_program,
+        new_size,
+    )?;
+
+    game_account.auth = new_auth;
+
+    require!(game_account.admin_count() > 0, SoarError::NoAdminAuthority);
+    require!(
+        usize::from(game_account.threshold) <= game_account.admin_count(),
+        SoarError::InvalidThreshold
+    );
+
     Ok(())
 }
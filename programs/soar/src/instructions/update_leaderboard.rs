@@ -1,40 +1,73 @@
-use crate::state::FieldsCheck;
-use crate::UpdateLeaderBoard;
+use crate::state::{FieldsCheck, LeaderTopEntriesV2, Ranking, UpdateLeaderBoardInput};
+use crate::{error::SoarError, LeaderBoardUpdated, UpdateLeaderBoard};
 use anchor_lang::prelude::*;
 
-pub fn handler(
-    ctx: Context<UpdateLeaderBoard>,
-    new_description: Option<String>,
-    new_nft_meta: Option<Pubkey>,
-    new_min_score: Option<u64>,
-    new_max_score: Option<u64>,
-    new_is_ascending: Option<bool>,
-    new_allow_multiple_scores: Option<bool>,
-) -> Result<()> {
+pub fn handler(ctx: Context<UpdateLeaderBoard>, input: UpdateLeaderBoardInput) -> Result<()> {
     let leaderboard = &mut ctx.accounts.leaderboard;
+    let reorders = input.new_is_ascending.is_some() || input.new_tie_break.is_some();
 
-    if let Some(description) = new_description {
+    // A closed season's ranks are final until the next one starts.
+    require!(!(reorders && leaderboard.is_frozen), SoarError::SeasonEnded);
+    let changes = input.clone();
+
+    if let Some(description) = input.new_description {
         leaderboard.description = description;
     }
-    if let Some(nft_meta) = new_nft_meta {
+    if let Some(nft_meta) = input.new_nft_meta {
         leaderboard.nft_meta = nft_meta;
     }
-    if let Some(max_score) = new_max_score {
+    if let Some(max_score) = input.new_max_score {
         leaderboard.max_score = max_score;
     }
-    if let Some(min_score) = new_min_score {
+    if let Some(min_score) = input.new_min_score {
         leaderboard.min_score = min_score;
     }
-    if let Some(is_ascending) = new_is_ascending {
-        let top_entries = &mut ctx.accounts.top_entries;
-        if let Some(top_entries) = top_entries {
-            top_entries.is_ascending = is_ascending;
-        }
-    }
-    if let Some(allow_multiple_scores) = new_allow_multiple_scores {
+    if let Some(allow_multiple_scores) = input.new_allow_multiple_scores {
         leaderboard.allow_multiple_scores = allow_multiple_scores;
     }
+    if let Some(opens_at) = input.new_opens_at {
+        leaderboard.opens_at = opens_at;
+    }
+    if let Some(closes_at) = input.new_closes_at {
+        leaderboard.closes_at = closes_at;
+    }
+    if let Some(retention) = input.new_retention {
+        leaderboard.retention = retention;
+    }
+    if let Some(scoring_mode) = input.new_scoring_mode {
+        leaderboard.scoring_mode = scoring_mode;
+    }
+    if let Some(tie_break) = input.new_tie_break {
+        leaderboard.tie_break = tie_break;
+    }
+    if let Some(rate_limit) = input.new_rate_limit {
+        leaderboard.rate_limit = rate_limit;
+    }
     leaderboard.check()?;
 
+    if reorders {
+        require!(
+            leaderboard.top_entries.is_none() || ctx.accounts.top_entries.is_some(),
+            SoarError::MissingExpectedAccount
+        );
+        if let Some(top_entries) = &ctx.accounts.top_entries {
+            let mut data = top_entries.as_ref().try_borrow_mut_data()?;
+            let (header, scores) = LeaderTopEntriesV2::split_mut(&mut data)?;
+            let is_ascending = input.new_is_ascending.unwrap_or(header.is_ascending());
+            header.set_is_ascending(is_ascending);
+            Ranking::new(
+                is_ascending,
+                leaderboard.tie_break,
+                leaderboard.score_type,
+                scores,
+            )
+            .sort();
+        }
+    }
+
+    emit!(LeaderBoardUpdated {
+        leaderboard: leaderboard.key(),
+        changes,
+    });
     Ok(())
 }
@@ -1,5 +1,5 @@
 use crate::state::FieldsCheck;
-use crate::UpdatePlayer;
+use crate::{PlayerUpdated, UpdatePlayer};
 use anchor_lang::prelude::*;
 
 pub fn handler(
@@ -9,13 +9,19 @@ pub fn handler(
 ) -> Result<()> {
     let player_account = &mut ctx.accounts.player_account;
 
-    if let Some(name) = username {
-        player_account.username = name;
+    if let Some(name) = &username {
+        player_account.username = name.clone();
     }
     if let Some(meta) = nft_metadata {
         player_account.nft_meta = meta;
     }
 
     player_account.check()?;
+
+    emit!(PlayerUpdated {
+        player_account: player_account.key(),
+        new_username: username,
+        new_nft_meta: nft_metadata,
+    });
     Ok(())
 }
@@ -1,4 +1,4 @@
-use crate::{seeds, utils, VerifyNftReward};
+use crate::{seeds, utils, NftRewardVerified, VerifyNftReward};
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<VerifyNftReward>) -> Result<()> {
@@ -30,5 +30,9 @@ pub fn handler(ctx: Context<VerifyNftReward>) -> Result<()> {
         Some(&[&achievement_seeds[..]]),
     )?;
 
+    emit!(NftRewardVerified {
+        achievement: achievement_account.key(),
+        mint: mint.key(),
+    });
     Ok(())
 }
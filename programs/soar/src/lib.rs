@@ -10,13 +10,19 @@ use solana_security_txt::security_txt;
 declare_id!("SoarNNzwQHMwcfdkdLc6kvbkoMSxcHy89gTHrjhJYkk");
 
 mod error;
+mod events;
 mod instructions;
 mod seeds;
 mod state;
 mod utils;
 
-use error::SoarError;
+pub use error::SoarError;
+pub use events::*;
 use instructions::*;
+pub use seeds::{
+    ACHIEVEMENT, LEADER, LEADER_TOP_ENTRIES, NFT_CLAIM, PLAYER, PLAYER_ACHIEVEMENT, PLAYER_SCORES,
+    PROPOSAL, RANK_REWARD, RANK_REWARD_CLAIM, SEASON_ARCHIVE,
+};
 pub use state::*;
 
 #[cfg(not(feature = "no-entrypoint"))]
@@ -37,7 +43,7 @@ pub mod soar {
     pub fn initialize_game(
         ctx: Context<InitializeGame>,
         game_meta: GameAttributes,
-        game_auth: Vec<Pubkey>,
+        game_auth: Vec<GameAuthority>,
     ) -> Result<()> {
         create_game::handler(ctx, game_meta, game_auth)
     }
@@ -46,29 +52,58 @@ pub mod soar {
     pub fn update_game(
         ctx: Context<UpdateGame>,
         new_meta: Option<GameAttributes>,
-        new_auth: Option<Vec<Pubkey>>,
+        new_auth: Option<Vec<GameAuthority>>,
     ) -> Result<()> {
         update_game::handler(ctx, new_meta, new_auth)
     }
 
+    /// Propose a sensitive change to a [Game], registering the proposing admin's approval.
+    ///
+    /// Only needed for games whose `threshold` is greater than `1`.
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        create_proposal::handler(ctx, action)
+    }
+
+    /// Register an admin's approval for a [GameProposal].
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        approve_proposal::handler(ctx)
+    }
+
+    /// Apply an approved [GameProposal] that changes the [Game]'s authorities or threshold.
+    ///
+    /// Proposals for other actions are consumed by the instructions they authorize.
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        execute_proposal::handler(ctx)
+    }
+
     /// Add a new [Achievement] that can be attained for a particular [Game].
     pub fn add_achievement(
         ctx: Context<AddAchievement>,
         title: String,
         description: String,
         nft_meta: Pubkey,
+        unlock_rule: Option<AchievementRule>,
+        progress_target: Option<u64>,
     ) -> Result<()> {
-        add_achievement::handler(ctx, title, description, nft_meta)
+        add_achievement::handler(
+            ctx,
+            title,
+            description,
+            nft_meta,
+            unlock_rule,
+            progress_target,
+        )
     }
 
-    /// Update an [Achievement]'s meta information.
+    /// Update an [Achievement]'s meta information or unlock rule.
     pub fn update_achievement(
         ctx: Context<UpdateAchievement>,
         new_title: Option<String>,
         new_description: Option<String>,
         nft_meta: Option<Pubkey>,
+        new_unlock_rule: Option<Option<AchievementRule>>,
     ) -> Result<()> {
-        update_achievement::handler(ctx, new_title, new_description, nft_meta)
+        update_achievement::handler(ctx, new_title, new_description, nft_meta, new_unlock_rule)
     }
 
     /// Overwrite the active [LeaderBoard] and set a newly created one.
@@ -79,26 +114,88 @@ pub mod soar {
         add_leaderboard::handler(ctx, input)
     }
 
-    /// Update's a leaderboard's description, nft metadata information, min/max score, or whether
-    /// or not multiple scores are allowed for a single player.
+    /// Update's a leaderboard's description, nft metadata information, min/max score, order,
+    /// whether or not multiple scores are allowed for a single player, its submission window,
+    /// retention policy, scoring mode or tie-break rule.
     pub fn update_leaderboard(
         ctx: Context<UpdateLeaderBoard>,
-        new_description: Option<String>,
-        new_nft_meta: Option<Pubkey>,
-        new_min_score: Option<u64>,
-        new_max_score: Option<u64>,
-        new_is_ascending: Option<bool>,
-        new_allow_multiple_scores: Option<bool>,
+        input: UpdateLeaderBoardInput,
     ) -> Result<()> {
-        update_leaderboard::handler(
-            ctx,
-            new_description,
-            new_nft_meta,
-            new_min_score,
-            new_max_score,
-            new_is_ascending,
-            new_allow_multiple_scores,
-        )
+        update_leaderboard::handler(ctx, input)
+    }
+
+    /// Change the number of scores a leaderboard's [LeaderTopEntriesV2] retains, keeping
+    /// current rankings. Shrinking refunds rent to `payer`, and can only drop the
+    /// lowest-ranked entries if `drop_entries` is true.
+    ///
+    /// The account can grow by at most 10KiB per call, so large increases take several calls.
+    /// Not callable while the leaderboard's season is closed.
+    pub fn resize_top_entries(
+        ctx: Context<ResizeTopEntries>,
+        new_len: u16,
+        drop_entries: bool,
+    ) -> Result<()> {
+        resize_top_entries::handler(ctx, new_len, drop_entries)
+    }
+
+    /// Convert a [Game] from its legacy layout to the current one in place, granting each of
+    /// its authorities the admin role.
+    ///
+    /// Can be called by anyone. `payer` covers any extra rent.
+    pub fn migrate_game(ctx: Context<MigrateGame>) -> Result<()> {
+        migrate_game::handler(ctx)
+    }
+
+    /// Convert a leaderboard's top entries account from the legacy borsh [LeaderTopEntries]
+    /// layout to the zero-copy [LeaderTopEntriesV2] layout in place, keeping its rankings.
+    ///
+    /// Can be called by anyone. `payer` covers any extra rent. The account can grow by at most
+    /// 10KiB per call, so large accounts take several calls and are converted by the last one.
+    pub fn migrate_top_entries(ctx: Context<MigrateTopEntries>) -> Result<()> {
+        migrate_top_entries::handler(ctx)
+    }
+
+    /// Convert a [PlayerScoresList] from its legacy layout to the current one in place,
+    /// rebuilding its aggregates from the scores it holds.
+    ///
+    /// Can be called by anyone. `payer` covers any extra rent. The account can grow by at most
+    /// 10KiB per call, so large lists take several calls and are converted by the last one.
+    /// Leaderboards with top entries must have them migrated first.
+    pub fn migrate_player_scores(ctx: Context<MigratePlayerScores>) -> Result<()> {
+        migrate_player_scores::handler(ctx)
+    }
+
+    /// Convert a [PlayerAchievement] from its legacy layout to the current one in place, with
+    /// no progress recorded.
+    ///
+    /// Can be called by anyone. `payer` covers any extra rent.
+    pub fn migrate_player_achievement(ctx: Context<MigratePlayerAchievement>) -> Result<()> {
+        migrate_player_achievement::handler(ctx)
+    }
+
+    /// Convert a [Merged] account from its legacy layout to the current one in place. Its rent
+    /// is refunded to the initiator when it's closed, and a pending merge expires a week after
+    /// being migrated.
+    ///
+    /// Can be called by anyone. `payer` covers any extra rent.
+    pub fn migrate_merge(ctx: Context<MigrateMerge>) -> Result<()> {
+        migrate_merge::handler(ctx)
+    }
+
+    /// Close the current season of a [LeaderBoard], freezing it and archiving its
+    /// [LeaderTopEntriesV2] into a [SeasonArchive] account.
+    ///
+    /// Leaderboards with top entries must pass them, and can't rank more than
+    /// [SeasonArchive::MAX_SCORES] entries; shrink them with [resize_top_entries] first.
+    ///
+    /// No scores can be submitted to a frozen leaderboard until [start_season] is called.
+    pub fn close_season(ctx: Context<CloseSeason>) -> Result<()> {
+        close_season::handler(ctx)
+    }
+
+    /// Start a new season for a frozen [LeaderBoard], resetting its [LeaderTopEntriesV2].
+    pub fn start_season(ctx: Context<StartSeason>, season_end: Option<i64>) -> Result<()> {
+        start_season::handler(ctx, season_end)
     }
 
     /// Create a [Player] account for a particular user.
@@ -128,9 +225,45 @@ pub mod soar {
     /// Submit a score for a player and have it timestamped and added to the [PlayerEntryList].
     /// Optionally increase the player's rank if needed.
     ///
-    /// This instruction automatically resizes the [PlayerScoresList] account if needed.
-    pub fn submit_score(ctx: Context<SubmitScore>, score: u64) -> Result<()> {
-        submit_score::handler(ctx, score)
+    /// On leaderboards with a [ScoreType::Signed] score type, `score` is the `i64` value cast
+    /// to `u64`.
+    ///
+    /// `secondary_score` and `context` are stored with the entry. The secondary score settles
+    /// ties on leaderboards using [TieBreak::SecondaryScore], where entries without one rank last.
+    ///
+    /// Submissions that break the [LeaderBoard]'s [RateLimit] for the player are rejected.
+    ///
+    /// This instruction automatically resizes the [PlayerScoresList] account if needed, within the
+    /// limit set by the [LeaderBoard]'s [RetentionPolicy].
+    ///
+    /// Optionally takes `(achievement, player_achievement)` pairs as remaining accounts, creating
+    /// the [PlayerAchievement] account for each [Achievement] whose unlock rule is satisfied.
+    pub fn submit_score<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubmitScore<'info>>,
+        score: u64,
+        secondary_score: Option<u64>,
+        context: Option<[u8; 32]>,
+    ) -> Result<()> {
+        submit_score::handler(ctx, score, secondary_score, context)
+    }
+
+    /// Submit a score signed off-chain by a [Game] authority with the attester role, with the
+    /// player's user signing and paying for the transaction instead of the game.
+    ///
+    /// The preceding instruction must be an ed25519 signature verification of the borsh-serialized
+    /// [ScoreAttestation] for these arguments. `nonce` must exceed the last one used for this
+    /// [PlayerScoresList], and the attestation is rejected after `expiry`.
+    ///
+    /// Takes the same optional remaining accounts as [submit_score].
+    pub fn submit_attested_score<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubmitAttestedScore<'info>>,
+        score: u64,
+        secondary_score: Option<u64>,
+        context: Option<[u8; 32]>,
+        nonce: u64,
+        expiry: i64,
+    ) -> Result<()> {
+        submit_attested_score::handler(ctx, score, secondary_score, context, nonce, expiry)
     }
 
     /// Initialize a new merge account and await approval from the verified users of all the
@@ -147,18 +280,73 @@ pub mod soar {
         approve_merge::handler(ctx)
     }
 
+    /// Reject a pending merge that includes the signer's [Player] account, closing the
+    /// [Merged] account and refunding its rent to the original payer.
+    pub fn reject_merge(ctx: Context<RejectMerge>) -> Result<()> {
+        reject_merge::handler(ctx)
+    }
+
+    /// Cancel a pending merge as its initiator, closing the [Merged] account and refunding
+    /// its rent to the original payer.
+    pub fn cancel_merge(ctx: Context<CancelMerge>) -> Result<()> {
+        cancel_merge::handler(ctx)
+    }
+
+    /// Close a [Merged] account and refund its rent to the original payer.
+    ///
+    /// Pending merges can be closed by anyone once expired. Completed merges can only be
+    /// closed by their initiator.
+    pub fn close_merge(ctx: Context<CloseMerge>) -> Result<()> {
+        close_merge::handler(ctx)
+    }
+
+    /// Move all scores from a merged [Player]'s [PlayerScoresList] into the merge initiator's
+    /// list for the same [LeaderBoard], keeping only what the leaderboard's retention policy allows.
+    ///
+    /// Only callable once the [Merged] account is complete.
+    pub fn merge_player_scores(ctx: Context<MergePlayerScores>) -> Result<()> {
+        merge_player_data::scores::handler(ctx)
+    }
+
+    /// Fold a merged [Player]'s [PlayerAchievement] into the merge initiator's, creating it if
+    /// needed.
+    ///
+    /// Only callable once the [Merged] account is complete. Each merged [PlayerAchievement]
+    /// can only be folded in once.
+    pub fn merge_player_achievement(ctx: Context<MergePlayerAchievement>) -> Result<()> {
+        merge_player_data::achievement::handler(ctx)
+    }
+
+    /// Reassign every [LeaderTopEntriesV2] slot held by a merged [Player] to the merge initiator's
+    /// [Player], keeping only its best entry if the [LeaderBoard] doesn't allow multiple scores.
+    ///
+    /// Only callable once the [Merged] account is complete, and not while the leaderboard's
+    /// season is closed.
+    pub fn merge_top_entries(ctx: Context<MergeTopEntries>) -> Result<()> {
+        merge_player_data::top_entries::handler(ctx)
+    }
+
     /// Unlock a [PlayerAchievement] account without minting a reward.
     ///
     /// Used `ONLY` for custom rewards mechanism to setup a [PlayerAchievement] account that
     /// can serve as a gated verification-method for claims.
     ///
-    /// Since claim instructions like [claim_ft_reward] and [claim_nft_reward] for reward types
-    /// defined by this program try to initialize this account and will fail if it already exists,
-    /// calling this means opting out of using these functions.
+    /// Claim instructions like [claim_ft_reward] and [claim_nft_reward] still accept an
+    /// account unlocked this way, as long as its reward hasn't been claimed.
     pub fn unlock_player_achievement(ctx: Context<UnlockPlayerAchievement>) -> Result<()> {
         unlock_player_achievement::handler(ctx)
     }
 
+    /// Increase a player's progress towards an [Achievement] with a `progress_target`,
+    /// creating their [PlayerAchievement] account if needed and unlocking it once the
+    /// target is reached.
+    pub fn increment_achievement_progress(
+        ctx: Context<IncrementAchievementProgress>,
+        amount: u64,
+    ) -> Result<()> {
+        increment_achievement_progress::handler(ctx, amount)
+    }
+
     /// Add a fungible token [Reward] to an [Achievement] to mint to users on unlock.
     ///
     /// Overwrites the current reward if one exists.
@@ -173,9 +361,27 @@ pub mod soar {
         add_reward::nft::handler(ctx, input)
     }
 
-    /// Mint an NFT reward for unlocking a [PlayerAchievement] account.
+    /// Add a fungible token [RankReward] paying out to the top ranks of a [LeaderBoard]'s
+    /// current season.
+    ///
+    /// Tiers can't reward ranks beyond the capacity of the leaderboard's [LeaderTopEntriesV2]
+    /// or [SeasonArchive::MAX_SCORES].
+    pub fn add_rank_reward(ctx: Context<AddRankReward>, input: AddRankRewardInput) -> Result<()> {
+        add_rank_reward::handler(ctx, input)
+    }
+
+    /// Claim the [RankReward] for a player holding `rank` in the [SeasonArchive] of the
+    /// reward's season.
+    ///
+    /// Each rank can be claimed only once.
+    pub fn claim_rank_reward(ctx: Context<ClaimRankReward>, rank: u32) -> Result<()> {
+        claim_rank_reward::handler(ctx, rank)
+    }
+
+    /// Transfer an FT reward for unlocking a [PlayerAchievement] account.
     ///
-    /// This will attempt to create a [PlayerAchievement] account and fail if it already exists.
+    /// This will create the [PlayerAchievement] account if it doesn't exist yet. If it does,
+    /// it must already be unlocked and not yet claimed.
     ///
     /// Relevant `ONLY` if an FT reward is specified for that achievement.    
     pub fn claim_ft_reward(ctx: Context<ClaimFtReward>) -> Result<()> {
@@ -184,13 +390,59 @@ pub mod soar {
 
     /// Mint an NFT reward for unlocking a [PlayerAchievement] account.
     ///
-    /// This will attempt to create a [PlayerAchievement] account and fail if it already exists.
+    /// This will create the [PlayerAchievement] account if it doesn't exist yet. If it does,
+    /// it must already be unlocked and not yet claimed.
     ///
     /// Relevant `ONLY` if an NFT reward is specified for that achievement.
     pub fn claim_nft_reward(ctx: Context<ClaimNftReward>) -> Result<()> {
         claim_reward::nft::handler(ctx)
     }
 
+    /// Close an [Achievement] that has no [Reward] attached, refunding its rent to `receiver`.
+    pub fn close_achievement(ctx: Context<CloseAchievement>) -> Result<()> {
+        close_accounts::achievement::handler(ctx)
+    }
+
+    /// Close a [Reward] with no available spots left, detaching it from its [Achievement]
+    /// and refunding its rent to `receiver`.
+    pub fn close_reward(ctx: Context<CloseReward>) -> Result<()> {
+        close_accounts::reward::handler(ctx)
+    }
+
+    /// Close a [LeaderBoard] whose current season is closed, along with its
+    /// [LeaderTopEntriesV2] if it has one, refunding their rent to `receiver`.
+    pub fn close_leaderboard(ctx: Context<CloseLeaderBoard>) -> Result<()> {
+        close_accounts::leaderboard::handler(ctx)
+    }
+
+    /// Close a user's own [PlayerScoresList], refunding its rent to `receiver`.
+    ///
+    /// Only allowed once the [LeaderBoard]'s season is closed, or the leaderboard itself is,
+    /// since the list holds the player's attestation nonce and rate limit state.
+    pub fn close_player_scores(ctx: Context<ClosePlayerScores>) -> Result<()> {
+        close_accounts::player_scores::handler(ctx)
+    }
+
+    /// Close a [PlayerAchievement], refunding its rent to `receiver`.
+    ///
+    /// Closing it allows the achievement to be unlocked again. Accounts that claimed, or were
+    /// merged into another player's, can't be closed while the achievement has a reward.
+    pub fn close_player_achievement(ctx: Context<ClosePlayerAchievement>) -> Result<()> {
+        close_accounts::player_achievement::handler(ctx)
+    }
+
+    /// Close an [NftClaim], refunding its rent to `receiver`.
+    ///
+    /// The claimed NFT can no longer be verified as part of the reward's collection afterwards.
+    pub fn close_nft_claim(ctx: Context<CloseNftClaim>) -> Result<()> {
+        close_accounts::nft_claim::handler(ctx)
+    }
+
+    /// Close a user's own [Player] account, refunding its rent to `receiver`.
+    pub fn close_player(ctx: Context<ClosePlayer>) -> Result<()> {
+        close_accounts::player::handler(ctx)
+    }
+
     /// Verify NFT reward as belonging to a particular collection.
     ///
     /// Optional: Only relevant if an NFT reward is specified and the reward's
@@ -201,7 +453,7 @@ pub mod soar {
 }
 
 #[derive(Accounts)]
-#[instruction(_attr: GameAttributes, auth: Vec<Pubkey>)]
+#[instruction(_attr: GameAttributes, auth: Vec<GameAuthority>)]
 pub struct InitializeGame<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -217,27 +469,106 @@ pub struct InitializeGame<'info> {
 #[derive(Accounts)]
 pub struct UpdateGame<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_role(authority.key, GameAuthority::ADMIN)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(action: ProposalAction)]
+pub struct CreateProposal<'info> {
+    #[account(
+        constraint = game.check_role(authority.key, GameAuthority::ADMIN)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
     #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = GameProposal::size(&action, game.admin_count()),
+        seeds = [
+            seeds::PROPOSAL,
+            game.key().as_ref(),
+            &game.next_proposal().to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, GameProposal>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct AddAchievement<'info> {
+pub struct ApproveProposal<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_role(authority.key, GameAuthority::ADMIN)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game
+    )]
+    pub proposal: Account<'info, GameProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game
+    )]
+    pub proposal: Account<'info, GameProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddAchievement<'info> {
+    #[account(
+        constraint = game.check_role(authority.key, GameAuthority::ACHIEVEMENT_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
     #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Account<'info, Game>,
     #[account(
         init,
@@ -257,10 +588,14 @@ pub struct AddAchievement<'info> {
 #[derive(Accounts)]
 pub struct UpdateAchievement<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_role(authority.key, GameAuthority::ACHIEVEMENT_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Account<'info, Game>,
     #[account(
         mut,
@@ -273,13 +608,17 @@ pub struct UpdateAchievement<'info> {
 #[instruction(input: RegisterLeaderBoardInput)]
 pub struct AddLeaderBoard<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_role(authority.key, GameAuthority::ADMIN)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Account<'info, Game>,
     #[account(
         init,
@@ -296,7 +635,7 @@ pub struct AddLeaderBoard<'info> {
         init,
         constraint = input.scores_to_retain > 0,
         space =
-            LeaderTopEntries::size(input.scores_to_retain as usize),
+            LeaderTopEntriesV2::size(input.scores_to_retain as usize),
         payer = payer,
         seeds = [
             seeds::LEADER_TOP_ENTRIES,
@@ -304,57 +643,127 @@ pub struct AddLeaderBoard<'info> {
         ],
         bump,
     )]
-    pub top_entries: Option<Account<'info, LeaderTopEntries>>,
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntriesV2>>,
     pub system_program: Program<'info, System>,
+    #[account(mut)]
+    pub proposal: Option<Account<'info, GameProposal>>,
 }
 
 #[derive(Accounts)]
 pub struct UpdateLeaderBoard<'info> {
     #[account(
-        constraint = game.check_signer(authority.key)
+        constraint = game.check_role(authority.key, GameAuthority::ADMIN)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Account<'info, Game>,
     #[account(
         mut,
         has_one = game
     )]
     pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        constraint =
+            check_top_entries(&leaderboard, top_entries)
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntriesV2>>,
+}
+
+#[derive(Accounts)]
+pub struct ResizeTopEntries<'info> {
+    #[account(
+        constraint = game.check_role(authority.key, GameAuthority::ADMIN)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
     #[account(mut)]
-    pub top_entries: Option<Account<'info, LeaderTopEntries>>,
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        has_one = game,
+        constraint = !leaderboard.is_frozen
+        @SoarError::SeasonEnded
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntriesV2>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct InitializePlayer<'info> {
+pub struct MigrateTopEntries<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    pub user: Signer<'info>,
     #[account(
-        init,
-        payer = payer,
-        space = Player::SIZE,
-        seeds = [seeds::PLAYER, user.key().as_ref()],
-        bump,
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
     )]
-    pub player_account: Account<'info, Player>,
+    pub leaderboard: Account<'info, LeaderBoard>,
+    /// CHECK: Deserialized as a legacy [LeaderTopEntries] in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub top_entries: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct RegisterPlayer<'info> {
+pub struct MigrateGame<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    pub user: Signer<'info>,
-    #[account(has_one = user)]
-    pub player_account: Account<'info, Player>,
-    pub game: Account<'info, Game>,
-    #[account(has_one = game)]
+    /// CHECK: Deserialized as a [LegacyGame] in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub game: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePlayerAchievement<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Deserialized as a [LegacyPlayerAchievement] in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub player_achievement: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateMerge<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Deserialized as a [LegacyMerged] in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub merge_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePlayerScores<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Only used to derive `player_scores`' address.
+    pub player_account: UncheckedAccount<'info>,
     pub leaderboard: Account<'info, LeaderBoard>,
     #[account(
-        init,
-        payer = payer,
-        space = PlayerScoresList::initial_size(),
+        constraint =
+            check_top_entries(&leaderboard, top_entries)
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntriesV2>>,
+    /// CHECK: Deserialized as a [LegacyPlayerScoresList] in the handler.
+    #[account(
+        mut,
+        owner = crate::ID,
         seeds = [
             seeds::PLAYER_SCORES,
             player_account.key().as_ref(),
@@ -362,54 +771,213 @@ pub struct RegisterPlayer<'info> {
         ],
         bump
     )]
-    pub new_list: Account<'info, PlayerScoresList>,
+    pub player_scores: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct UpdatePlayer<'info> {
-    pub user: Signer<'info>,
-    #[account(mut)]
-    pub player_account: Account<'info, Player>,
-}
-
-#[derive(Accounts)]
-pub struct SubmitScore<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+pub struct CloseSeason<'info> {
     #[account(
-        constraint = game.check_signer(&authority.key())
+        constraint = game.check_role(authority.key, GameAuthority::ADMIN)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
-    pub player_account: Account<'info, Player>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Account<'info, Game>,
-    #[account(has_one = game)]
-    pub leaderboard: Account<'info, LeaderBoard>,
     #[account(
         mut,
-        has_one = player_account,
-        has_one = leaderboard
+        has_one = game
     )]
-    pub player_scores: Account<'info, PlayerScoresList>,
+    pub leaderboard: Account<'info, LeaderBoard>,
     #[account(
-        mut,
         constraint =
             check_top_entries(&leaderboard, top_entries)
     )]
-    pub top_entries: Option<Account<'info, LeaderTopEntries>>,
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntriesV2>>,
+    #[account(
+        init,
+        payer = payer,
+        space = SeasonArchive::size(
+            top_entries.as_ref().map_or(0, |t| {
+                t.load().map_or(0, |header| header.capacity as usize)
+            }).min(SeasonArchive::MAX_SCORES)
+        ),
+        seeds = [
+            seeds::SEASON_ARCHIVE,
+            leaderboard.key().as_ref(),
+            &leaderboard.season.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub archive: Account<'info, SeasonArchive>,
     pub system_program: Program<'info, System>,
 }
 
-fn check_top_entries(
-    leaderboard: &Account<LeaderBoard>,
-    entry: &Account<LeaderTopEntries>,
-) -> bool {
-    if let Some(expected) = leaderboard.top_entries {
-        expected == entry.key()
-    } else {
-        true
-    }
+#[derive(Accounts)]
+pub struct StartSeason<'info> {
+    #[account(
+        constraint = game.check_role(authority.key, GameAuthority::ADMIN)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        has_one = game
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        constraint =
+            check_top_entries(&leaderboard, top_entries)
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntriesV2>>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePlayer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub user: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = Player::SIZE,
+        seeds = [seeds::PLAYER, user.key().as_ref()],
+        bump,
+    )]
+    pub player_account: Account<'info, Player>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterPlayer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(has_one = game)]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        init,
+        payer = payer,
+        space = PlayerScoresList::initial_size(&leaderboard.retention),
+        seeds = [
+            seeds::PLAYER_SCORES,
+            player_account.key().as_ref(),
+            leaderboard.key().as_ref()
+        ],
+        bump
+    )]
+    pub new_list: Account<'info, PlayerScoresList>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlayer<'info> {
+    pub user: Signer<'info>,
+    #[account(mut)]
+    pub player_account: Account<'info, Player>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitScore<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = game.check_role(&authority.key(), GameAuthority::SCORE_SUBMITTER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(has_one = game)]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        has_one = player_account,
+        has_one = leaderboard,
+        constraint = check_player_scores(&player_scores)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub player_scores: Account<'info, PlayerScoresList>,
+    #[account(
+        mut,
+        constraint =
+            check_top_entries(&leaderboard, top_entries)
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntriesV2>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitAttestedScore<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(has_one = game)]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        has_one = player_account,
+        has_one = leaderboard,
+        constraint = check_player_scores(&player_scores)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub player_scores: Account<'info, PlayerScoresList>,
+    #[account(
+        mut,
+        constraint =
+            check_top_entries(&leaderboard, top_entries)
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntriesV2>>,
+    /// CHECK: The instructions sysvar.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+fn check_top_entries(
+    leaderboard: &Account<LeaderBoard>,
+    entry: &AccountLoader<LeaderTopEntriesV2>,
+) -> bool {
+    leaderboard.top_entries == Some(entry.key())
+}
+
+fn check_game(game: &Account<Game>) -> bool {
+    // Legacy games store their authorities in fewer bytes than `auth`'s length implies.
+    game.to_account_info().data_len() == Game::size(game.auth.len())
+}
+
+fn check_player_scores(player_scores: &Account<PlayerScoresList>) -> bool {
+    // Legacy lists share the discriminator but are smaller than their `alloc_count` implies.
+    player_scores.to_account_info().data_len() == player_scores.current_size()
 }
 
 #[derive(Accounts)]
@@ -456,16 +1024,173 @@ pub struct ApproveMerge<'info> {
     pub merge_account: Account<'info, Merged>,
 }
 
+#[derive(Accounts)]
+pub struct RejectMerge<'info> {
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        constraint = merge_account.contains(&player_account.key())
+        @SoarError::AccountNotPartOfMerge,
+        constraint = !merge_account.merge_complete
+        @SoarError::MergeAlreadyComplete
+    )]
+    pub merge_account: Account<'info, Merged>,
+    /// CHECK: Checked in has_one relationship with `merge_account`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelMerge<'info> {
+    #[account(address = merge_account.initiator @SoarError::InvalidAuthority)]
+    pub initiator: Signer<'info>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        constraint = !merge_account.merge_complete
+        @SoarError::MergeAlreadyComplete
+    )]
+    pub merge_account: Account<'info, Merged>,
+    /// CHECK: Checked in has_one relationship with `merge_account`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseMerge<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer
+    )]
+    pub merge_account: Account<'info, Merged>,
+    /// CHECK: Checked in has_one relationship with `merge_account`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MergePlayerScores<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = merge_account.merge_complete
+        @SoarError::MergeNotComplete
+    )]
+    pub merge_account: Account<'info, Merged>,
+    #[account(
+        seeds = [seeds::PLAYER, merge_account.initiator.as_ref()],
+        bump
+    )]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        mut,
+        constraint = merge_account.contains(&merged_player_scores.player_account)
+        @SoarError::AccountNotPartOfMerge,
+        constraint = check_player_scores(&merged_player_scores)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub merged_player_scores: Account<'info, PlayerScoresList>,
+    #[account(
+        mut,
+        has_one = player_account,
+        has_one = leaderboard,
+        constraint = player_scores.leaderboard == merged_player_scores.leaderboard,
+        constraint = check_player_scores(&player_scores)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub player_scores: Account<'info, PlayerScoresList>,
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        constraint =
+            check_top_entries(&leaderboard, top_entries)
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntriesV2>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MergePlayerAchievement<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = merge_account.merge_complete
+        @SoarError::MergeNotComplete
+    )]
+    pub merge_account: Account<'info, Merged>,
+    #[account(
+        seeds = [seeds::PLAYER, merge_account.initiator.as_ref()],
+        bump
+    )]
+    pub player_account: Account<'info, Player>,
+    pub achievement: Account<'info, Achievement>,
+    #[account(
+        mut,
+        has_one = achievement,
+        constraint = merge_account.contains(&merged_player_achievement.player_account)
+        @SoarError::AccountNotPartOfMerge
+    )]
+    pub merged_player_achievement: Account<'info, PlayerAchievement>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerAchievement::SIZE,
+        seeds = [
+            seeds::PLAYER_ACHIEVEMENT,
+            player_account.key().as_ref(),
+            achievement.key().as_ref()
+        ],
+        bump
+    )]
+    pub player_achievement: Account<'info, PlayerAchievement>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MergeTopEntries<'info> {
+    #[account(
+        constraint = merge_account.merge_complete
+        @SoarError::MergeNotComplete
+    )]
+    pub merge_account: Account<'info, Merged>,
+    #[account(
+        seeds = [seeds::PLAYER, merge_account.initiator.as_ref()],
+        bump
+    )]
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = !leaderboard.is_frozen
+        @SoarError::SeasonEnded
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntriesV2>,
+}
+
 #[derive(Accounts)]
 pub struct UnlockPlayerAchievement<'info> {
     #[account(
-        constraint = game.check_signer(&authority.key())
+        constraint = game.check_role(&authority.key(), GameAuthority::ACHIEVEMENT_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Account<'info, Game>,
     #[account(has_one = game)]
     pub achievement: Account<'info, Achievement>,
@@ -484,15 +1209,55 @@ pub struct UnlockPlayerAchievement<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct IncrementAchievementProgress<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), GameAuthority::ACHIEVEMENT_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub player_account: Account<'info, Player>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        has_one = game,
+        constraint = achievement.progress_target.is_some()
+        @SoarError::NoProgressTarget
+    )]
+    pub achievement: Account<'info, Achievement>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerAchievement::SIZE,
+        seeds = [
+            seeds::PLAYER_ACHIEVEMENT,
+            player_account.key().as_ref(),
+            achievement.key().as_ref()
+        ],
+        bump
+    )]
+    pub player_achievement: Account<'info, PlayerAchievement>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct AddFtReward<'info> {
     #[account(
-        constraint = game.check_signer(&authority.key())
+        constraint = game.check_role(&authority.key(), GameAuthority::REWARD_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Box<Account<'info, Game>>,
     #[account(
         mut,
@@ -512,17 +1277,23 @@ pub struct AddFtReward<'info> {
     pub token_account_owner: Signer<'info>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    #[account(mut)]
+    pub proposal: Option<Box<Account<'info, GameProposal>>>,
 }
 
 #[derive(Accounts)]
 pub struct AddNftReward<'info> {
     #[account(
-        constraint = game.check_signer(&authority.key())
+        constraint = game.check_role(&authority.key(), GameAuthority::REWARD_MANAGER)
         @SoarError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Box<Account<'info, Game>>,
     #[account(
         mut,
@@ -545,6 +1316,111 @@ pub struct AddNftReward<'info> {
     #[account(address = mpl_token_metadata::ID)]
     /// CHECK: We check that the ID is the correct one.
     pub token_metadata_program: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub proposal: Option<Box<Account<'info, GameProposal>>>,
+}
+
+#[derive(Accounts)]
+#[instruction(input: AddRankRewardInput)]
+pub struct AddRankReward<'info> {
+    #[account(
+        constraint = game.check_role(&authority.key(), GameAuthority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Box<Account<'info, Game>>,
+    #[account(has_one = game)]
+    pub leaderboard: Box<Account<'info, LeaderBoard>>,
+    #[account(
+        constraint = leaderboard.top_entries == Some(top_entries.key())
+        @SoarError::MissingExpectedAccount
+    )]
+    pub top_entries: AccountLoader<'info, LeaderTopEntriesV2>,
+    #[account(
+        init,
+        payer = payer,
+        space = RankReward::size(input.tiers.len()),
+        seeds = [
+            seeds::RANK_REWARD,
+            leaderboard.key().as_ref(),
+            &leaderboard.season.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub rank_reward: Box<Account<'info, RankReward>>,
+    pub reward_token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = delegate_from_token_account.mint == reward_token_mint.key()
+    )]
+    pub delegate_from_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_account_owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    pub proposal: Option<Box<Account<'info, GameProposal>>>,
+}
+
+#[derive(Accounts)]
+#[instruction(rank: u32)]
+pub struct ClaimRankReward<'info> {
+    pub user: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Box<Account<'info, Player>>,
+    pub leaderboard: Box<Account<'info, LeaderBoard>>,
+    #[account(
+        has_one = leaderboard,
+        seeds = [
+            seeds::RANK_REWARD,
+            leaderboard.key().as_ref(),
+            &rank_reward.season.to_le_bytes()
+        ],
+        bump
+    )]
+    pub rank_reward: Box<Account<'info, RankReward>>,
+    #[account(
+        has_one = leaderboard,
+        seeds = [
+            seeds::SEASON_ARCHIVE,
+            leaderboard.key().as_ref(),
+            &rank_reward.season.to_le_bytes()
+        ],
+        bump
+    )]
+    pub archive: Box<Account<'info, SeasonArchive>>,
+    #[account(
+        init,
+        payer = payer,
+        space = RankRewardClaim::SIZE,
+        seeds = [
+            seeds::RANK_REWARD_CLAIM,
+            rank_reward.key().as_ref(),
+            &rank.to_le_bytes()
+        ],
+        bump
+    )]
+    pub claim: Box<Account<'info, RankRewardClaim>>,
+    #[account(
+        mut,
+        address = rank_reward.account
+    )]
+    pub source_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key(),
+        constraint = user_token_account.mint == rank_reward.mint
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -554,7 +1430,12 @@ pub struct ClaimFtReward<'info> {
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(constraint = game.check_signer(&authority.key()))]
+    #[account(
+        constraint = game.check_role(&authority.key(), GameAuthority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority,
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Account<'info, Game>,
     #[account(
         seeds = [
@@ -578,7 +1459,7 @@ pub struct ClaimFtReward<'info> {
     #[account(has_one = user)]
     pub player_account: Box<Account<'info, Player>>,
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         space = PlayerAchievement::SIZE,
         seeds = [
@@ -605,7 +1486,12 @@ pub struct ClaimNftReward<'info> {
     /// CHECK: Checked with `player_account`
     pub user: UncheckedAccount<'info>,
     pub authority: Signer<'info>,
-    #[account(constraint = game.check_signer(&authority.key()))]
+    #[account(
+        constraint = game.check_role(&authority.key(), GameAuthority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority,
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Account<'info, Game>,
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -631,7 +1517,7 @@ pub struct ClaimNftReward<'info> {
     #[account(has_one = user)]
     pub player_account: Box<Account<'info, Player>>,
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         space = PlayerAchievement::SIZE,
         seeds = [
@@ -674,10 +1560,194 @@ pub struct ClaimNftReward<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+pub struct CloseAchievement<'info> {
+    #[account(
+        constraint = game.check_role(authority.key, GameAuthority::ACHIEVEMENT_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        close = receiver,
+        has_one = game,
+        constraint = achievement.reward.is_none()
+        @SoarError::AchievementHasReward
+    )]
+    pub achievement: Account<'info, Achievement>,
+    /// CHECK: Any account chosen by the authority to receive the rent.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseReward<'info> {
+    #[account(
+        constraint = game.check_role(authority.key, GameAuthority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Box<Account<'info, Game>>,
+    #[account(
+        mut,
+        has_one = game
+    )]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(
+        mut,
+        close = receiver,
+        has_one = achievement,
+        constraint = reward.available_spots == 0
+        @SoarError::RewardSpotsRemaining
+    )]
+    pub reward: Box<Account<'info, Reward>>,
+    /// CHECK: Any account chosen by the authority to receive the rent.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseLeaderBoard<'info> {
+    #[account(
+        constraint = game.check_role(authority.key, GameAuthority::ADMIN)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        close = receiver,
+        has_one = game,
+        constraint = leaderboard.is_frozen
+        @SoarError::SeasonNotClosed
+    )]
+    pub leaderboard: Account<'info, LeaderBoard>,
+    #[account(
+        mut,
+        close = receiver
+    )]
+    pub top_entries: Option<AccountLoader<'info, LeaderTopEntriesV2>>,
+    /// CHECK: Any account chosen by the authority to receive the rent.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePlayerScores<'info> {
+    pub user: Signer<'info>,
+    #[account(has_one = user)]
+    pub player_account: Account<'info, Player>,
+    /// CHECK: Deserialized in the handler unless it has already been closed.
+    #[account(address = player_scores.leaderboard)]
+    pub leaderboard: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = receiver,
+        has_one = player_account
+    )]
+    pub player_scores: Account<'info, PlayerScoresList>,
+    /// CHECK: Any account chosen by the user to receive the rent.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePlayerAchievement<'info> {
+    #[account(
+        constraint = game.check_role(authority.key, GameAuthority::ACHIEVEMENT_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Account<'info, Game>,
+    #[account(has_one = game)]
+    pub achievement: Account<'info, Achievement>,
+    #[account(
+        mut,
+        close = receiver,
+        has_one = achievement,
+        constraint = achievement.reward.is_none()
+            || !(player_achievement.claimed || player_achievement.absorbed)
+        @SoarError::RewardAlreadyClaimed
+    )]
+    pub player_achievement: Account<'info, PlayerAchievement>,
+    /// CHECK: Any account chosen by the authority to receive the rent.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseNftClaim<'info> {
+    #[account(
+        constraint = game.check_role(authority.key, GameAuthority::REWARD_MANAGER)
+        @SoarError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
+    pub game: Box<Account<'info, Game>>,
+    #[account(has_one = game)]
+    pub achievement: Box<Account<'info, Achievement>>,
+    #[account(has_one = achievement)]
+    pub reward: Box<Account<'info, Reward>>,
+    /// CHECK: Used as a seed for `claim`.
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = receiver,
+        seeds = [
+            seeds::NFT_CLAIM,
+            reward.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Account<'info, NftClaim>,
+    /// CHECK: Any account chosen by the authority to receive the rent.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePlayer<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        close = receiver,
+        has_one = user
+    )]
+    pub player_account: Account<'info, Player>,
+    /// CHECK: Any account chosen by the user to receive the rent.
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
 #[derive(Accounts)]
 pub struct VerifyNftReward<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        constraint = check_game(&game)
+        @SoarError::LegacyAccountLayout
+    )]
     pub game: Box<Account<'info, Game>>,
     #[account(
         has_one = game,
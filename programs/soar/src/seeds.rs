@@ -5,3 +5,7 @@ pub const PLAYER_SCORES: &[u8] = b"player-scores-list";
 pub const PLAYER_ACHIEVEMENT: &[u8] = b"player-achievement";
 pub const LEADER_TOP_ENTRIES: &[u8] = b"top-scores";
 pub const NFT_CLAIM: &[u8] = b"nft-claim";
+pub const SEASON_ARCHIVE: &[u8] = b"season-archive";
+pub const RANK_REWARD: &[u8] = b"rank-reward";
+pub const RANK_REWARD_CLAIM: &[u8] = b"rank-reward-claim";
+pub const PROPOSAL: &[u8] = b"proposal";
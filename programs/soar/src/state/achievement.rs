@@ -1,5 +1,6 @@
-use super::{MAX_DESCRIPTION_LEN, MAX_TITLE_LEN};
+use super::{ScoreEntry, ScoreType, MAX_DESCRIPTION_LEN, MAX_TITLE_LEN};
 use anchor_lang::prelude::*;
+use std::cmp::Ordering;
 
 #[account]
 #[derive(Debug)]
@@ -28,6 +29,28 @@ pub struct Achievement {
 
     /// Optional: Specify a reward to players for unlocking this achievement.
     pub reward: Option<Pubkey>,
+
+    /// Optional: A rule that automatically unlocks this achievement for a
+    /// player when satisfied during a score submission.
+    pub unlock_rule: Option<AchievementRule>,
+
+    /// Optional: A progress value players must reach through
+    /// [increment_achievement_progress][crate::soar::increment_achievement_progress]
+    /// for this achievement to be unlocked.
+    pub progress_target: Option<u64>,
+}
+
+/// A condition on a player's score submissions that unlocks an [Achievement].
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug)]
+pub enum AchievementRule {
+    /// Unlocked by submitting a score greater than or equal to `score` to `leaderboard`.
+    ScoreAtLeast { leaderboard: Pubkey, score: u64 },
+
+    /// Unlocked by submitting a score less than or equal to `score` to `leaderboard`.
+    ScoreAtMost { leaderboard: Pubkey, score: u64 },
+
+    /// Unlocked once a player has submitted `count` scores to `leaderboard`.
+    SubmissionCount { leaderboard: Pubkey, count: u64 },
 }
 
 impl Achievement {
@@ -38,7 +61,9 @@ impl Achievement {
         4 + MAX_TITLE_LEN + // title
         4 + MAX_DESCRIPTION_LEN + // description
         32 + // nft_meta
-        1 + 32; // reward
+        1 + 32 + // reward
+        1 + AchievementRule::SIZE + // unlock_rule
+        1 + 8; // progress_target
 
     /// Create a new [Achievement] instance.
     pub fn new(
@@ -47,6 +72,8 @@ impl Achievement {
         description: String,
         nft_meta: Pubkey,
         id: u64,
+        unlock_rule: Option<AchievementRule>,
+        progress_target: Option<u64>,
     ) -> Self {
         Achievement {
             game,
@@ -55,6 +82,42 @@ impl Achievement {
             description,
             nft_meta,
             reward: None,
+            unlock_rule,
+            progress_target,
+        }
+    }
+}
+
+impl AchievementRule {
+    /// Size of the largest borsh-serialized [AchievementRule] variant.
+    pub const SIZE: usize = 1 + // variant
+        32 + // leaderboard
+        8; // score or count
+
+    /// Check if this rule is satisfied by a new `entry` submitted to `leaderboard`,
+    /// given the player's total number of `submissions` to it.
+    ///
+    /// Score thresholds are interpreted according to the leaderboard's `score_type`.
+    pub fn is_satisfied(
+        &self,
+        leaderboard: &Pubkey,
+        score_type: ScoreType,
+        entry: &ScoreEntry,
+        submissions: u64,
+    ) -> bool {
+        match self {
+            AchievementRule::ScoreAtLeast {
+                leaderboard: key,
+                score,
+            } => key == leaderboard && score_type.compare(entry.score, *score) != Ordering::Less,
+            AchievementRule::ScoreAtMost {
+                leaderboard: key,
+                score,
+            } => key == leaderboard && score_type.compare(entry.score, *score) != Ordering::Greater,
+            AchievementRule::SubmissionCount {
+                leaderboard: key,
+                count,
+            } => key == leaderboard && submissions >= *count,
         }
     }
 }
@@ -42,11 +42,19 @@ impl FieldsCheck for Game {
 
 impl FieldsCheck for RegisterLeaderBoardInput {
     fn check(&self) -> Result<()> {
-        if self.description.len() > MAX_DESCRIPTION_LEN {
+        if self.description.len() > MAX_DESCRIPTION_LEN
+            || self.scores_to_retain as usize > LeaderTopEntriesV2::MAX_INITIAL_SCORES
+        {
             return Err(SoarError::InvalidFieldLength.into());
         }
-
-        Ok(())
+        check_submission_window(self.opens_at, self.closes_at)?;
+        check_retention(&self.retention)?;
+        check_score_bounds(
+            self.score_type,
+            self.min_score.unwrap_or(self.score_type.min_score()),
+            self.max_score.unwrap_or(self.score_type.max_score()),
+        )?;
+        check_scoring_mode(&self.scoring_mode, self.allow_multiple_scores)
     }
 }
 
@@ -55,9 +63,51 @@ impl FieldsCheck for LeaderBoard {
         if self.description.len() > MAX_DESCRIPTION_LEN {
             return Err(SoarError::InvalidFieldLength.into());
         }
+        check_submission_window(self.opens_at, self.closes_at)?;
+        check_retention(&self.retention)?;
+        check_score_bounds(self.score_type, self.min_score, self.max_score)?;
+        check_scoring_mode(&self.scoring_mode, self.allow_multiple_scores)
+    }
+}
 
-        Ok(())
+fn check_score_bounds(score_type: ScoreType, min_score: u64, max_score: u64) -> Result<()> {
+    require!(
+        score_type.compare(min_score, max_score) != std::cmp::Ordering::Greater,
+        SoarError::InvalidScoreBounds
+    );
+
+    Ok(())
+}
+
+fn check_scoring_mode(scoring_mode: &ScoringMode, allow_multiple_scores: bool) -> Result<()> {
+    require!(
+        !(scoring_mode.replaces_previous() && allow_multiple_scores),
+        SoarError::InvalidScoringMode
+    );
+
+    Ok(())
+}
+
+fn check_retention(retention: &RetentionPolicy) -> Result<()> {
+    match retention {
+        RetentionPolicy::KeepLast { count } | RetentionPolicy::KeepBest { count } => {
+            require!(
+                *count > 0 && *count as usize <= PlayerScoresList::MAX_RETAINED_SCORES,
+                SoarError::InvalidRetentionPolicy
+            );
+        }
+        RetentionPolicy::Unbounded | RetentionPolicy::AggregateOnly => (),
     }
+
+    Ok(())
+}
+
+fn check_submission_window(opens_at: Option<i64>, closes_at: Option<i64>) -> Result<()> {
+    if let (Some(opens_at), Some(closes_at)) = (opens_at, closes_at) {
+        require!(opens_at < closes_at, SoarError::InvalidSubmissionWindow);
+    }
+
+    Ok(())
 }
 
 impl FieldsCheck for Player {
@@ -93,3 +143,29 @@ impl FieldsCheck for Reward {
         }
     }
 }
+
+impl FieldsCheck for AddRankRewardInput {
+    fn check(&self) -> Result<()> {
+        if self.tiers.len() > RankReward::MAX_TIERS {
+            return Err(SoarError::InvalidFieldLength.into());
+        }
+
+        let mut tiers = self.tiers.clone();
+        tiers.sort_by_key(|tier| tier.start_rank);
+        require!(
+            !tiers.is_empty()
+                && tiers
+                    .iter()
+                    .all(|tier| tier.start_rank > 0 && tier.start_rank <= tier.end_rank)
+                && tiers
+                    .iter()
+                    .all(|tier| tier.end_rank as usize <= SeasonArchive::MAX_SCORES)
+                && tiers
+                    .windows(2)
+                    .all(|pair| pair[0].end_rank < pair[1].start_rank),
+            SoarError::InvalidRankRewardTiers
+        );
+
+        Ok(())
+    }
+}
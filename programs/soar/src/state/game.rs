@@ -1,5 +1,5 @@
 use super::{MAX_DESCRIPTION_LEN, MAX_TITLE_LEN};
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
 
 #[account]
 #[derive(Debug, Default)]
@@ -17,28 +17,103 @@ pub struct Game {
     /// used to determine the u64 seed for the next achievement.
     pub achievement_count: u64,
 
+    /// A collection of valid authorities for this game and the
+    /// roles each of them has been granted.
+    pub auth: Vec<GameAuthority>,
+
+    /// Number of admin approvals a [GameProposal][super::GameProposal] needs before
+    /// sensitive changes can be made. Values of `0` or `1` disable proposals.
+    pub threshold: u8,
+
+    /// Number of proposals created for this game. Also used to
+    /// determine the u64 seed for the next proposal.
+    pub proposal_count: u64,
+}
+
+/// Layout of a [Game] before authority roles and proposals were added.
+///
+/// Legacy accounts share [Game]'s discriminator and are told apart by their size, which
+/// depends on the length of `auth` in both layouts. They're only read to be converted in
+/// place with `migrate_game`.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct LegacyGame {
+    /// Game meta-information.
+    pub meta: GameAttributes,
+
+    /// Number of leaderboards this game has created.
+    pub leaderboard_count: u64,
+
+    /// Number of achievements that exist for this game.
+    pub achievement_count: u64,
+
     /// A collection of pubkeys which each represent a valid
     /// authority for this game.
     pub auth: Vec<Pubkey>,
 }
 
+/// A [Game] authority and the roles it has been granted.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct GameAuthority {
+    /// The authority's public key.
+    pub key: Pubkey,
+
+    /// A bitmask of the roles granted to this authority.
+    pub roles: u8,
+}
+
 impl Game {
     /// Base size of a game account, not counting the auth vec.
     pub const SIZE_NO_AUTHS: usize = 8 + // discriminator
         GameAttributes::SIZE + // GameMeta fixed size
         8 + // leaderboard_count
-        8; // achievement_count
+        8 + // achievement_count
+        1 + // threshold
+        8; // proposal_count
 
     /// The size of a game account, considering a auth vec of
     /// size `auths_len`.
     pub fn size(auth_len: usize) -> usize {
         Self::SIZE_NO_AUTHS + // Base size
-        4 + (auth_len * 32) // Auths vector.
+        4 + (auth_len * GameAuthority::SIZE) // Auths vector.
+    }
+
+    /// Convert a legacy game, granting each of its authorities the admin role they
+    /// implicitly had. Proposals start out disabled.
+    pub fn from_legacy(legacy: LegacyGame) -> Self {
+        Game {
+            meta: legacy.meta,
+            leaderboard_count: legacy.leaderboard_count,
+            achievement_count: legacy.achievement_count,
+            auth: legacy
+                .auth
+                .into_iter()
+                .map(|key| GameAuthority::new(key, GameAuthority::ADMIN))
+                .collect(),
+            threshold: 0,
+            proposal_count: 0,
+        }
+    }
+
+    /// Check that a given pubkey is one of the Game's authorities and has been
+    /// granted `role`. Admins are implicitly granted every role.
+    pub fn check_role(&self, key: &Pubkey, role: u8) -> bool {
+        self.auth
+            .iter()
+            .any(|auth| auth.key == *key && auth.has_role(role))
+    }
+
+    /// Check if sensitive changes to this game must go through an approved
+    /// [GameProposal][super::GameProposal].
+    pub fn requires_proposal(&self) -> bool {
+        self.threshold > 1
     }
 
-    /// Check that a given pubkey is one of the Game's authorities.
-    pub fn check_signer(&self, key: &Pubkey) -> bool {
-        self.auth.contains(key)
+    /// Number of authorities with the admin role.
+    pub fn admin_count(&self) -> usize {
+        self.auth
+            .iter()
+            .filter(|auth| auth.has_role(GameAuthority::ADMIN))
+            .count()
     }
 
     /// Set a game's attributes.
@@ -51,12 +126,77 @@ impl Game {
         self.achievement_count.checked_add(1).unwrap()
     }
 
+    /// Get next proposal id.
+    pub fn next_proposal(&self) -> u64 {
+        self.proposal_count.checked_add(1).unwrap()
+    }
+
     /// Get next leaderboard id.
     pub fn next_leaderboard(&self) -> u64 {
         self.leaderboard_count.checked_add(1).unwrap()
     }
 }
 
+impl LegacyGame {
+    /// The size of a legacy game account, considering a auth vec of
+    /// size `auth_len`.
+    pub fn size(auth_len: usize) -> usize {
+        8 + // discriminator
+        GameAttributes::SIZE + // GameMeta fixed size
+        8 + // leaderboard_count
+        8 + // achievement_count
+        4 + (auth_len * 32) // Auths vector.
+    }
+
+    /// Decode a [Game] account's data, failing unless it's in the legacy layout.
+    pub fn try_from_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.get(..8) == Some(&Game::discriminator()[..]),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let legacy = Self::deserialize(&mut &data[8..])
+            .map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))?;
+        require!(
+            data.len() == Self::size(legacy.auth.len()),
+            ErrorCode::AccountDidNotDeserialize
+        );
+
+        Ok(legacy)
+    }
+}
+
+impl GameAuthority {
+    /// Size of a borsh-serialized [GameAuthority].
+    pub const SIZE: usize = 32 + // key
+        1; // roles
+
+    /// Can update the game, its authorities and its leaderboards. Implies every other role.
+    pub const ADMIN: u8 = 1 << 0;
+
+    /// Can submit scores.
+    pub const SCORE_SUBMITTER: u8 = 1 << 1;
+
+    /// Can add, update and unlock achievements.
+    pub const ACHIEVEMENT_MANAGER: u8 = 1 << 2;
+
+    /// Can add rewards and claim them on behalf of players.
+    pub const REWARD_MANAGER: u8 = 1 << 3;
+
+    /// Can sign off-chain [score attestations][super::ScoreAttestation] that players
+    /// submit themselves.
+    pub const ATTESTER: u8 = 1 << 4;
+
+    /// Create a new instance of Self.
+    pub fn new(key: Pubkey, roles: u8) -> Self {
+        GameAuthority { key, roles }
+    }
+
+    /// Check if this authority has been granted `role`.
+    pub fn has_role(&self, role: u8) -> bool {
+        self.roles & Self::ADMIN != 0 || self.roles & role == role
+    }
+}
+
 /// A type that represents game-specific information.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct GameAttributes {
@@ -101,3 +241,55 @@ impl GameAttributes {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attributes() -> GameAttributes {
+        GameAttributes::new(
+            "Title".to_string(),
+            "Description".to_string(),
+            0,
+            0,
+            Pubkey::new_unique(),
+        )
+    }
+
+    #[test]
+    fn legacy_games_decode_from_baseline_bytes() {
+        let auth = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut data = Game::discriminator().to_vec();
+        attributes().serialize(&mut data).unwrap();
+        data.extend_from_slice(&3u64.to_le_bytes());
+        data.extend_from_slice(&4u64.to_le_bytes());
+        auth.serialize(&mut data).unwrap();
+        data.resize(LegacyGame::size(auth.len()), 0);
+
+        let game = Game::from_legacy(LegacyGame::try_from_data(&data).unwrap());
+        assert_eq!((game.leaderboard_count, game.achievement_count), (3, 4));
+        assert!(!game.requires_proposal());
+        assert_eq!(game.admin_count(), 2);
+        assert!(auth
+            .iter()
+            .all(|key| game.check_role(key, GameAuthority::REWARD_MANAGER)));
+    }
+
+    #[test]
+    fn current_games_are_not_read_as_legacy() {
+        let game = Game {
+            meta: attributes(),
+            auth: vec![GameAuthority::new(
+                Pubkey::new_unique(),
+                GameAuthority::ADMIN,
+            )],
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        game.try_serialize(&mut data).unwrap();
+        data.resize(Game::size(game.auth.len()), 0);
+
+        assert!(LegacyGame::try_from_data(&data).is_err());
+        assert!(Game::try_deserialize(&mut &data[..]).is_ok());
+    }
+}
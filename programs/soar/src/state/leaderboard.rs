@@ -1,14 +1,291 @@
-use super::{RegisterLeaderBoardInput, MAX_DESCRIPTION_LEN};
+use super::{PlayerScoresList, RegisterLeaderBoardInput, MAX_DESCRIPTION_LEN};
 use anchor_lang::prelude::*;
+use std::cmp::Ordering;
 
 /// A single score entry for a player.
-#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default)]
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default)]
 pub struct ScoreEntry {
     /// The player's score.
     pub score: u64,
 
     /// When this entry was made.
     pub timestamp: i64,
+
+    /// Optional secondary metric, such as a move count. Zero if not provided.
+    pub secondary_score: u64,
+
+    /// Optional opaque data linked to this entry, such as the hash of a replay.
+    /// All zeroes if not provided.
+    pub context: [u8; 32],
+
+    /// `1` if `secondary_score` was provided, `0` otherwise.
+    pub has_secondary_score: u8,
+
+    /// Unused.
+    pub padding: [u8; 7],
+}
+
+/// Layout of a [ScoreEntry] before secondary scores and context were added.
+///
+/// Only kept to read legacy [LeaderTopEntries][super::LeaderTopEntries] and
+/// [PlayerScoresList] accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default)]
+pub struct LegacyScoreEntry {
+    /// The player's score.
+    pub score: u64,
+
+    /// When this entry was made.
+    pub timestamp: i64,
+}
+
+/// Determines which [scores][ScoreEntry] a [PlayerScoresList][super::PlayerScoresList]
+/// keeps for a leaderboard.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RetentionPolicy {
+    /// Keep every score, growing the account as needed.
+    #[default]
+    Unbounded,
+
+    /// Keep only the `count` most recent scores.
+    KeepLast { count: u16 },
+
+    /// Keep only the `count` best scores, ranked in the leaderboard's top-entries order
+    /// or highest-first if it has none.
+    KeepBest { count: u16 },
+
+    /// Don't keep individual scores, only the list's running aggregates.
+    AggregateOnly,
+}
+
+impl RetentionPolicy {
+    /// Size of a borsh-serialized [RetentionPolicy].
+    pub const SIZE: usize = 1 + 2;
+
+    /// The maximum number of scores kept under this policy, or [None] if unbounded.
+    pub fn max_scores(&self) -> Option<usize> {
+        match self {
+            RetentionPolicy::Unbounded => None,
+            RetentionPolicy::KeepLast { count } | RetentionPolicy::KeepBest { count } => {
+                Some(*count as usize)
+            }
+            RetentionPolicy::AggregateOnly => Some(0),
+        }
+    }
+}
+
+/// Determines the value a player is ranked by in a leaderboard's
+/// [top entries][super::LeaderTopEntries].
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ScoringMode {
+    /// Rank each player by their best submitted score.
+    #[default]
+    Best,
+
+    /// Rank each player by their most recently submitted score.
+    Latest,
+
+    /// Rank each player by the sum of the scores they submitted during the current season.
+    Cumulative,
+
+    /// Rank each player by their number of submissions during the current season.
+    Count,
+}
+
+impl ScoringMode {
+    /// Size of a borsh-serialized [ScoringMode].
+    pub const SIZE: usize = 1;
+
+    /// The value to rank a player by after `entry` has been recorded in their `player_scores`.
+    pub fn ranked_value(
+        &self,
+        entry: &ScoreEntry,
+        player_scores: &PlayerScoresList,
+        score_type: ScoreType,
+    ) -> u64 {
+        match self {
+            ScoringMode::Best | ScoringMode::Latest => entry.score,
+            ScoringMode::Cumulative => score_type.from_value(player_scores.season_total_score),
+            ScoringMode::Count => player_scores.season_submission_count,
+        }
+    }
+
+    /// Whether a player's new ranked value replaces their previous one instead of
+    /// only replacing it when better.
+    pub fn replaces_previous(&self) -> bool {
+        !matches!(self, ScoringMode::Best)
+    }
+
+    /// Combine two ranked entries held by the same player into one.
+    pub fn combine(
+        &self,
+        a: ScoreEntry,
+        b: ScoreEntry,
+        score_type: ScoreType,
+        is_ascending: bool,
+    ) -> ScoreEntry {
+        match self {
+            ScoringMode::Best => {
+                let a_is_better = if is_ascending {
+                    score_type.compare(a.score, b.score) != Ordering::Greater
+                } else {
+                    score_type.compare(a.score, b.score) != Ordering::Less
+                };
+                if a_is_better {
+                    a
+                } else {
+                    b
+                }
+            }
+            ScoringMode::Latest => {
+                if a.timestamp >= b.timestamp {
+                    a
+                } else {
+                    b
+                }
+            }
+            ScoringMode::Cumulative | ScoringMode::Count => {
+                let latest = if a.timestamp >= b.timestamp { a } else { b };
+                ScoreEntry {
+                    score: score_type
+                        .from_value(score_type.value(a.score) + score_type.value(b.score)),
+                    ..latest
+                }
+            }
+        }
+    }
+}
+
+/// Decides which of two entries with equal scores ranks higher.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The entry submitted first ranks higher.
+    #[default]
+    EarlierWins,
+
+    /// The entry submitted last ranks higher.
+    LaterWins,
+
+    /// The entry with the better secondary score ranks higher, lower first if `is_ascending`.
+    /// Entries without one rank last, and entries that also tie on it are ordered by the
+    /// earlier submission.
+    SecondaryScore { is_ascending: bool },
+}
+
+impl TieBreak {
+    /// Size of a borsh-serialized [TieBreak].
+    pub const SIZE: usize = 1 + 1;
+
+    /// Compare two entries with equal scores. [Ordering::Less] means `a` ranks above `b`.
+    pub fn compare(&self, a: &ScoreEntry, b: &ScoreEntry) -> Ordering {
+        match self {
+            TieBreak::EarlierWins => a.timestamp.cmp(&b.timestamp),
+            TieBreak::LaterWins => b.timestamp.cmp(&a.timestamp),
+            TieBreak::SecondaryScore { is_ascending } => {
+                // Entries without a secondary score rank below those with one.
+                let by_secondary = match (a.secondary_score(), b.secondary_score()) {
+                    (Some(a), Some(b)) if *is_ascending => a.cmp(&b),
+                    (Some(a), Some(b)) => b.cmp(&a),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                };
+                by_secondary.then(a.timestamp.cmp(&b.timestamp))
+            }
+        }
+    }
+}
+
+/// Limits how often scores can be submitted for each player of a leaderboard.
+///
+/// Zero values disable the corresponding limit.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Minimum number of seconds between two submissions for the same player.
+    pub min_interval: u32,
+
+    /// Maximum number of submissions per player, counted within each attempt window
+    /// or over the player's lifetime on the leaderboard if `attempt_window` is zero.
+    pub max_attempts: u32,
+
+    /// Length in seconds of the fixed windows attempts are counted in, aligned to the
+    /// unix epoch. For example, `86400` counts attempts per UTC day.
+    pub attempt_window: u32,
+}
+
+impl RateLimit {
+    /// Size of a borsh-serialized [RateLimit].
+    pub const SIZE: usize = 4 + // min_interval
+        4 + // max_attempts
+        4; // attempt_window
+
+    /// Start of the attempt window containing `timestamp`, or [None] if attempts
+    /// aren't counted per window.
+    pub fn window_start(&self, timestamp: i64) -> Option<i64> {
+        if self.attempt_window == 0 {
+            return None;
+        }
+        Some(timestamp - timestamp.rem_euclid(i64::from(self.attempt_window)))
+    }
+}
+
+/// How the `u64` scores of a leaderboard are interpreted.
+///
+/// Scores are always stored and submitted as `u64`. On a [ScoreType::Signed] leaderboard
+/// they hold the two's complement bit pattern of an `i64`, i.e. `value as u64`. Together
+/// with the leaderboard's `decimals`, this also represents fixed-point values.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ScoreType {
+    /// Scores are unsigned integers.
+    #[default]
+    Unsigned,
+
+    /// Scores are signed integers, allowing negative values.
+    Signed,
+}
+
+impl ScoreType {
+    /// Size of a borsh-serialized [ScoreType].
+    pub const SIZE: usize = 1;
+
+    /// The numeric value of a stored `score`.
+    pub fn value(&self, score: u64) -> i128 {
+        match self {
+            ScoreType::Unsigned => i128::from(score),
+            ScoreType::Signed => i128::from(score as i64),
+        }
+    }
+
+    /// Store a numeric `value` as a score, saturating at this type's bounds.
+    pub fn from_value(&self, value: i128) -> u64 {
+        match self {
+            ScoreType::Unsigned => value.clamp(0, i128::from(u64::MAX)) as u64,
+            ScoreType::Signed => {
+                value.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64 as u64
+            }
+        }
+    }
+
+    /// The lowest score representable by this type.
+    pub fn min_score(&self) -> u64 {
+        match self {
+            ScoreType::Unsigned => u64::MIN,
+            ScoreType::Signed => i64::MIN as u64,
+        }
+    }
+
+    /// The highest score representable by this type.
+    pub fn max_score(&self) -> u64 {
+        match self {
+            ScoreType::Unsigned => u64::MAX,
+            ScoreType::Signed => i64::MAX as u64,
+        }
+    }
+
+    /// Compare two stored scores by their numeric value.
+    pub fn compare(&self, a: u64, b: u64) -> Ordering {
+        self.value(a).cmp(&self.value(b))
+    }
 }
 
 #[account]
@@ -29,13 +306,14 @@ pub struct LeaderBoard {
     /// Pubkey of an nft metadata account that describes this leaderboard.
     pub nft_meta: Pubkey,
 
-    /// Used to contextualize scores for this leaderboard.
+    /// Used to contextualize scores for this leaderboard, as the number of fixed-point
+    /// decimal places.
     pub decimals: u8,
 
-    /// Minimum possible score for this leaderboard.
+    /// Minimum possible score for this leaderboard, interpreted according to `score_type`.
     pub min_score: u64,
 
-    /// Maximum possible score for this leaderboard.
+    /// Maximum possible score for this leaderboard, interpreted according to `score_type`.
     pub max_score: u64,
 
     /// Top [entries](ScoreEntry) for a leaderboard.
@@ -43,6 +321,42 @@ pub struct LeaderBoard {
 
     /// Whether or not multiple scores are allowed for a single player.
     pub allow_multiple_scores: bool,
+
+    /// The current season number, starting at `1`. Also used as a seed
+    /// for the [SeasonArchive][super::SeasonArchive] created when it closes.
+    pub season: u64,
+
+    /// Timestamp at which the current season started.
+    pub season_start: i64,
+
+    /// Optional timestamp after which scores are no longer accepted for
+    /// the current season.
+    pub season_end: Option<i64>,
+
+    /// Set to true when the current season is closed and the leaderboard
+    /// is frozen until a new season is started.
+    pub is_frozen: bool,
+
+    /// Optional timestamp before which scores are not accepted.
+    pub opens_at: Option<i64>,
+
+    /// Optional timestamp after which scores are not accepted.
+    pub closes_at: Option<i64>,
+
+    /// Which scores each player's [PlayerScoresList][super::PlayerScoresList] keeps.
+    pub retention: RetentionPolicy,
+
+    /// What each player is ranked by in the leaderboard's top entries.
+    pub scoring_mode: ScoringMode,
+
+    /// How entries with equal scores are ordered in the leaderboard's top entries.
+    pub tie_break: TieBreak,
+
+    /// How scores submitted to this leaderboard are interpreted.
+    pub score_type: ScoreType,
+
+    /// Limits on how often scores can be submitted for each player.
+    pub rate_limit: RateLimit,
 }
 
 impl LeaderBoard {
@@ -56,7 +370,18 @@ impl LeaderBoard {
         8 +  // min_score
         8 +  // max_score
         1 + // allow_multiple_scores
-        1 + 32; // top_entries
+        1 + 32 + // top_entries
+        8 + // season
+        8 + // season_start
+        1 + 8 + // season_end
+        1 + // is_frozen
+        1 + 8 + // opens_at
+        1 + 8 + // closes_at
+        RetentionPolicy::SIZE + // retention
+        ScoringMode::SIZE + // scoring_mode
+        TieBreak::SIZE + // tie_break
+        ScoreType::SIZE + // score_type
+        RateLimit::SIZE; // rate_limit
 
     /// Create a new [LeaderBoard] instance.
     pub fn new(
@@ -78,6 +403,40 @@ impl LeaderBoard {
             max_score: max_score.unwrap_or(u64::MAX),
             allow_multiple_scores: false,
             top_entries: None,
+            season: 1,
+            season_start: 0,
+            season_end: None,
+            is_frozen: false,
+            opens_at: None,
+            closes_at: None,
+            retention: RetentionPolicy::Unbounded,
+            scoring_mode: ScoringMode::Best,
+            tie_break: TieBreak::EarlierWins,
+            score_type: ScoreType::Unsigned,
+            rate_limit: RateLimit::default(),
+        }
+    }
+
+    /// Check if `score` falls within this leaderboard's bounds.
+    pub fn is_within_bounds(&self, score: u64) -> bool {
+        let score_type = self.score_type;
+        score_type.compare(score, self.min_score) != Ordering::Less
+            && score_type.compare(score, self.max_score) != Ordering::Greater
+    }
+
+    /// Check if `timestamp` falls within this leaderboard's submission window.
+    pub fn is_open(&self, timestamp: i64) -> bool {
+        let not_yet_open = matches!(self.opens_at, Some(opens_at) if timestamp < opens_at);
+        let already_closed = matches!(self.closes_at, Some(closes_at) if timestamp > closes_at);
+        !not_yet_open && !already_closed
+    }
+
+    /// Check if scores can be submitted to this leaderboard's current season
+    /// at the given `timestamp`.
+    pub fn is_season_active(&self, timestamp: i64) -> bool {
+        match self.season_end {
+            Some(end) => !self.is_frozen && timestamp <= end,
+            None => !self.is_frozen,
         }
     }
 }
@@ -88,20 +447,68 @@ impl From<RegisterLeaderBoardInput> for LeaderBoard {
             description: input.description,
             nft_meta: input.nft_meta,
             decimals: input.decimals.unwrap_or(0),
-            min_score: input.min_score.unwrap_or(u64::MIN),
-            max_score: input.max_score.unwrap_or(u64::MAX),
+            min_score: input.min_score.unwrap_or(input.score_type.min_score()),
+            max_score: input.max_score.unwrap_or(input.score_type.max_score()),
             allow_multiple_scores: input.allow_multiple_scores,
+            season: 1,
+            season_end: input.season_end,
+            opens_at: input.opens_at,
+            closes_at: input.closes_at,
+            retention: input.retention,
+            scoring_mode: input.scoring_mode,
+            tie_break: input.tie_break,
+            score_type: input.score_type,
+            rate_limit: input.rate_limit,
             ..Default::default()
         }
     }
 }
 
+impl LegacyScoreEntry {
+    /// Size of a [LegacyScoreEntry].
+    pub const SIZE: usize = 8 + // score
+        8; // timestamp
+}
+
+impl From<LegacyScoreEntry> for ScoreEntry {
+    fn from(entry: LegacyScoreEntry) -> Self {
+        ScoreEntry::new(entry.score, entry.timestamp)
+    }
+}
+
 impl ScoreEntry {
     /// Size of a [ScoreEntry].
-    pub const SIZE: usize = 8 + 8;
+    pub const SIZE: usize = 8 + // score
+        8 + // timestamp
+        8 + // secondary_score
+        32 + // context
+        1 + // has_secondary_score
+        7; // padding
 
-    /// Create a new instance of self.
+    /// Create a new instance of self with no secondary score or context.
     pub fn new(score: u64, timestamp: i64) -> Self {
-        ScoreEntry { score, timestamp }
+        Self::with_details(score, timestamp, None, [0; 32])
+    }
+
+    /// Create a new instance of self with an optional secondary score and context.
+    pub fn with_details(
+        score: u64,
+        timestamp: i64,
+        secondary_score: Option<u64>,
+        context: [u8; 32],
+    ) -> Self {
+        ScoreEntry {
+            score,
+            timestamp,
+            secondary_score: secondary_score.unwrap_or_default(),
+            context,
+            has_secondary_score: u8::from(secondary_score.is_some()),
+            padding: [0; 7],
+        }
+    }
+
+    /// The secondary score, or [None] if it wasn't provided.
+    pub fn secondary_score(&self) -> Option<u64> {
+        (self.has_secondary_score != 0).then_some(self.secondary_score)
     }
 }
@@ -1,4 +1,4 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
 
 /// An account that represents a single user's ownership of
 /// multiple [Player][super::Player] accounts.
@@ -13,6 +13,29 @@ pub struct Merged {
 
     /// Set to true when every user in `others` has registered their approval.
     pub merge_complete: bool,
+
+    /// The account that paid rent for this merge and is refunded when it's closed.
+    pub payer: Pubkey,
+
+    /// Timestamp after which a pending merge can no longer be approved and can be
+    /// closed by anyone.
+    pub expires_at: i64,
+}
+
+/// Layout of a [Merged] account before rent refunds and expiry were added.
+///
+/// Legacy accounts share [Merged]'s discriminator and are told apart by their size. They're
+/// only read to be converted in place with `migrate_merge`.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct LegacyMerged {
+    /// The user that initialized this merge.
+    pub initiator: Pubkey,
+
+    /// Details of all the player accounts to be merged with the main_user's.
+    pub approvals: Vec<MergeApproval>,
+
+    /// Set to true when every user in `others` has registered their approval.
+    pub merge_complete: bool,
 }
 
 /// Represents a [Player][super::Player] account involved in a merge
@@ -27,6 +50,9 @@ pub struct MergeApproval {
 }
 
 impl Merged {
+    /// Duration in seconds a merge stays open for approvals.
+    pub const EXPIRY_SECONDS: i64 = 7 * 24 * 60 * 60;
+
     /// Calculate the size of a [Merged] account given a `count`
     /// of the [MergeApproval] accounts it intends to hold.
     pub fn size(count: usize) -> usize {
@@ -34,6 +60,56 @@ impl Merged {
         32 + // initiator
         4 + (count * MergeApproval::SIZE) // approvals vec
         + 1 // merge_complete
+        + 32 // payer
+        + 8 // expires_at
+    }
+
+    /// Convert a legacy merge, refunding its rent to the initiator when it's closed.
+    /// Pending merges expire [Self::EXPIRY_SECONDS] after `timestamp`.
+    pub fn from_legacy(legacy: LegacyMerged, timestamp: i64) -> Self {
+        Merged {
+            initiator: legacy.initiator,
+            approvals: legacy.approvals,
+            merge_complete: legacy.merge_complete,
+            payer: legacy.initiator,
+            expires_at: timestamp.checked_add(Self::EXPIRY_SECONDS).unwrap(),
+        }
+    }
+
+    /// Check if this merge is still pending and past its expiry at `timestamp`.
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        !self.merge_complete && timestamp > self.expires_at
+    }
+
+    /// Check if `player_account` is one of the accounts merged into the initiator's.
+    pub fn contains(&self, player_account: &Pubkey) -> bool {
+        self.approvals.iter().any(|one| one.key == *player_account)
+    }
+}
+
+impl LegacyMerged {
+    /// Calculate the size of a legacy [Merged] account holding `count` approvals.
+    pub fn size(count: usize) -> usize {
+        8 + // discriminator
+        32 + // initiator
+        4 + (count * MergeApproval::SIZE) // approvals vec
+        + 1 // merge_complete
+    }
+
+    /// Decode a [Merged] account's data, failing unless it's in the legacy layout.
+    pub fn try_from_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.get(..8) == Some(&Merged::discriminator()[..]),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let legacy = Self::deserialize(&mut &data[8..])
+            .map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))?;
+        require!(
+            data.len() == Self::size(legacy.approvals.len()),
+            ErrorCode::AccountDidNotDeserialize
+        );
+
+        Ok(legacy)
     }
 }
 
@@ -49,3 +125,31 @@ impl MergeApproval {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_merges_decode_from_baseline_bytes() {
+        let initiator = Pubkey::new_unique();
+        let approvals = vec![MergeApproval::new(Pubkey::new_unique())];
+        let mut data = Merged::discriminator().to_vec();
+        data.extend_from_slice(initiator.as_ref());
+        approvals.serialize(&mut data).unwrap();
+        data.push(0);
+        assert_eq!(data.len(), LegacyMerged::size(1));
+        assert!(Merged::try_deserialize(&mut &data[..]).is_err());
+
+        let merge = Merged::from_legacy(LegacyMerged::try_from_data(&data).unwrap(), 100);
+        assert_eq!((merge.initiator, merge.payer), (initiator, initiator));
+        assert!(merge.contains(&approvals[0].key));
+        assert!(!merge.is_expired(100 + Merged::EXPIRY_SECONDS));
+        assert!(merge.is_expired(101 + Merged::EXPIRY_SECONDS));
+
+        let mut current = Vec::new();
+        merge.try_serialize(&mut current).unwrap();
+        assert_eq!(current.len(), Merged::size(1));
+        assert!(LegacyMerged::try_from_data(&current).is_err());
+    }
+}
@@ -7,7 +7,11 @@ mod merge;
 mod player;
 mod player_achievement;
 mod player_scores_list;
+mod proposal;
+mod rank_reward;
+mod ranking;
 mod reward;
+mod season_archive;
 mod top_entries;
 
 pub use achievement::*;
@@ -19,7 +23,11 @@ pub use merge::*;
 pub use player::*;
 pub use player_achievement::*;
 pub use player_scores_list::*;
+pub use proposal::*;
+pub use rank_reward::*;
+pub use ranking::*;
 pub use reward::*;
+pub use season_archive::*;
 pub use top_entries::*;
 
 use anchor_lang::prelude::*;
@@ -36,20 +44,117 @@ pub struct RegisterLeaderBoardInput {
     /// Specify the decimals score values are represented in. Defaults to `0` if [None].
     pub decimals: Option<u8>,
 
-    /// Specifies minimum allowed score. Defaults to `u64::MIN` if [None].
+    /// Specifies minimum allowed score. Defaults to the lowest score representable by
+    /// `score_type` if [None].
     pub min_score: Option<u64>,
 
-    /// Specifies maximum allowed score. Defaults to `u64::MAX` if [None].
+    /// Specifies maximum allowed score. Defaults to the highest score representable by
+    /// `score_type` if [None].
     pub max_score: Option<u64>,
 
-    /// Number of top scores to store on-chain.
-    pub scores_to_retain: u8,
+    /// Number of top scores to store on-chain. Can be changed later with `resize_top_entries`.
+    pub scores_to_retain: u16,
 
     /// Order by which scores are stored. `true` for ascending, `false` for descending.
     pub is_ascending: bool,
 
     /// Whether or not multiple scores are kept in the leaderboard for a single player.
     pub allow_multiple_scores: bool,
+
+    /// Optional end timestamp for the leaderboard's first season.
+    pub season_end: Option<i64>,
+
+    /// Optional timestamp before which scores are rejected.
+    pub opens_at: Option<i64>,
+
+    /// Optional timestamp after which scores are rejected.
+    pub closes_at: Option<i64>,
+
+    /// Which scores are kept in each player's [PlayerScoresList].
+    pub retention: RetentionPolicy,
+
+    /// What players are ranked by in the top entries. Modes other than
+    /// [ScoringMode::Best] require `allow_multiple_scores` to be false.
+    pub scoring_mode: ScoringMode,
+
+    /// How entries with equal scores are ordered in the top entries.
+    pub tie_break: TieBreak,
+
+    /// How submitted scores are interpreted. Can't be changed later.
+    pub score_type: ScoreType,
+
+    /// Limits on how often scores can be submitted for each player.
+    pub rate_limit: RateLimit,
+}
+
+/// Parameters for updating a leaderboard. Fields set to [None] are left unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct UpdateLeaderBoardInput {
+    /// New leaderboard description.
+    pub new_description: Option<String>,
+
+    /// New nft metadata representing the leaderboard.
+    pub new_nft_meta: Option<Pubkey>,
+
+    /// New minimum allowed score.
+    pub new_min_score: Option<u64>,
+
+    /// New maximum allowed score.
+    pub new_max_score: Option<u64>,
+
+    /// New arrangement order for the leaderboard's top entries. Can't be changed while the
+    /// season is closed.
+    pub new_is_ascending: Option<bool>,
+
+    /// Whether or not multiple scores are kept in the leaderboard for a single player.
+    pub new_allow_multiple_scores: Option<bool>,
+
+    /// New opening timestamp. `Some(None)` removes the lower bound.
+    pub new_opens_at: Option<Option<i64>>,
+
+    /// New closing timestamp. `Some(None)` removes the upper bound.
+    pub new_closes_at: Option<Option<i64>>,
+
+    /// New retention policy. Existing [PlayerScoresList]s are trimmed on their next submission.
+    pub new_retention: Option<RetentionPolicy>,
+
+    /// New scoring mode. Existing top entries are kept as-is.
+    pub new_scoring_mode: Option<ScoringMode>,
+
+    /// New tie-break rule. The top entries are re-sorted under it, so it can't be changed
+    /// while the season is closed.
+    pub new_tie_break: Option<TieBreak>,
+
+    /// New submission rate limits, applied from the next submission.
+    pub new_rate_limit: Option<RateLimit>,
+}
+
+/// A score signed off-chain by a [Game] authority with the
+/// [attester][GameAuthority::ATTESTER] role, allowing players to submit it themselves.
+///
+/// The signed message is the borsh serialization of this struct.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScoreAttestation {
+    /// The player account the score is for.
+    pub player_account: Pubkey,
+
+    /// The leaderboard the score is for.
+    pub leaderboard: Pubkey,
+
+    /// The attested score.
+    pub score: u64,
+
+    /// The attested secondary score, if any.
+    pub secondary_score: Option<u64>,
+
+    /// The attested context. All zeroes if not provided.
+    pub context: [u8; 32],
+
+    /// Must be greater than the player's `last_nonce` for this leaderboard.
+    pub nonce: u64,
+
+    /// Timestamp after which the attestation can no longer be submitted.
+    pub expiry: i64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -61,6 +166,15 @@ pub struct AddNewRewardInput {
     pub kind: RewardKindInput,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+/// Input to add a new rank-based reward for a leaderboard season.
+pub struct AddRankRewardInput {
+    /// Amount to be delegated to this program's PDA so it can spend for reward claims.
+    pub deposit: u64,
+    /// Payout table, mapping ranges of ranks to reward amounts.
+    pub tiers: Vec<RankRewardTier>,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 /// Specific variant of [AddNewRewardInput].
 pub enum RewardKindInput {
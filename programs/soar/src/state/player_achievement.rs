@@ -1,4 +1,4 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
 
 #[account]
 #[derive(Debug, Default)]
@@ -20,6 +20,72 @@ pub struct PlayerAchievement {
 
     /// Whether or not this player has claimed their reward.
     pub claimed: bool,
+
+    /// Progress towards the achievement's `progress_target`, if it has one.
+    pub progress: u64,
+
+    /// Whether this account's state has been merged into another player's account.
+    pub absorbed: bool,
+}
+
+/// Layout of a [PlayerAchievement] before progress tracking and merges were added.
+///
+/// Legacy accounts share [PlayerAchievement]'s discriminator and are told apart by their
+/// size. They're only read to be converted in place with `migrate_player_achievement`.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct LegacyPlayerAchievement {
+    /// The user's [player][super::Player] account.
+    pub player_account: Pubkey,
+
+    /// The key of the achievement unlocked for this player.
+    pub achievement: Pubkey,
+
+    /// Timestamp showing when this achievement was unlocked.
+    pub timestamp: i64,
+
+    /// A player's unlock status for this achievement.
+    pub unlocked: bool,
+
+    /// Whether or not this player has claimed their reward.
+    pub claimed: bool,
+}
+
+impl LegacyPlayerAchievement {
+    /// Size of a legacy playerAchievement account.
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // player
+        32 + // achievement
+        8 +  // timestamp
+        1 +  // unlocked
+        1; // claimed
+
+    /// Decode a [PlayerAchievement] account's data, failing unless it's in the legacy layout.
+    pub fn try_from_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.get(..8) == Some(&PlayerAchievement::discriminator()[..]),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(
+            data.len() == Self::SIZE,
+            ErrorCode::AccountDidNotDeserialize
+        );
+
+        Self::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+impl From<LegacyPlayerAchievement> for PlayerAchievement {
+    fn from(legacy: LegacyPlayerAchievement) -> Self {
+        Self {
+            player_account: legacy.player_account,
+            achievement: legacy.achievement,
+            timestamp: legacy.timestamp,
+            unlocked: legacy.unlocked,
+            claimed: legacy.claimed,
+            progress: 0,
+            absorbed: false,
+        }
+    }
 }
 
 impl PlayerAchievement {
@@ -29,7 +95,9 @@ impl PlayerAchievement {
         32 + // achievement
         8 +  // timestamp
         1 +  // unlocked
-        1; // claimed
+        1 +  // claimed
+        8 +  // progress
+        1; // absorbed
 
     pub fn new(player_account: Pubkey, achievement: Pubkey, timestamp: i64) -> Self {
         Self {
@@ -38,6 +106,99 @@ impl PlayerAchievement {
             timestamp,
             unlocked: true,
             claimed: false,
+            progress: 0,
+            absorbed: false,
         }
     }
+
+    /// Fold the state of `other`, belonging to a player merged into this one, into self.
+    ///
+    /// `other` is left with no progress and marked as absorbed so its reward can only be
+    /// claimed through this account. Accounts that were already absorbed are skipped.
+    pub fn absorb(&mut self, other: &mut PlayerAchievement) {
+        if other.absorbed {
+            return;
+        }
+        if other.unlocked && (!self.unlocked || other.timestamp < self.timestamp) {
+            self.timestamp = other.timestamp;
+        }
+        self.unlocked = self.unlocked || other.unlocked;
+        self.claimed = self.claimed || other.claimed;
+        self.progress = self.progress.saturating_add(other.progress);
+
+        other.progress = 0;
+        other.absorbed = true;
+    }
+
+    /// Add `amount` to this player's progress, unlocking the achievement at `timestamp`
+    /// if `target` is reached.
+    pub fn add_progress(&mut self, amount: u64, target: u64, timestamp: i64) {
+        self.progress = self.progress.saturating_add(amount);
+        if !self.unlocked && self.progress >= target {
+            self.unlocked = true;
+            self.timestamp = timestamp;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unlocked(timestamp: i64, claimed: bool) -> PlayerAchievement {
+        PlayerAchievement {
+            claimed,
+            ..PlayerAchievement::new(Pubkey::new_unique(), Pubkey::new_unique(), timestamp)
+        }
+    }
+
+    #[test]
+    fn absorb_keeps_the_earliest_unlock_and_real_claims() {
+        let mut initiator = PlayerAchievement::default();
+        let mut merged = unlocked(5, true);
+        merged.progress = 3;
+
+        initiator.absorb(&mut merged);
+        assert!(initiator.unlocked && initiator.claimed);
+        assert_eq!((initiator.timestamp, initiator.progress), (5, 3));
+        assert!(merged.absorbed);
+        assert_eq!(merged.progress, 0);
+    }
+
+    #[test]
+    fn absorbing_twice_changes_nothing() {
+        let mut initiator = unlocked(10, false);
+        let mut merged = unlocked(5, false);
+        merged.progress = 3;
+
+        initiator.absorb(&mut merged);
+        initiator.absorb(&mut merged);
+        assert!(!initiator.claimed);
+        assert!(!merged.claimed);
+        assert_eq!((initiator.timestamp, initiator.progress), (5, 3));
+    }
+
+    #[test]
+    fn legacy_achievements_decode_from_baseline_bytes() {
+        let (player_account, achievement) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = PlayerAchievement::discriminator().to_vec();
+        data.extend_from_slice(player_account.as_ref());
+        data.extend_from_slice(achievement.as_ref());
+        data.extend_from_slice(&7i64.to_le_bytes());
+        data.extend_from_slice(&[1, 1]);
+        assert!(PlayerAchievement::try_deserialize(&mut &data[..]).is_err());
+
+        let migrated =
+            PlayerAchievement::from(LegacyPlayerAchievement::try_from_data(&data).unwrap());
+        assert_eq!(migrated.player_account, player_account);
+        assert_eq!(migrated.achievement, achievement);
+        assert_eq!(migrated.timestamp, 7);
+        assert!(migrated.unlocked && migrated.claimed);
+        assert!(!migrated.absorbed);
+
+        let mut current = Vec::new();
+        migrated.try_serialize(&mut current).unwrap();
+        assert_eq!(current.len(), PlayerAchievement::SIZE);
+        assert!(LegacyPlayerAchievement::try_from_data(&current).is_err());
+    }
 }
@@ -1,5 +1,8 @@
-use super::ScoreEntry;
-use anchor_lang::prelude::*;
+use super::{LegacyScoreEntry, RateLimit, RetentionPolicy, ScoreEntry, ScoreType};
+use anchor_lang::{
+    prelude::*, solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE, Discriminator,
+};
+use std::cmp::Ordering;
 
 #[account]
 #[derive(Debug, Default)]
@@ -17,16 +20,117 @@ pub struct PlayerScoresList {
     /// Max number of [scores][ScoreEntry] the current space allocation supports.
     pub alloc_count: u16,
 
+    /// Nonce of the last [attestation][super::ScoreAttestation] submitted for this player.
+    /// New attestations must use a greater nonce.
+    pub last_nonce: u64,
+
+    /// Best score ever submitted, ranked in the leaderboard's top-entries order or
+    /// highest-first if it has none. Zero if nothing was submitted.
+    pub best_score: u64,
+
+    /// Sum of the values of all scores ever submitted, interpreted according to
+    /// the leaderboard's [ScoreType].
+    pub total_score: i128,
+
+    /// Number of scores ever submitted, including ones no longer retained.
+    pub submission_count: u64,
+
+    /// Timestamp of the first submission. Zero if nothing was submitted.
+    pub first_submission: i64,
+
+    /// Timestamp of the latest submission. Zero if nothing was submitted.
+    pub last_submission: i64,
+
+    /// Start of the [attempt window][RateLimit::attempt_window] `window_attempts` counts.
+    pub window_start: i64,
+
+    /// Number of submissions within the attempt window starting at `window_start`.
+    pub window_attempts: u32,
+
+    /// The leaderboard season `season_total_score` and `season_submission_count` cover.
+    pub season: u64,
+
+    /// Sum of the values of the scores submitted during `season`, interpreted according to
+    /// the leaderboard's [ScoreType].
+    pub season_total_score: i128,
+
+    /// Number of scores submitted during `season`.
+    pub season_submission_count: u64,
+
     /// Collection of [scores][ScoreEntry].
     pub scores: Vec<ScoreEntry>,
 }
 
+/// Layout of a [PlayerScoresList] before aggregates, attestation nonces and rate limits
+/// were added.
+///
+/// Legacy accounts share [PlayerScoresList]'s discriminator and are told apart by their size:
+/// `migrate_player_scores` grows them in steps before converting them, so any size from
+/// [Self::size] up to, but excluding, [PlayerScoresList::size] for their `alloc_count` is legacy.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct LegacyPlayerScoresList {
+    /// The player[super::Player] account this entry is derived from
+    pub player_account: Pubkey,
+
+    /// The id of the specific leaderboard.
+    pub leaderboard: Pubkey,
+
+    /// Max number of [scores][LegacyScoreEntry] the current space allocation supports.
+    pub alloc_count: u16,
+
+    /// Collection of [scores][LegacyScoreEntry].
+    pub scores: Vec<LegacyScoreEntry>,
+}
+
+impl LegacyPlayerScoresList {
+    /// Base size of a legacy account without counting the scores list.
+    pub const SIZE_WITHOUT_VEC: usize = 8 + // discriminator
+        32 + // player_account
+        32 + // leaderboard
+        2; // alloc_count
+
+    /// Size of a legacy account with space for `alloc_count` scores.
+    pub const fn size(alloc_count: usize) -> usize {
+        Self::SIZE_WITHOUT_VEC + 4 + alloc_count * LegacyScoreEntry::SIZE
+    }
+
+    /// Decode a [PlayerScoresList] account's data, failing unless it's in the legacy layout.
+    pub fn try_from_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.get(..8) == Some(&PlayerScoresList::discriminator()[..]),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let alloc_count = data
+            .get(Self::SIZE_WITHOUT_VEC - 2..Self::SIZE_WITHOUT_VEC)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        let alloc_count = alloc_count as usize;
+        require!(
+            (Self::size(alloc_count)..PlayerScoresList::size(alloc_count)).contains(&data.len()),
+            ErrorCode::AccountDidNotDeserialize
+        );
+
+        Self::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
 impl PlayerScoresList {
     /// Base size of this account without counting the scores list.
     pub const SIZE_WITHOUT_VEC: usize = 8 + // discriminator
         32 + // player_account
         32 + // leaderboard
-        2; // alloc_count
+        2 + // alloc_count
+        8 + // last_nonce
+        8 + // best_score
+        16 + // total_score
+        8 + // submission_count
+        8 + // first_submission
+        8 + // last_submission
+        8 + // window_start
+        4 + // window_attempts
+        8 + // season
+        16 + // season_total_score
+        8; // season_submission_count
 
     /// Initial number of scores[ScoreEntry] space is allocated for.
     pub const INITIAL_SCORES_LENGTH: usize = 10;
@@ -34,25 +138,533 @@ impl PlayerScoresList {
     /// Increase space to accommodate this number more  during a resize.
     pub const REALLOC_WINDOW: usize = 10;
 
+    /// Maximum number of scores a bounded [RetentionPolicy] can keep, so the list
+    /// always fits within a single account initialization.
+    pub const MAX_RETAINED_SCORES: usize =
+        (MAX_PERMITTED_DATA_INCREASE - Self::SIZE_WITHOUT_VEC - 4) / ScoreEntry::SIZE;
+
+    /// Number of scores space is allocated for during account initialization.
+    pub fn initial_length(retention: &RetentionPolicy) -> usize {
+        match retention.max_scores() {
+            Some(max) => max.min(Self::INITIAL_SCORES_LENGTH),
+            None => Self::INITIAL_SCORES_LENGTH,
+        }
+    }
+
     /// Calculate the space required during account initialization
-    pub fn initial_size() -> usize {
+    pub fn initial_size(retention: &RetentionPolicy) -> usize {
         Self::SIZE_WITHOUT_VEC + // base size.
-        4 + (Self::INITIAL_SCORES_LENGTH * ScoreEntry::SIZE) // size of scores vec.
+        4 + (Self::initial_length(retention) * ScoreEntry::SIZE) // size of scores vec.
+    }
+
+    /// Size of an account with space for `alloc_count` scores.
+    pub const fn size(alloc_count: usize) -> usize {
+        Self::SIZE_WITHOUT_VEC + // base size.
+        4 + (alloc_count * ScoreEntry::SIZE) // size of scores vec.
     }
 
     /// Gets the current size of a [PlayerScoresList] account.
     pub fn current_size(&self) -> usize {
-        Self::SIZE_WITHOUT_VEC + // base size.
-        4 + (self.alloc_count as usize * ScoreEntry::SIZE) // size of scores vec.
+        Self::size(self.alloc_count as usize)
     }
 
     /// Create a new instance of Self.
-    pub fn new(player_account: Pubkey, leaderboard: Pubkey) -> Self {
+    pub fn new(player_account: Pubkey, leaderboard: Pubkey, retention: &RetentionPolicy) -> Self {
+        let length = Self::initial_length(retention);
         PlayerScoresList {
             player_account,
             leaderboard,
-            alloc_count: Self::INITIAL_SCORES_LENGTH as u16,
-            scores: Vec::with_capacity(Self::INITIAL_SCORES_LENGTH),
+            alloc_count: length as u16,
+            last_nonce: 0,
+            best_score: 0,
+            total_score: 0,
+            submission_count: 0,
+            first_submission: 0,
+            last_submission: 0,
+            window_start: 0,
+            window_attempts: 0,
+            season: 0,
+            season_total_score: 0,
+            season_submission_count: 0,
+            scores: Vec::with_capacity(length),
+        }
+    }
+
+    /// Convert a legacy list, rebuilding the aggregates from the scores it holds and
+    /// keeping those retained under `retention`.
+    ///
+    /// Legacy scores predate seasons, so they all count towards the current `season`.
+    pub fn from_legacy(
+        legacy: LegacyPlayerScoresList,
+        season: u64,
+        retention: &RetentionPolicy,
+        score_type: ScoreType,
+        is_ascending: bool,
+    ) -> Self {
+        let mut list = Self::new(legacy.player_account, legacy.leaderboard, retention);
+        list.season = season;
+        for entry in legacy.scores {
+            let entry = ScoreEntry::from(entry);
+            list.record(&entry, score_type, is_ascending);
+            list.scores.push(entry);
+        }
+        list.apply_retention(retention, score_type, is_ascending);
+        list.alloc_count = u16::try_from(list.scores.len().max(list.alloc_count as usize)).unwrap();
+        list
+    }
+
+    /// Start counting season aggregates for `season` if they were recorded for another one.
+    pub fn roll_season(&mut self, season: u64) {
+        if self.season != season {
+            self.season = season;
+            self.season_total_score = 0;
+            self.season_submission_count = 0;
+        }
+    }
+
+    /// Update the running aggregates with a newly submitted `entry`.
+    ///
+    /// The entry is counted towards the current `season`, so the list must have been rolled
+    /// to the leaderboard's season first.
+    pub fn record(&mut self, entry: &ScoreEntry, score_type: ScoreType, is_ascending: bool) {
+        let is_better = if is_ascending {
+            score_type.compare(entry.score, self.best_score) == Ordering::Less
+        } else {
+            score_type.compare(entry.score, self.best_score) == Ordering::Greater
+        };
+        if self.submission_count == 0 || is_better {
+            self.best_score = entry.score;
+        }
+        if self.submission_count == 0 {
+            self.first_submission = entry.timestamp;
+        }
+
+        let value = score_type.value(entry.score);
+        self.total_score = self.total_score.checked_add(value).unwrap();
+        self.submission_count = self.submission_count.checked_add(1).unwrap();
+        self.season_total_score = self.season_total_score.checked_add(value).unwrap();
+        self.season_submission_count = self.season_submission_count.checked_add(1).unwrap();
+        self.last_submission = self.last_submission.max(entry.timestamp);
+    }
+
+    /// Fold `other`'s aggregates into this list's, resetting `other`'s.
+    pub fn absorb_aggregates(
+        &mut self,
+        other: &mut PlayerScoresList,
+        score_type: ScoreType,
+        is_ascending: bool,
+    ) {
+        if other.submission_count == 0 {
+            return;
+        }
+
+        if self.submission_count == 0 {
+            self.best_score = other.best_score;
+            self.first_submission = other.first_submission;
+        } else {
+            let other_is_better = if is_ascending {
+                score_type.compare(other.best_score, self.best_score) == Ordering::Less
+            } else {
+                score_type.compare(other.best_score, self.best_score) == Ordering::Greater
+            };
+            if other_is_better {
+                self.best_score = other.best_score;
+            }
+            self.first_submission = self.first_submission.min(other.first_submission);
+        }
+        self.total_score = self.total_score.checked_add(other.total_score).unwrap();
+        self.submission_count = self
+            .submission_count
+            .checked_add(other.submission_count)
+            .unwrap();
+        self.last_submission = self.last_submission.max(other.last_submission);
+        if other.season > self.season {
+            self.season = other.season;
+            self.season_total_score = other.season_total_score;
+            self.season_submission_count = other.season_submission_count;
+        } else if other.season == self.season {
+            self.season_total_score = self
+                .season_total_score
+                .checked_add(other.season_total_score)
+                .unwrap();
+            self.season_submission_count = self
+                .season_submission_count
+                .checked_add(other.season_submission_count)
+                .unwrap();
+        }
+        // Attempts are only counted for the window starting at `window_start`, so counts from
+        // the same window add up, while an older window's count no longer limits anything.
+        if other.window_start > self.window_start {
+            self.window_start = other.window_start;
+            self.window_attempts = other.window_attempts;
+        } else if other.window_start == self.window_start {
+            self.window_attempts = self.window_attempts.saturating_add(other.window_attempts);
+        }
+
+        other.best_score = 0;
+        other.total_score = 0;
+        other.submission_count = 0;
+        other.first_submission = 0;
+        other.last_submission = 0;
+        other.window_start = 0;
+        other.window_attempts = 0;
+        other.season_total_score = 0;
+        other.season_submission_count = 0;
+    }
+
+    /// Whether a submission at `timestamp` respects `rate_limit`'s minimum interval.
+    pub fn is_past_min_interval(&self, rate_limit: &RateLimit, timestamp: i64) -> bool {
+        self.submission_count == 0
+            || timestamp
+                >= self
+                    .last_submission
+                    .saturating_add(i64::from(rate_limit.min_interval))
+    }
+
+    /// Number of submissions made within the attempt window containing `timestamp`,
+    /// or ever if `rate_limit` doesn't count attempts per window.
+    pub fn attempts(&self, rate_limit: &RateLimit, timestamp: i64) -> u64 {
+        match rate_limit.window_start(timestamp) {
+            Some(start) if start == self.window_start => u64::from(self.window_attempts),
+            Some(_) => 0,
+            None => self.submission_count,
+        }
+    }
+
+    /// Whether `rate_limit` leaves an attempt for a submission at `timestamp`.
+    pub fn has_attempts_left(&self, rate_limit: &RateLimit, timestamp: i64) -> bool {
+        rate_limit.max_attempts == 0
+            || self.attempts(rate_limit, timestamp) < u64::from(rate_limit.max_attempts)
+    }
+
+    /// Count a submission at `timestamp` towards `rate_limit`'s current attempt window.
+    pub fn count_attempt(&mut self, rate_limit: &RateLimit, timestamp: i64) {
+        if let Some(start) = rate_limit.window_start(timestamp) {
+            if start != self.window_start {
+                self.window_start = start;
+                self.window_attempts = 0;
+            }
+            self.window_attempts = self.window_attempts.saturating_add(1);
+        }
+    }
+
+    /// Average of all scores ever submitted, or [None] if nothing was submitted.
+    pub fn average_score(&self) -> Option<i128> {
+        self.total_score.checked_div(self.submission_count as i128)
+    }
+
+    /// Number of [scores][ScoreEntry] space must be allocated for to hold the current list,
+    /// or [None] if the current allocation is enough.
+    ///
+    /// Grows by [Self::REALLOC_WINDOW] at a time, capped at the policy's maximum.
+    pub fn required_alloc_count(&self, retention: &RetentionPolicy) -> Option<usize> {
+        let alloc_count = self.alloc_count as usize;
+        if self.scores.len() <= alloc_count {
+            return None;
+        }
+
+        let grown = alloc_count.checked_add(Self::REALLOC_WINDOW).unwrap();
+        let capped = match retention.max_scores() {
+            Some(max) => grown.min(max),
+            None => grown,
+        };
+        Some(capped.max(self.scores.len()))
+    }
+
+    /// Drop scores that aren't kept under `retention`, leaving the rest in
+    /// chronological order.
+    ///
+    /// `score_type` and `is_ascending` decide which scores are best for
+    /// [RetentionPolicy::KeepBest].
+    pub fn apply_retention(
+        &mut self,
+        retention: &RetentionPolicy,
+        score_type: ScoreType,
+        is_ascending: bool,
+    ) {
+        match retention {
+            RetentionPolicy::Unbounded => (),
+            RetentionPolicy::KeepLast { count } => {
+                let excess = self.scores.len().saturating_sub(*count as usize);
+                self.scores.drain(..excess);
+            }
+            RetentionPolicy::KeepBest { count } => {
+                if self.scores.len() > *count as usize {
+                    if is_ascending {
+                        self.scores
+                            .sort_by(|a, b| score_type.compare(a.score, b.score));
+                    } else {
+                        self.scores
+                            .sort_by(|a, b| score_type.compare(b.score, a.score));
+                    }
+                    self.scores.truncate(*count as usize);
+                    self.scores.sort_by_key(|entry| entry.timestamp);
+                }
+            }
+            RetentionPolicy::AggregateOnly => self.scores.clear(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A list as written by the original, pre-aggregate layout.
+    fn legacy_bytes(
+        player_account: Pubkey,
+        leaderboard: Pubkey,
+        alloc_count: u16,
+        scores: &[(u64, i64)],
+    ) -> Vec<u8> {
+        let mut data = PlayerScoresList::discriminator().to_vec();
+        data.extend_from_slice(player_account.as_ref());
+        data.extend_from_slice(leaderboard.as_ref());
+        data.extend_from_slice(&alloc_count.to_le_bytes());
+        data.extend_from_slice(&(scores.len() as u32).to_le_bytes());
+        for (score, timestamp) in scores {
+            data.extend_from_slice(&score.to_le_bytes());
+            data.extend_from_slice(&timestamp.to_le_bytes());
+        }
+        data.resize(LegacyPlayerScoresList::size(alloc_count as usize), 0);
+        data
+    }
+
+    fn recorded(
+        scores: &[(u64, i64)],
+        score_type: ScoreType,
+        is_ascending: bool,
+    ) -> PlayerScoresList {
+        let mut list = PlayerScoresList::default();
+        for (score, timestamp) in scores {
+            list.record(
+                &ScoreEntry::new(*score, *timestamp),
+                score_type,
+                is_ascending,
+            );
+        }
+        list
+    }
+
+    #[test]
+    fn first_submission_sets_every_aggregate() {
+        // Even a score worse than the zeroed best score is taken on the first submission.
+        let list = recorded(&[(-4i64 as u64, 3)], ScoreType::Signed, false);
+        assert_eq!(list.best_score, -4i64 as u64);
+        assert_eq!((list.total_score, list.submission_count), (-4, 1));
+        assert_eq!((list.first_submission, list.last_submission), (3, 3));
+    }
+
+    #[test]
+    fn best_score_follows_the_ranking_order() {
+        let descending = recorded(&[(10, 1), (30, 2), (20, 3)], ScoreType::Unsigned, false);
+        assert_eq!(descending.best_score, 30);
+
+        let ascending = recorded(&[(10, 1), (30, 2), (5, 3)], ScoreType::Unsigned, true);
+        assert_eq!(ascending.best_score, 5);
+        let ascending = recorded(&[(10, 1), (30, 2)], ScoreType::Unsigned, true);
+        assert_eq!(ascending.best_score, 10);
+
+        assert_eq!(descending.total_score, 60);
+        assert_eq!(
+            (descending.first_submission, descending.last_submission),
+            (1, 3)
+        );
+    }
+
+    #[test]
+    fn average_score_of_an_empty_list_is_none() {
+        assert_eq!(PlayerScoresList::default().average_score(), None);
+        let list = recorded(&[(3, 1), (4, 2)], ScoreType::Unsigned, false);
+        assert_eq!(list.average_score(), Some(3));
+        let list = recorded(
+            &[(-3i64 as u64, 1), (-6i64 as u64, 2)],
+            ScoreType::Signed,
+            false,
+        );
+        assert_eq!(list.average_score(), Some(-4));
+    }
+
+    #[test]
+    fn absorb_aggregates_combines_both_lists() {
+        let mut list = recorded(&[(10, 5)], ScoreType::Unsigned, true);
+        let mut other = recorded(&[(20, 2), (4, 8)], ScoreType::Unsigned, true);
+        list.absorb_aggregates(&mut other, ScoreType::Unsigned, true);
+        assert_eq!(list.best_score, 4);
+        assert_eq!((list.total_score, list.submission_count), (34, 3));
+        assert_eq!((list.first_submission, list.last_submission), (2, 8));
+        assert_eq!((other.submission_count, other.total_score), (0, 0));
+
+        // Absorbing an emptied list changes nothing.
+        list.absorb_aggregates(&mut other, ScoreType::Unsigned, true);
+        assert_eq!(list.submission_count, 3);
+
+        let mut empty = PlayerScoresList::default();
+        let mut other = recorded(&[(7, 1)], ScoreType::Unsigned, false);
+        empty.absorb_aggregates(&mut other, ScoreType::Unsigned, false);
+        assert_eq!((empty.best_score, empty.first_submission), (7, 1));
+    }
+
+    fn rate_limit(min_interval: u32, max_attempts: u32, attempt_window: u32) -> RateLimit {
+        RateLimit {
+            min_interval,
+            max_attempts,
+            attempt_window,
+        }
+    }
+
+    #[test]
+    fn min_interval_counts_from_the_last_submission() {
+        let limit = rate_limit(10, 0, 0);
+        let list = PlayerScoresList::default();
+        assert!(list.is_past_min_interval(&limit, 0));
+
+        let list = recorded(&[(1, 100)], ScoreType::Unsigned, false);
+        assert!(!list.is_past_min_interval(&limit, 109));
+        assert!(list.is_past_min_interval(&limit, 110));
+        assert!(list.is_past_min_interval(&rate_limit(0, 0, 0), 100));
+    }
+
+    #[test]
+    fn attempts_are_limited_within_a_window() {
+        let limit = rate_limit(0, 2, 100);
+        let mut list = PlayerScoresList::default();
+        for timestamp in [100, 150] {
+            assert!(list.has_attempts_left(&limit, timestamp));
+            list.count_attempt(&limit, timestamp);
+        }
+        assert_eq!(list.attempts(&limit, 199), 2);
+        assert!(!list.has_attempts_left(&limit, 199));
+
+        // The next window starts over.
+        assert!(list.has_attempts_left(&limit, 200));
+        list.count_attempt(&limit, 200);
+        assert_eq!((list.window_start, list.window_attempts), (200, 1));
+    }
+
+    #[test]
+    fn attempts_without_a_window_count_every_submission() {
+        let limit = rate_limit(0, 2, 0);
+        let mut list = recorded(&[(1, 1)], ScoreType::Unsigned, false);
+        list.count_attempt(&limit, 1);
+        assert_eq!(list.window_attempts, 0);
+        assert!(list.has_attempts_left(&limit, 1_000_000));
+
+        list.record(&ScoreEntry::new(1, 2), ScoreType::Unsigned, false);
+        assert!(!list.has_attempts_left(&limit, 1_000_000));
+        assert!(list.has_attempts_left(&rate_limit(0, 0, 0), 1_000_000));
+    }
+
+    #[test]
+    fn absorb_aggregates_keeps_the_latest_window() {
+        let limit = rate_limit(0, 0, 100);
+        let mut list = recorded(&[(1, 150)], ScoreType::Unsigned, false);
+        list.count_attempt(&limit, 150);
+        let mut other = recorded(&[(1, 120)], ScoreType::Unsigned, false);
+        other.count_attempt(&limit, 120);
+        list.absorb_aggregates(&mut other, ScoreType::Unsigned, false);
+        assert_eq!((list.window_start, list.window_attempts), (100, 2));
+
+        let mut newer = recorded(&[(1, 250)], ScoreType::Unsigned, false);
+        newer.count_attempt(&limit, 250);
+        list.absorb_aggregates(&mut newer, ScoreType::Unsigned, false);
+        assert_eq!((list.window_start, list.window_attempts), (200, 1));
+
+        let mut older = recorded(&[(1, 50)], ScoreType::Unsigned, false);
+        older.count_attempt(&limit, 50);
+        list.absorb_aggregates(&mut older, ScoreType::Unsigned, false);
+        assert_eq!((list.window_start, list.window_attempts), (200, 1));
+    }
+
+    #[test]
+    fn legacy_lists_decode_from_baseline_bytes() {
+        let (player_account, leaderboard) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let data = legacy_bytes(player_account, leaderboard, 10, &[(30, 1), (10, 2)]);
+
+        let legacy = LegacyPlayerScoresList::try_from_data(&data).unwrap();
+        assert_eq!(legacy.player_account, player_account);
+        assert_eq!(legacy.leaderboard, leaderboard);
+        assert_eq!(legacy.alloc_count, 10);
+        assert_eq!(legacy.scores.len(), 2);
+
+        let list = PlayerScoresList::from_legacy(
+            legacy,
+            2,
+            &RetentionPolicy::Unbounded,
+            ScoreType::Unsigned,
+            false,
+        );
+        assert_eq!((list.best_score, list.total_score), (30, 40));
+        assert_eq!((list.first_submission, list.last_submission), (1, 2));
+        assert_eq!(list.submission_count, 2);
+        assert_eq!((list.season, list.season_submission_count), (2, 2));
+        assert_eq!(list.scores[1].score, 10);
+        assert_eq!(list.alloc_count, 10);
+    }
+
+    #[test]
+    fn partially_migrated_lists_are_still_legacy() {
+        let scores: Vec<_> = (0..300).map(|i| (i, i as i64)).collect();
+        let mut data = legacy_bytes(Pubkey::new_unique(), Pubkey::new_unique(), 300, &scores);
+        let target = PlayerScoresList::size(300);
+        assert!(target - data.len() > MAX_PERMITTED_DATA_INCREASE);
+
+        data.resize(data.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        let legacy = LegacyPlayerScoresList::try_from_data(&data).unwrap();
+        assert_eq!(legacy.scores.len(), 300);
+
+        let list = PlayerScoresList::from_legacy(
+            legacy,
+            0,
+            &RetentionPolicy::Unbounded,
+            ScoreType::Unsigned,
+            false,
+        );
+        assert_eq!(list.current_size(), target);
+        data.resize(target, 0);
+        assert!(LegacyPlayerScoresList::try_from_data(&data).is_err());
+    }
+
+    #[test]
+    fn season_aggregates_restart_each_season() {
+        let mut list = PlayerScoresList::default();
+        list.roll_season(1);
+        list.record(&ScoreEntry::new(5, 1), ScoreType::Unsigned, false);
+        list.record(&ScoreEntry::new(7, 2), ScoreType::Unsigned, false);
+        assert_eq!(
+            (list.season_total_score, list.season_submission_count),
+            (12, 2)
+        );
+
+        list.roll_season(1);
+        assert_eq!(list.season_submission_count, 2);
+
+        list.roll_season(2);
+        list.record(&ScoreEntry::new(3, 3), ScoreType::Unsigned, false);
+        assert_eq!(
+            (list.season_total_score, list.season_submission_count),
+            (3, 1)
+        );
+        assert_eq!((list.total_score, list.submission_count), (15, 3));
+    }
+
+    #[test]
+    fn current_lists_are_not_read_as_legacy() {
+        let list = PlayerScoresList::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            &RetentionPolicy::Unbounded,
+        );
+        let mut data = Vec::new();
+        list.try_serialize(&mut data).unwrap();
+        data.resize(list.current_size(), 0);
+        assert!(LegacyPlayerScoresList::try_from_data(&data).is_err());
+        assert!(PlayerScoresList::try_deserialize(&mut &data[..]).is_ok());
+
+        // Legacy data may still decode, but never matches the size of the layout it decodes to.
+        let legacy = legacy_bytes(Pubkey::new_unique(), Pubkey::new_unique(), 10, &[(1, 1)]);
+        if let Ok(misread) = PlayerScoresList::try_deserialize(&mut &legacy[..]) {
+            assert_ne!(misread.current_size(), legacy.len());
         }
     }
 }
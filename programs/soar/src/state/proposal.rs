@@ -0,0 +1,82 @@
+use super::{Game, GameAuthority};
+use anchor_lang::prelude::*;
+
+/// A sensitive change to a [Game] awaiting approval from `game.threshold` of its admins.
+///
+/// Seeds = `[b"proposal", game.key().as_ref(), &id.to_le_bytes()]`
+#[account]
+#[derive(Debug)]
+pub struct GameProposal {
+    /// The game this proposal applies to.
+    pub game: Pubkey,
+
+    /// The proposal_count of the game when this account was created,
+    /// also used as a seed for its PDA.
+    pub id: u64,
+
+    /// The proposed change.
+    pub action: ProposalAction,
+
+    /// Admin authorities that have approved this proposal.
+    pub approvals: Vec<Pubkey>,
+
+    /// Set to true once the proposed change has been made.
+    pub executed: bool,
+}
+
+/// A sensitive change that needs to be approved through a [GameProposal].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    /// Replace the game's authorities. Applied by `execute_proposal`.
+    UpdateAuth { new_auth: Vec<GameAuthority> },
+
+    /// Change the game's approval threshold. Applied by `execute_proposal`.
+    SetThreshold { threshold: u8 },
+
+    /// Allow a single `add_leaderboard` call.
+    AddLeaderBoard,
+
+    /// Allow a single `add_ft_reward` or `add_nft_reward` call for `achievement`.
+    AddReward { achievement: Pubkey },
+
+    /// Allow a single `add_rank_reward` call for `leaderboard`.
+    AddRankReward { leaderboard: Pubkey },
+}
+
+impl GameProposal {
+    /// Calculate the size of a [GameProposal] for `action`, with room for
+    /// `max_approvals` approvals.
+    pub fn size(action: &ProposalAction, max_approvals: usize) -> usize {
+        8 + // discriminator
+        32 + // game
+        8 + // id
+        action.size() + // action
+        4 + (max_approvals * 32) + // approvals vec
+        1 // executed
+    }
+
+    /// Number of approvals from keys that are still admins of `game`.
+    pub fn approval_count(&self, game: &Game) -> usize {
+        self.approvals
+            .iter()
+            .filter(|key| game.check_role(key, GameAuthority::ADMIN))
+            .count()
+    }
+
+    /// Check if this proposal has enough approvals to be executed.
+    pub fn is_approved(&self, game: &Game) -> bool {
+        !self.executed && self.approval_count(game) >= usize::from(game.threshold.max(1))
+    }
+}
+
+impl ProposalAction {
+    /// Size of this borsh-serialized [ProposalAction].
+    pub fn size(&self) -> usize {
+        1 + match self {
+            ProposalAction::UpdateAuth { new_auth } => 4 + new_auth.len() * GameAuthority::SIZE,
+            ProposalAction::SetThreshold { .. } => 1,
+            ProposalAction::AddLeaderBoard => 0,
+            ProposalAction::AddReward { .. } | ProposalAction::AddRankReward { .. } => 32,
+        }
+    }
+}
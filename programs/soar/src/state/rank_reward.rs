@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+/// A fungible token reward paid out to the holders of specific ranks in a
+/// [LeaderBoard][super::LeaderBoard]'s [top entries][super::LeaderTopEntries]
+/// once its season is closed.
+///
+/// Seeds = `[b"rank-reward", leaderboard.key().as_ref(), &season.to_le_bytes()]`
+#[account]
+#[derive(Debug, Default)]
+pub struct RankReward {
+    /// The leaderboard this reward is given out for.
+    pub leaderboard: Pubkey,
+
+    /// The leaderboard season this reward is given out for.
+    pub season: u64,
+
+    /// The mint of the token to be given out.
+    pub mint: Pubkey,
+
+    /// The token account to withdraw from.
+    pub account: Pubkey,
+
+    /// Payout table, mapping ranges of ranks to reward amounts.
+    pub tiers: Vec<RankRewardTier>,
+}
+
+/// An inclusive range of 1-indexed ranks and the amount each of them receives.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default)]
+pub struct RankRewardTier {
+    /// First rank in this tier.
+    pub start_rank: u32,
+
+    /// Last rank in this tier.
+    pub end_rank: u32,
+
+    /// Reward amount given to each rank in this tier.
+    pub amount: u64,
+}
+
+/// Existence serves as proof that the reward for a rank has been claimed.
+///
+/// Seeds = `[b"rank-reward-claim", rank_reward.key().as_ref(), &rank.to_le_bytes()]`
+#[account]
+#[derive(Debug, Default)]
+pub struct RankRewardClaim {
+    /// The player account that claimed this rank.
+    pub player_account: Pubkey,
+
+    /// The claimed rank.
+    pub rank: u32,
+}
+
+impl RankReward {
+    /// Maximum number of tiers a single [RankReward] can hold.
+    pub const MAX_TIERS: usize = 10;
+
+    /// Calculate the size of a [RankReward] holding `tiers_count` tiers.
+    pub fn size(tiers_count: usize) -> usize {
+        8 + // discriminator
+        32 + // leaderboard
+        8 + // season
+        32 + // mint
+        32 + // account
+        4 + (tiers_count * RankRewardTier::SIZE) // tiers vec
+    }
+
+    /// Get the reward amount for a 1-indexed `rank`, if any.
+    pub fn amount_for_rank(&self, rank: u32) -> Option<u64> {
+        self.tiers
+            .iter()
+            .find(|tier| tier.contains(rank))
+            .map(|tier| tier.amount)
+    }
+}
+
+impl RankRewardTier {
+    /// Size of a borsh-serialized [RankRewardTier].
+    pub const SIZE: usize = 4 + 4 + 8;
+
+    /// Check if a 1-indexed `rank` falls within this tier.
+    pub fn contains(&self, rank: u32) -> bool {
+        rank >= self.start_rank && rank <= self.end_rank
+    }
+}
+
+impl RankRewardClaim {
+    /// Size of a borsh-serialized [RankRewardClaim].
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // player_account
+        4; // rank
+}
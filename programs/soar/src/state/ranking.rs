@@ -0,0 +1,472 @@
+//! Ordering and insertion rules for a leaderboard's top scores.
+
+use super::{LeaderBoardScore, ScoreEntry, ScoreType, ScoringMode, TieBreak};
+use anchor_lang::prelude::*;
+use std::cmp::Ordering;
+
+impl LeaderBoardScore {
+    /// Whether this is an unused slot rather than a player's entry.
+    pub fn is_placeholder(&self) -> bool {
+        self.player == Pubkey::default()
+    }
+}
+
+/// A mutable view over a leaderboard's fixed-length list of top scores, kept sorted by rank.
+pub struct Ranking<'a> {
+    /// Arrangement order.
+    pub is_ascending: bool,
+
+    /// How entries with equal scores are ordered.
+    pub tie_break: TieBreak,
+
+    /// How scores are compared.
+    pub score_type: ScoreType,
+
+    /// Top scores, best first. Unused slots hold placeholders at the end.
+    pub scores: &'a mut [LeaderBoardScore],
+}
+
+impl<'a> Ranking<'a> {
+    /// Create a view over `scores`.
+    pub fn new(
+        is_ascending: bool,
+        tie_break: TieBreak,
+        score_type: ScoreType,
+        scores: &'a mut [LeaderBoardScore],
+    ) -> Self {
+        Ranking {
+            is_ascending,
+            tie_break,
+            score_type,
+            scores,
+        }
+    }
+
+    /// Compare two entries by rank. [Ordering::Less] means `a` ranks above `b`.
+    ///
+    /// Entries are ordered by score value in the list's arrangement order, with ties settled by
+    /// the [TieBreak] rule. Placeholders rank below every player entry.
+    pub fn compare(&self, a: &LeaderBoardScore, b: &LeaderBoardScore) -> Ordering {
+        compare(self.is_ascending, self.tie_break, self.score_type, a, b)
+    }
+
+    /// Compare two score entries by rank, ignoring which player holds them.
+    pub fn compare_entries(&self, a: &ScoreEntry, b: &ScoreEntry) -> Ordering {
+        compare_entries(self.is_ascending, self.tie_break, self.score_type, a, b)
+    }
+
+    /// Placeholder entry for an unused slot that any valid score for a leaderboard with
+    /// the given `max_score` ranks above.
+    pub fn placeholder(&self, max_score: u64) -> LeaderBoardScore {
+        let mut placeholder = LeaderBoardScore::default();
+        if self.is_ascending {
+            placeholder.entry.score = max_score;
+        }
+        placeholder
+    }
+
+    /// Reset all scores to placeholders.
+    pub fn clear(&mut self, max_score: u64) {
+        let placeholder = self.placeholder(max_score);
+        self.scores.fill(placeholder);
+    }
+
+    /// Restore the list's order after entries were modified in place.
+    pub fn sort(&mut self) {
+        let (is_ascending, tie_break, score_type) =
+            (self.is_ascending, self.tie_break, self.score_type);
+        self.scores
+            .sort_by(|a, b| compare(is_ascending, tie_break, score_type, a, b));
+    }
+
+    /// Insert `candidate` into the list, keeping it sorted and its length unchanged.
+    ///
+    /// If `allow_multiple_scores` is false, the player's existing entry is replaced when
+    /// `candidate` ranks above it, or unconditionally if `replace_existing` is true.
+    ///
+    /// Returns the index `candidate` was inserted at, or [None] if it didn't make the list.
+    pub fn insert(
+        &mut self,
+        candidate: LeaderBoardScore,
+        allow_multiple_scores: bool,
+        replace_existing: bool,
+    ) -> Option<usize> {
+        let len = self.scores.len();
+        if len == 0 || candidate.is_placeholder() {
+            return None;
+        }
+
+        let (is_ascending, tie_break, score_type) =
+            (self.is_ascending, self.tie_break, self.score_type);
+        let ranks_at_or_above = |s: &LeaderBoardScore| {
+            compare(is_ascending, tie_break, score_type, s, &candidate) != Ordering::Greater
+        };
+
+        let existing = if allow_multiple_scores {
+            None
+        } else {
+            self.scores
+                .iter()
+                .position(|s| s.player == candidate.player)
+        };
+
+        let position = match existing {
+            Some(index) => {
+                let improves = self.compare(&candidate, &self.scores[index]) == Ordering::Less;
+                if !replace_existing && !improves {
+                    return None;
+                }
+
+                // Move the player's old entry to where the candidate belongs.
+                if improves {
+                    let position = self.scores[..index].partition_point(ranks_at_or_above);
+                    self.scores[position..=index].rotate_right(1);
+                    position
+                } else {
+                    let position =
+                        index + self.scores[index + 1..].partition_point(ranks_at_or_above);
+                    self.scores[index..=position].rotate_left(1);
+                    position
+                }
+            }
+            None => {
+                let position = self.scores.partition_point(ranks_at_or_above);
+                if position >= len {
+                    return None;
+                }
+                self.scores[position..].rotate_right(1);
+                position
+            }
+        };
+
+        self.scores[position] = candidate;
+        Some(position)
+    }
+
+    /// Reassign every entry held by a player for which `is_merged` returns true to
+    /// `canonical`, then restore the list's order.
+    ///
+    /// If `allow_multiple_scores` is false, `canonical`'s entries are combined into one
+    /// according to `scoring_mode` and the freed slots are refilled with placeholders.
+    pub fn reassign(
+        &mut self,
+        is_merged: impl Fn(&Pubkey) -> bool,
+        canonical: Pubkey,
+        allow_multiple_scores: bool,
+        scoring_mode: ScoringMode,
+        max_score: u64,
+    ) {
+        self.scores
+            .iter_mut()
+            .filter(|s| is_merged(&s.player))
+            .for_each(|s| s.player = canonical);
+
+        if !allow_multiple_scores {
+            let (is_ascending, score_type) = (self.is_ascending, self.score_type);
+            let placeholder = self.placeholder(max_score);
+            let mut combined: Option<ScoreEntry> = None;
+            for s in self.scores.iter_mut().filter(|s| s.player == canonical) {
+                combined = Some(match combined {
+                    Some(entry) => scoring_mode.combine(entry, s.entry, score_type, is_ascending),
+                    None => s.entry,
+                });
+                *s = placeholder;
+            }
+
+            if let Some(entry) = combined {
+                // At least one slot was freed, so the last one is a placeholder once sorted.
+                self.sort();
+                let last = self.scores.len() - 1;
+                self.scores[last] = LeaderBoardScore::new(canonical, entry);
+            }
+        }
+
+        self.sort();
+    }
+}
+
+fn compare(
+    is_ascending: bool,
+    tie_break: TieBreak,
+    score_type: ScoreType,
+    a: &LeaderBoardScore,
+    b: &LeaderBoardScore,
+) -> Ordering {
+    match (a.is_placeholder(), b.is_placeholder()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => (),
+    }
+
+    compare_entries(is_ascending, tie_break, score_type, &a.entry, &b.entry)
+}
+
+fn compare_entries(
+    is_ascending: bool,
+    tie_break: TieBreak,
+    score_type: ScoreType,
+    a: &ScoreEntry,
+    b: &ScoreEntry,
+) -> Ordering {
+    let by_score = if is_ascending {
+        score_type.compare(a.score, b.score)
+    } else {
+        score_type.compare(b.score, a.score)
+    };
+
+    by_score.then_with(|| tie_break.compare(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TopEntries {
+        is_ascending: bool,
+        tie_break: TieBreak,
+        score_type: ScoreType,
+        top_scores: Vec<LeaderBoardScore>,
+    }
+
+    impl TopEntries {
+        fn ranking(&mut self) -> Ranking<'_> {
+            Ranking::new(
+                self.is_ascending,
+                self.tie_break,
+                self.score_type,
+                &mut self.top_scores,
+            )
+        }
+    }
+
+    fn entries(is_ascending: bool, len: usize) -> TopEntries {
+        let mut top_entries = TopEntries {
+            is_ascending,
+            tie_break: TieBreak::EarlierWins,
+            score_type: ScoreType::Unsigned,
+            top_scores: vec![LeaderBoardScore::default(); len],
+        };
+        top_entries.ranking().clear(u64::MAX);
+        top_entries
+    }
+
+    fn score(player: u8, score: u64, timestamp: i64) -> LeaderBoardScore {
+        LeaderBoardScore::new(
+            Pubkey::new_from_array([player; 32]),
+            ScoreEntry::new(score, timestamp),
+        )
+    }
+
+    fn ranking(top_entries: &TopEntries) -> Vec<(u8, u64)> {
+        top_entries
+            .top_scores
+            .iter()
+            .filter(|s| !s.is_placeholder())
+            .map(|s| (s.player.to_bytes()[0], s.entry.score))
+            .collect()
+    }
+
+    #[test]
+    fn descending_insert_keeps_order() {
+        let mut top = entries(false, 3);
+        assert_eq!(top.ranking().insert(score(1, 10, 1), false, false), Some(0));
+        assert_eq!(top.ranking().insert(score(2, 30, 2), false, false), Some(0));
+        assert_eq!(top.ranking().insert(score(3, 20, 3), false, false), Some(1));
+        assert_eq!(ranking(&top), vec![(2, 30), (3, 20), (1, 10)]);
+
+        assert_eq!(top.ranking().insert(score(4, 5, 4), false, false), None);
+        assert_eq!(top.ranking().insert(score(4, 25, 5), false, false), Some(1));
+        assert_eq!(ranking(&top), vec![(2, 30), (4, 25), (3, 20)]);
+    }
+
+    #[test]
+    fn ascending_insert_keeps_order() {
+        let mut top = entries(true, 3);
+        top.ranking().insert(score(1, 10, 1), false, false);
+        top.ranking().insert(score(2, 30, 2), false, false);
+        top.ranking().insert(score(3, 20, 3), false, false);
+        assert_eq!(ranking(&top), vec![(1, 10), (3, 20), (2, 30)]);
+
+        assert_eq!(top.ranking().insert(score(4, 40, 4), false, false), None);
+        assert_eq!(top.ranking().insert(score(4, 5, 5), false, false), Some(0));
+        assert_eq!(ranking(&top), vec![(4, 5), (1, 10), (3, 20)]);
+    }
+
+    #[test]
+    fn zero_score_fills_empty_slot() {
+        let mut top = entries(false, 2);
+        assert_eq!(top.ranking().insert(score(1, 0, 1), false, false), Some(0));
+        assert_eq!(ranking(&top), vec![(1, 0)]);
+    }
+
+    #[test]
+    fn single_entry_per_player_keeps_best() {
+        let mut top = entries(false, 3);
+        top.ranking().insert(score(1, 10, 1), false, false);
+        top.ranking().insert(score(2, 20, 2), false, false);
+        top.ranking().insert(score(3, 30, 3), false, false);
+
+        assert_eq!(top.ranking().insert(score(2, 15, 4), false, false), None);
+        assert_eq!(ranking(&top), vec![(3, 30), (2, 20), (1, 10)]);
+
+        // The player's existing slot is at a different index than where the new score lands.
+        assert_eq!(top.ranking().insert(score(1, 40, 5), false, false), Some(0));
+        assert_eq!(ranking(&top), vec![(1, 40), (3, 30), (2, 20)]);
+    }
+
+    #[test]
+    fn replace_existing_moves_entry_down() {
+        let mut top = entries(false, 3);
+        top.ranking().insert(score(1, 30, 1), false, false);
+        top.ranking().insert(score(2, 20, 2), false, false);
+        top.ranking().insert(score(3, 10, 3), false, false);
+
+        assert_eq!(top.ranking().insert(score(1, 5, 4), false, true), Some(2));
+        assert_eq!(ranking(&top), vec![(2, 20), (3, 10), (1, 5)]);
+    }
+
+    #[test]
+    fn multiple_scores_per_player() {
+        let mut top = entries(false, 3);
+        top.ranking().insert(score(1, 10, 1), true, false);
+        top.ranking().insert(score(1, 20, 2), true, false);
+        top.ranking().insert(score(1, 15, 3), true, false);
+        assert_eq!(ranking(&top), vec![(1, 20), (1, 15), (1, 10)]);
+    }
+
+    #[test]
+    fn ties_go_to_earlier_timestamp() {
+        let mut top = entries(false, 2);
+        top.ranking().insert(score(1, 10, 5), false, false);
+        assert_eq!(top.ranking().insert(score(2, 10, 3), false, false), Some(0));
+        assert_eq!(top.ranking().insert(score(3, 10, 7), false, false), None);
+        assert_eq!(ranking(&top), vec![(2, 10), (1, 10)]);
+
+        let mut top = entries(true, 2);
+        top.ranking().insert(score(1, 10, 5), false, false);
+        assert_eq!(top.ranking().insert(score(2, 10, 7), false, false), Some(1));
+        assert_eq!(ranking(&top), vec![(1, 10), (2, 10)]);
+    }
+
+    #[test]
+    fn ties_go_to_later_timestamp() {
+        let mut top = entries(false, 2);
+        top.tie_break = TieBreak::LaterWins;
+        top.ranking().insert(score(1, 10, 5), false, false);
+        assert_eq!(top.ranking().insert(score(2, 10, 3), false, false), Some(1));
+        assert_eq!(top.ranking().insert(score(3, 10, 7), false, false), Some(0));
+        assert_eq!(ranking(&top), vec![(3, 10), (1, 10)]);
+    }
+
+    #[test]
+    fn ties_go_to_better_secondary_score() {
+        let with_secondary = |player: u8, secondary_score: u64, timestamp: i64| {
+            LeaderBoardScore::new(
+                Pubkey::new_from_array([player; 32]),
+                ScoreEntry::with_details(10, timestamp, Some(secondary_score), [0; 32]),
+            )
+        };
+
+        let mut top = entries(false, 3);
+        top.tie_break = TieBreak::SecondaryScore { is_ascending: true };
+        top.ranking().insert(with_secondary(1, 50, 1), false, false);
+        assert_eq!(
+            top.ranking().insert(with_secondary(2, 40, 2), false, false),
+            Some(0)
+        );
+        assert_eq!(
+            top.ranking().insert(with_secondary(3, 40, 3), false, false),
+            Some(1)
+        );
+        assert_eq!(ranking(&top), vec![(2, 10), (3, 10), (1, 10)]);
+    }
+
+    #[test]
+    fn missing_secondary_scores_rank_last() {
+        for is_ascending in [true, false] {
+            let mut top = entries(false, 3);
+            top.tie_break = TieBreak::SecondaryScore { is_ascending };
+            top.ranking().insert(score(1, 10, 1), false, false);
+            let with_secondary = LeaderBoardScore::new(
+                Pubkey::new_from_array([2; 32]),
+                ScoreEntry::with_details(10, 2, Some(0), [0; 32]),
+            );
+            assert_eq!(top.ranking().insert(with_secondary, false, false), Some(0));
+            assert_eq!(top.ranking().insert(score(3, 10, 3), false, false), Some(2));
+            assert_eq!(ranking(&top), vec![(2, 10), (1, 10), (3, 10)]);
+        }
+    }
+
+    #[test]
+    fn signed_scores_rank_negative_values_lowest() {
+        let negative = |value: i64| value as u64;
+
+        let mut top = entries(false, 3);
+        top.score_type = ScoreType::Signed;
+        top.ranking()
+            .insert(score(1, negative(-5), 1), false, false);
+        top.ranking().insert(score(2, 3, 2), false, false);
+        assert_eq!(
+            top.ranking()
+                .insert(score(3, negative(-1), 3), false, false),
+            Some(1)
+        );
+        assert_eq!(
+            ranking(&top),
+            vec![(2, 3), (3, negative(-1)), (1, negative(-5))]
+        );
+
+        let mut top = entries(true, 2);
+        top.score_type = ScoreType::Signed;
+        top.ranking().insert(score(1, 2, 1), false, false);
+        assert_eq!(
+            top.ranking()
+                .insert(score(2, negative(-4), 2), false, false),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn sort_places_placeholders_last() {
+        let mut top = entries(false, 3);
+        top.top_scores[2] = score(1, 10, 1);
+        top.top_scores[1] = score(2, 20, 2);
+        top.ranking().sort();
+        assert_eq!(ranking(&top), vec![(2, 20), (1, 10)]);
+        assert!(top.top_scores[2].is_placeholder());
+    }
+
+    #[test]
+    fn reassign_combines_merged_entries() {
+        let mut top = entries(false, 4);
+        top.ranking().insert(score(1, 10, 1), false, false);
+        top.ranking().insert(score(2, 40, 2), false, false);
+        top.ranking().insert(score(3, 20, 3), false, false);
+
+        let canonical = Pubkey::new_from_array([3; 32]);
+        let merged = Pubkey::new_from_array([1; 32]);
+        top.ranking().reassign(
+            |key| *key == merged,
+            canonical,
+            false,
+            ScoringMode::Best,
+            u64::MAX,
+        );
+        assert_eq!(ranking(&top), vec![(2, 40), (3, 20)]);
+        assert_eq!(top.top_scores.len(), 4);
+
+        let mut top = entries(false, 4);
+        top.ranking().insert(score(1, 10, 1), false, false);
+        top.ranking().insert(score(2, 40, 2), false, false);
+        top.ranking().insert(score(3, 35, 3), false, false);
+        top.ranking().reassign(
+            |key| *key == merged,
+            canonical,
+            false,
+            ScoringMode::Cumulative,
+            u64::MAX,
+        );
+        assert_eq!(ranking(&top), vec![(3, 45), (2, 40)]);
+    }
+}
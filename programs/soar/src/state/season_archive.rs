@@ -0,0 +1,47 @@
+use super::LeaderBoardScore;
+use anchor_lang::{prelude::*, solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE};
+
+/// An immutable snapshot of a [LeaderBoard][super::LeaderBoard]'s top entries,
+/// taken when one of its seasons is closed.
+///
+/// Seeds = `[b"season-archive", leaderboard.key().as_ref(), &season.to_le_bytes()]`
+#[account]
+#[derive(Debug, Default)]
+pub struct SeasonArchive {
+    /// The leaderboard this archive belongs to.
+    pub leaderboard: Pubkey,
+
+    /// The season number this archive was taken for.
+    pub season: u64,
+
+    /// Timestamp at which the season started.
+    pub start: i64,
+
+    /// Timestamp at which the season was closed.
+    pub end: i64,
+
+    /// Arrangement order of `top_scores`.
+    pub is_ascending: bool,
+
+    /// Top scores at the time the season was closed. Seasons ranking more than
+    /// [Self::MAX_SCORES] entries can't be closed.
+    pub top_scores: Vec<LeaderBoardScore>,
+}
+
+impl SeasonArchive {
+    /// Maximum number of top scores archived, so the archive fits within a single
+    /// account initialization.
+    pub const MAX_SCORES: usize =
+        (MAX_PERMITTED_DATA_INCREASE - Self::size(0)) / LeaderBoardScore::SIZE;
+
+    /// Calculate the size of a [SeasonArchive] holding `scores_count` entries.
+    pub const fn size(scores_count: usize) -> usize {
+        8 + // discriminator
+        32 + // leaderboard
+        8 + // season
+        8 + // start
+        8 + // end
+        1 + // is_ascending
+        4 + (scores_count * LeaderBoardScore::SIZE) // top_scores vec
+    }
+}
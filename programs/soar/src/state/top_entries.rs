@@ -1,7 +1,12 @@
-use super::ScoreEntry;
-use anchor_lang::prelude::*;
+use super::{LegacyScoreEntry, ScoreEntry};
+use crate::SoarError;
+use anchor_lang::{prelude::*, solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE};
+use std::mem::size_of;
 
-/// Keeps track of a sorted list of top scores for a leaderboard.
+/// Legacy borsh layout of a leaderboard's top scores.
+///
+/// Only kept so existing accounts can be read and converted to a [LeaderTopEntriesV2]
+/// with `migrate_top_entries`.
 ///
 /// Seeds = [b"top-scores", leaderboard.key().as_ref()]
 #[account]
@@ -11,11 +16,35 @@ pub struct LeaderTopEntries {
     pub is_ascending: bool,
 
     /// Top scores.
-    pub top_scores: Vec<LeaderBoardScore>,
+    pub top_scores: Vec<LegacyLeaderBoardScore>,
+}
+
+/// Keeps track of a sorted list of top scores for a leaderboard.
+///
+/// This is the zero-copy header of the account. It's followed in the account's data by
+/// `capacity` [LeaderBoardScore]s, sorted by rank, which are accessed with [Self::split]
+/// and [Self::split_mut] instead of being deserialized.
+///
+/// Seeds = [b"top-scores", leaderboard.key().as_ref()]
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct LeaderTopEntriesV2 {
+    /// The leaderboard these entries belong to.
+    pub leaderboard: Pubkey,
+
+    /// Number of score slots following the header.
+    pub capacity: u32,
+
+    /// Arrangement order. `1` for ascending, `0` for descending.
+    pub is_ascending: u8,
+
+    /// Unused.
+    pub padding: [u8; 3],
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
-/// An single entry to a [LeaderTopEntries].
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug)]
+/// An single entry to a [LeaderTopEntriesV2].
 pub struct LeaderBoardScore {
     /// The player
     pub player: Pubkey,
@@ -24,12 +53,130 @@ pub struct LeaderBoardScore {
     pub entry: ScoreEntry,
 }
 
+/// An entry to a legacy [LeaderTopEntries].
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default)]
+pub struct LegacyLeaderBoardScore {
+    /// The player
+    pub player: Pubkey,
+
+    /// The user's score.
+    pub entry: LegacyScoreEntry,
+}
+
 impl LeaderTopEntries {
     /// Calculate the size for a given `top_scores` vector length.
-    pub fn size(scores_to_retain: usize) -> usize {
+    pub const fn size(scores_to_retain: usize) -> usize {
         8 + // discriminator
         1 + // is_ascending
-        4 + (scores_to_retain * LeaderBoardScore::SIZE) // top_scores vec
+        4 + (scores_to_retain * LegacyLeaderBoardScore::SIZE) // top_scores vec
+    }
+}
+
+impl LegacyLeaderBoardScore {
+    /// Size of a borsh-serialized [LegacyLeaderBoardScore].
+    pub const SIZE: usize = 32 + // player key
+        LegacyScoreEntry::SIZE; // entry
+}
+
+impl From<LegacyLeaderBoardScore> for LeaderBoardScore {
+    fn from(score: LegacyLeaderBoardScore) -> Self {
+        LeaderBoardScore::new(score.player, score.entry.into())
+    }
+}
+
+impl LeaderTopEntriesV2 {
+    /// Size of the discriminator and header preceding the scores.
+    pub const HEADER_SIZE: usize = 8 + size_of::<Self>();
+
+    /// Maximum number of scores a [LeaderTopEntriesV2] account can be created with.
+    /// Larger lists are reached by resizing after creation.
+    pub const MAX_INITIAL_SCORES: usize =
+        (MAX_PERMITTED_DATA_INCREASE - Self::HEADER_SIZE) / LeaderBoardScore::SIZE;
+
+    /// Calculate the account size for a given number of score slots.
+    pub const fn size(capacity: usize) -> usize {
+        Self::HEADER_SIZE + capacity * LeaderBoardScore::SIZE
+    }
+
+    /// Create a header for `capacity` score slots.
+    pub fn new(leaderboard: Pubkey, capacity: usize, is_ascending: bool) -> Self {
+        LeaderTopEntriesV2 {
+            leaderboard,
+            capacity: u32::try_from(capacity).unwrap(),
+            is_ascending: u8::from(is_ascending),
+            padding: [0; 3],
+        }
+    }
+
+    /// Whether scores are arranged in ascending order.
+    pub fn is_ascending(&self) -> bool {
+        self.is_ascending != 0
+    }
+
+    /// Set the arrangement order.
+    pub fn set_is_ascending(&mut self, is_ascending: bool) {
+        self.is_ascending = u8::from(is_ascending);
+    }
+
+    /// Check that `scores` can be resized to `new_len` slots in a single instruction.
+    ///
+    /// Shrinking below the number of ranked entries drops the lowest-ranked ones, which is
+    /// only allowed if `drop_entries` is true.
+    pub fn check_resize(
+        scores: &[LeaderBoardScore],
+        new_len: usize,
+        drop_entries: bool,
+    ) -> Result<()> {
+        require!(
+            new_len > 0
+                && Self::size(new_len) <= Self::size(scores.len()) + MAX_PERMITTED_DATA_INCREASE,
+            SoarError::InvalidTopEntriesSize
+        );
+
+        let ranked = scores.iter().filter(|s| !s.is_placeholder()).count();
+        require!(
+            drop_entries || new_len >= ranked,
+            SoarError::RankedEntriesDropped
+        );
+        Ok(())
+    }
+
+    /// Split an account's data into its header and ranked scores.
+    pub fn split(data: &[u8]) -> Result<(&Self, &[LeaderBoardScore])> {
+        let body = data
+            .get(8..)
+            .filter(|body| body.len() >= size_of::<Self>())
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        let (header, scores) = body.split_at(size_of::<Self>());
+        let header: &Self =
+            bytemuck::try_from_bytes(header).map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+        let len = header.capacity as usize * LeaderBoardScore::SIZE;
+        let scores = scores
+            .get(..len)
+            .and_then(|scores| bytemuck::try_cast_slice(scores).ok())
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+
+        Ok((header, scores))
+    }
+
+    /// Split an account's data into its mutable header and ranked scores.
+    pub fn split_mut(data: &mut [u8]) -> Result<(&mut Self, &mut [LeaderBoardScore])> {
+        let body = data
+            .get_mut(8..)
+            .filter(|body| body.len() >= size_of::<Self>())
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        let (header, scores) = body.split_at_mut(size_of::<Self>());
+        let header: &mut Self = bytemuck::try_from_bytes_mut(header)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+        let len = header.capacity as usize * LeaderBoardScore::SIZE;
+        let scores = scores
+            .get_mut(..len)
+            .and_then(|scores| bytemuck::try_cast_slice_mut(scores).ok())
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+
+        Ok((header, scores))
     }
 }
 
@@ -43,3 +190,84 @@ impl LeaderBoardScore {
         LeaderBoardScore { player, entry }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn split_reads_header_and_scores() {
+        // Account data is 8-byte aligned on-chain.
+        let mut buffer = vec![0u64; LeaderTopEntriesV2::size(3) / 8];
+        let data: &mut [u8] = bytemuck::cast_slice_mut(&mut buffer);
+        assert_eq!(data.len(), LeaderTopEntriesV2::size(3));
+
+        let leaderboard = Pubkey::new_unique();
+        {
+            let (header, _) = LeaderTopEntriesV2::split_mut(data).unwrap();
+            *header = LeaderTopEntriesV2::new(leaderboard, 3, true);
+        }
+        {
+            let (_, scores) = LeaderTopEntriesV2::split_mut(data).unwrap();
+            scores[2] = LeaderBoardScore::new(leaderboard, ScoreEntry::new(7, 1));
+        }
+
+        let (header, scores) = LeaderTopEntriesV2::split(data).unwrap();
+        assert_eq!(header.leaderboard, leaderboard);
+        assert!(header.is_ascending());
+        assert_eq!(scores.len(), 3);
+        assert_eq!(scores[2].entry.score, 7);
+
+        assert!(LeaderTopEntriesV2::split(&data[..LeaderTopEntriesV2::size(2)]).is_err());
+    }
+
+    fn ranked(len: usize, players: u8) -> Vec<LeaderBoardScore> {
+        let mut scores = vec![LeaderBoardScore::default(); len];
+        for (i, score) in scores.iter_mut().take(players as usize).enumerate() {
+            *score = LeaderBoardScore::new(
+                Pubkey::new_from_array([i as u8 + 1; 32]),
+                ScoreEntry::new(100 - i as u64, 1),
+            );
+        }
+        scores
+    }
+
+    #[test]
+    fn shrinking_below_ranked_entries_needs_opt_in() {
+        let scores = ranked(5, 3);
+        assert!(LeaderTopEntriesV2::check_resize(&scores, 3, false).is_ok());
+        assert!(LeaderTopEntriesV2::check_resize(&scores, 2, false).is_err());
+        assert!(LeaderTopEntriesV2::check_resize(&scores, 2, true).is_ok());
+        assert!(LeaderTopEntriesV2::check_resize(&scores, 0, true).is_err());
+    }
+
+    #[test]
+    fn growing_is_limited_per_instruction() {
+        let scores = ranked(5, 5);
+        let max_growth = MAX_PERMITTED_DATA_INCREASE / LeaderBoardScore::SIZE;
+        assert!(LeaderTopEntriesV2::check_resize(&scores, 6, false).is_ok());
+        assert!(LeaderTopEntriesV2::check_resize(&scores, 5 + max_growth, false).is_ok());
+        assert!(LeaderTopEntriesV2::check_resize(&scores, 6 + max_growth, false).is_err());
+    }
+
+    #[test]
+    fn legacy_entries_decode_from_baseline_bytes() {
+        let player = Pubkey::new_unique();
+        let mut data = LeaderTopEntries::DISCRIMINATOR.to_vec();
+        data.push(1); // is_ascending
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(player.as_ref());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&7i64.to_le_bytes());
+        data.extend_from_slice(&[0; LegacyLeaderBoardScore::SIZE]);
+        assert_eq!(data.len(), LeaderTopEntries::size(2));
+
+        let legacy = LeaderTopEntries::try_deserialize(&mut &data[..]).unwrap();
+        assert!(legacy.is_ascending);
+        let score = LeaderBoardScore::from(legacy.top_scores[0]);
+        assert_eq!(score.player, player);
+        assert_eq!((score.entry.score, score.entry.timestamp), (42, 7));
+        assert!(LeaderBoardScore::from(legacy.top_scores[1]).is_placeholder());
+    }
+}
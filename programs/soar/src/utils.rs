@@ -1,13 +1,102 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
+    ed25519_program,
+    entrypoint::MAX_PERMITTED_DATA_INCREASE,
     program::{invoke, invoke_signed},
     system_instruction,
-    sysvar::rent::Rent,
+    sysvar::{instructions as ix_sysvar, rent::Rent},
 };
 use anchor_spl::token;
+
+use crate::error::SoarError;
+use crate::state::{Game, GameProposal, ProposalAction};
 use mpl_token_metadata::instruction::{create_master_edition_v3, create_metadata_accounts_v3};
 use mpl_token_metadata::state::{DataV2, Metadata, TokenMetadataAccount};
 
+/// Authorize a sensitive `action` on `game`.
+///
+/// If the game requires proposals, `proposal` must be an approved, unexecuted [GameProposal]
+/// for exactly `action`, and is marked as executed.
+pub fn authorize_with_proposal(
+    game: &Account<Game>,
+    proposal: Option<&mut Account<GameProposal>>,
+    action: &ProposalAction,
+) -> Result<()> {
+    if !game.requires_proposal() {
+        return Ok(());
+    }
+
+    let proposal = proposal.ok_or(SoarError::ProposalRequired)?;
+    require_keys_eq!(proposal.game, game.key(), SoarError::ProposalActionMismatch);
+    require!(
+        proposal.action == *action,
+        SoarError::ProposalActionMismatch
+    );
+    require!(proposal.is_approved(game), SoarError::ProposalNotApproved);
+    proposal.executed = true;
+
+    Ok(())
+}
+
+/// Read the public key and message from an ed25519 signature-verification instruction
+/// placed immediately before the current one.
+///
+/// The ed25519 program fails the transaction if the signature is invalid, so a successful
+/// read means `message` was signed by the returned key.
+pub fn read_ed25519_verification(instructions_sysvar: &AccountInfo) -> Result<(Pubkey, Vec<u8>)> {
+    // Layout: num_signatures (u8), padding (u8), then one set of seven u16 offsets.
+    const HEADER_LEN: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    const SIGNATURE_LEN: usize = 64;
+    const PUBKEY_LEN: usize = 32;
+
+    let current_index = ix_sysvar::load_current_index_checked(instructions_sysvar)?;
+    let index = current_index
+        .checked_sub(1)
+        .ok_or(SoarError::InvalidAttestation)?;
+    let ix = ix_sysvar::load_instruction_at_checked(index as usize, instructions_sysvar)?;
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        SoarError::InvalidAttestation
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= HEADER_LEN + OFFSETS_LEN && data[0] == 1,
+        SoarError::InvalidAttestation
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = HEADER_LEN;
+    let signature_offset = read_u16(offsets) as usize;
+    let signature_ix_index = read_u16(offsets + 2);
+    let pubkey_offset = read_u16(offsets + 4) as usize;
+    let pubkey_ix_index = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_len = read_u16(offsets + 10) as usize;
+    let message_ix_index = read_u16(offsets + 12);
+
+    // All data must live in the ed25519 instruction itself.
+    require!(
+        signature_ix_index == u16::MAX
+            && pubkey_ix_index == u16::MAX
+            && message_ix_index == u16::MAX,
+        SoarError::InvalidAttestation
+    );
+    require!(
+        data.len() >= signature_offset + SIGNATURE_LEN
+            && data.len() >= pubkey_offset + PUBKEY_LEN
+            && data.len() >= message_offset + message_len,
+        SoarError::InvalidAttestation
+    );
+
+    let pubkey = Pubkey::try_from(&data[pubkey_offset..pubkey_offset + PUBKEY_LEN]).unwrap();
+    let message = data[message_offset..message_offset + message_len].to_vec();
+
+    Ok((pubkey, message))
+}
+
 // https://solanacookbook.com/references/programs.html#how-to-change-account-size
 pub fn resize_account<'a>(
     target_account: &AccountInfo<'a>,
@@ -33,6 +122,98 @@ pub fn resize_account<'a>(
     Ok(())
 }
 
+/// Resize `target_account` towards `new_size`, growing it by at most
+/// [MAX_PERMITTED_DATA_INCREASE] bytes so the call stays within the runtime's limit.
+///
+/// Returns whether the account reached `new_size`.
+pub fn resize_account_step<'a>(
+    target_account: &AccountInfo<'a>,
+    funding_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    new_size: usize,
+) -> Result<bool> {
+    let step_size = new_size.min(target_account.data_len() + MAX_PERMITTED_DATA_INCREASE);
+    resize_account(target_account, funding_account, system_program, step_size)?;
+
+    Ok(step_size == new_size)
+}
+
+/// Shrink a program-owned account to `new_size`, refunding the rent it no longer needs
+/// to `receiver`.
+pub fn shrink_account<'a>(
+    target_account: &AccountInfo<'a>,
+    receiver: &AccountInfo<'a>,
+    new_size: usize,
+) -> Result<()> {
+    target_account.realloc(new_size, false)?;
+
+    let rent = Rent::get()?;
+    let excess = target_account
+        .lamports()
+        .saturating_sub(rent.minimum_balance(new_size));
+    **target_account.try_borrow_mut_lamports()? -= excess;
+    **receiver.try_borrow_mut_lamports()? += excess;
+
+    Ok(())
+}
+
+/// Create a program-owned account at a PDA, funding it from `payer`.
+///
+/// Handles the case where the target address has already been sent lamports.
+pub fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    target_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let required_lamports = rent.minimum_balance(space);
+    let current_lamports = target_account.lamports();
+
+    if current_lamports == 0 {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                target_account.key,
+                required_lamports,
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                payer.clone(),
+                target_account.clone(),
+                system_program.clone(),
+            ],
+            &[signer_seeds],
+        )?;
+    } else {
+        let lamports_diff = required_lamports.saturating_sub(current_lamports);
+        if lamports_diff > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, target_account.key, lamports_diff),
+                &[
+                    payer.clone(),
+                    target_account.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+        invoke_signed(
+            &system_instruction::allocate(target_account.key, space as u64),
+            &[target_account.clone(), system_program.clone()],
+            &[signer_seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(target_account.key, &crate::ID),
+            &[target_account.clone(), system_program.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
 pub fn decode_mpl_metadata_account(account: &AccountInfo<'_>) -> Result<Metadata> {
     if account.owner != &mpl_token_metadata::ID {
         return Err(ProgramError::IllegalOwner.into());